@@ -4,11 +4,13 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Expr, ExprAssign, Ident, Lit, LitBool, LitStr, Meta, MetaList, Result, Token,
+    Attribute, Expr, ExprAssign, Ident, Lit, LitBool, LitInt, LitStr, Meta, MetaList, Path, Result,
+    Token, Type,
 };
 
 use self::{
     pyderive_field::{ExprAssignGeneric, OptionFieldAttr, PyderiveFieldAttr},
+    pyderive_struct::PyderiveStructAttr,
     pyo3_field::Pyo3FieldAttr,
     pyo3_struct::{Pyo3StructAttr, RenamingRule},
 };
@@ -20,10 +22,32 @@ fn take_meta_list(a: &Attribute) -> Option<&MetaList> {
     }
 }
 
+macro_rules! extract_ident {
+    ($value:ident) => {
+        match $value {
+            OptionFieldAttr::Ident(ident) => ident,
+            OptionFieldAttr::ExprAssign(ExprAssignGeneric { left, .. }) => left,
+        }
+    };
+}
+
+macro_rules! take_bool {
+    ($value:ident) => {
+        match $value {
+            OptionFieldAttr::Ident { .. } => true,
+            OptionFieldAttr::ExprAssign(ExprAssignGeneric {
+                right: LitBool { value, .. },
+                ..
+            }) => value,
+        }
+    };
+}
+
 #[derive(Debug, Default, Clone)]
 pub(crate) struct Pyo3StructOption {
     pub(crate) get: bool,
     pub(crate) set: bool,
+    pub(crate) frozen: bool,
     pub(crate) rename: RenamingRule,
 }
 
@@ -38,6 +62,9 @@ impl FromIterator<Pyo3StructAttr> for Pyo3StructOption {
                 Pyo3StructAttr::Set(_) => {
                     new.set = true;
                 }
+                Pyo3StructAttr::Frozen(_) => {
+                    new.frozen = true;
+                }
                 Pyo3StructAttr::Rename { value, .. } => {
                     new.rename = value;
                 }
@@ -48,6 +75,15 @@ impl FromIterator<Pyo3StructAttr> for Pyo3StructOption {
     }
 }
 
+/// Returns `true` if the attribute is `#[pyclass]` of any path,
+/// e.g. `#[pyo3::pyclass]`, `#[pyo3::prelude::pyclass]` or a re-export.
+pub(crate) fn is_pyclass(a: &Attribute) -> bool {
+    a.path()
+        .segments
+        .last()
+        .is_some_and(|last| last.ident == "pyclass")
+}
+
 // NEVER returns Error on parsing Pyo3 attr args,
 // we just read them.
 impl TryFrom<&Vec<Attribute>> for Pyo3StructOption {
@@ -58,19 +94,7 @@ impl TryFrom<&Vec<Attribute>> for Pyo3StructOption {
 
         value
             .iter()
-            // FIXME:
-            // Replace error handling,
-            // here is parsing pyo3 attr arg
-            .map(|a| {
-                if a.path().is_ident("pyderive") {
-                    Err(syn::Error::new(a.meta.span(), "support field only"))
-                } else {
-                    Ok(a)
-                }
-            })
-            .collect::<Result<Vec<_>>>()?
-            .into_iter()
-            .filter(|a| a.path().is_ident("pyclass") || a.path().is_ident("pyo3"))
+            .filter(|a| is_pyclass(a) || a.path().is_ident("pyo3"))
             .filter_map(take_meta_list)
             .map(|m| m.parse_args_with(Attr::parse_terminated))
             .collect::<Result<Vec<_>>>()
@@ -138,32 +162,49 @@ pub(crate) struct PyderiveFieldOption<'a> {
     pub(crate) default: Option<Expr>,
     pub(crate) default_factory: Option<bool>,
     pub(crate) annotation: Option<Cow<'a, str>>,
+    pub(crate) compare: Option<bool>,
+    pub(crate) new_default: Option<bool>,
+    pub(crate) iter_order: Option<usize>,
+    pub(crate) default_once: Option<Expr>,
+    pub(crate) is_name: Option<bool>,
+    pub(crate) repr_float: Option<LitStr>,
+    pub(crate) try_from: Option<Type>,
+    pub(crate) init_var: Option<bool>,
+    pub(crate) children: Option<bool>,
+    pub(crate) positional_only: Option<bool>,
+    pub(crate) path_str: Option<bool>,
+    pub(crate) set_field: Option<bool>,
+    pub(crate) len_field: Option<bool>,
+    pub(crate) cache_hash: Option<bool>,
+    pub(crate) compute: Option<Expr>,
+    pub(crate) classvar: Option<Expr>,
+    pub(crate) format_field: Option<bool>,
+    pub(crate) default_py: Option<LitStr>,
+    pub(crate) repr_debug: Option<bool>,
+    pub(crate) repr_name: Option<bool>,
+    pub(crate) doc: Option<LitStr>,
+    pub(crate) cache_repr: Option<bool>,
+    pub(crate) set: Option<bool>,
+    pub(crate) flatten: Option<bool>,
+    pub(crate) dataclass_flatten: Option<bool>,
+}
+
+impl PyderiveFieldOption<'_> {
+    /// Whether the field is not a part of the instance in Python,
+    /// i.e. the cache_hash, cache_repr and classvar fields.
+    pub(crate) fn hidden(&self) -> bool {
+        self.cache_hash == Some(true) || self.cache_repr == Some(true) || self.classvar.is_some()
+    }
 }
 
 impl<'a> FromIterator<PyderiveFieldAttr> for Result<PyderiveFieldOption<'a>> {
     fn from_iter<T: IntoIterator<Item = PyderiveFieldAttr>>(iter: T) -> Self {
         let mut new = PyderiveFieldOption::default();
-
-        macro_rules! extract_ident {
-            ($value:ident) => {
-                match $value {
-                    OptionFieldAttr::Ident(ident) => ident,
-                    OptionFieldAttr::ExprAssign(ExprAssignGeneric { left, .. }) => left,
-                }
-            };
-        }
-
-        macro_rules! take_bool {
-            ($value:ident) => {
-                match $value {
-                    OptionFieldAttr::Ident { .. } => true,
-                    OptionFieldAttr::ExprAssign(ExprAssignGeneric {
-                        right: LitBool { value, .. },
-                        ..
-                    }) => value,
-                }
-            };
-        }
+        let mut default_factory_span = None;
+        let mut new_default_span = None;
+        let mut default_once_span = None;
+        let mut kw_only_span = None;
+        let mut new_false_span = None;
 
         for opt in iter {
             match opt {
@@ -172,7 +213,12 @@ impl<'a> FromIterator<PyderiveFieldAttr> for Result<PyderiveFieldOption<'a>> {
                         return Err(syn::Error::new(extract_ident!(v).span(), "duplicated new"));
                     }
                     None => {
-                        new.new = Some(take_bool!(v));
+                        let span = extract_ident!(v).span();
+                        let init = take_bool!(v);
+                        if !init {
+                            new_false_span = Some(span);
+                        }
+                        new.new = Some(init);
                     }
                 },
                 PyderiveFieldAttr::MatchArgs(v) => match new.match_args {
@@ -194,6 +240,24 @@ impl<'a> FromIterator<PyderiveFieldAttr> for Result<PyderiveFieldOption<'a>> {
                         new.repr = Some(take_bool!(v));
                     }
                 },
+                PyderiveFieldAttr::ReprMode(v) => match new.repr {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr"));
+                    }
+                    None => {
+                        match v.right.value().as_str() {
+                            "debug" => new.repr_debug = Some(true),
+                            "name" => new.repr_name = Some(true),
+                            _ => {
+                                return Err(syn::Error::new(
+                                    v.right.span(),
+                                    "expected repr=\"debug\", repr=\"name\" or repr=<bool>",
+                                ));
+                            }
+                        }
+                        new.repr = Some(true);
+                    }
+                },
                 PyderiveFieldAttr::Str(v) => match new.str {
                     Some(_) => {
                         return Err(syn::Error::new(extract_ident!(v).span(), "duplicated str"));
@@ -226,7 +290,12 @@ impl<'a> FromIterator<PyderiveFieldAttr> for Result<PyderiveFieldOption<'a>> {
                         ));
                     }
                     None => {
-                        new.kw_only = Some(take_bool!(v));
+                        let span = extract_ident!(v).span();
+                        let kw_only = take_bool!(v);
+                        if kw_only {
+                            kw_only_span = Some(span);
+                        }
+                        new.kw_only = Some(kw_only);
                     }
                 },
                 PyderiveFieldAttr::DataclassField(v) => match new.dataclass_field {
@@ -256,7 +325,12 @@ impl<'a> FromIterator<PyderiveFieldAttr> for Result<PyderiveFieldOption<'a>> {
                         ));
                     }
                     None => {
-                        new.default_factory = Some(take_bool!(v));
+                        let span = extract_ident!(v).span();
+                        let default_factory = take_bool!(v);
+                        if default_factory {
+                            default_factory_span = Some(span);
+                        }
+                        new.default_factory = Some(default_factory);
                     }
                 },
                 PyderiveFieldAttr::Annotation(v) => match new.annotation {
@@ -267,9 +341,277 @@ impl<'a> FromIterator<PyderiveFieldAttr> for Result<PyderiveFieldOption<'a>> {
                         new.annotation = Some(Cow::from(v.right.value()));
                     }
                 },
+                PyderiveFieldAttr::Compare(v) => match new.compare {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated compare",
+                        ));
+                    }
+                    None => {
+                        new.compare = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::NewDefault(v) => match new.new_default {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated new_default",
+                        ));
+                    }
+                    None => {
+                        let span = extract_ident!(v).span();
+                        let new_default = take_bool!(v);
+                        if new_default {
+                            new_default_span = Some(span);
+                        }
+                        new.new_default = Some(new_default);
+                    }
+                },
+                PyderiveFieldAttr::IterOrder(v) => match new.iter_order {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated iter_order"));
+                    }
+                    None => {
+                        new.iter_order = Some(v.right.base10_parse()?);
+                    }
+                },
+                PyderiveFieldAttr::DefaultOnce(v) => match new.default_once {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated default_once"));
+                    }
+                    None => {
+                        default_once_span = Some(v.left.span());
+                        new.default_once = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::IsName(v) => match new.is_name {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated is_name",
+                        ));
+                    }
+                    None => {
+                        new.is_name = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::ReprFloat(v) => match new.repr_float {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr_float"));
+                    }
+                    None => {
+                        new.repr_float = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::TryFrom(v) => match new.try_from {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated try_from"));
+                    }
+                    None => {
+                        new.try_from = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::InitVar(v) => match new.init_var {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated init_var",
+                        ));
+                    }
+                    None => {
+                        new.init_var = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::Children(v) => match new.children {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated children",
+                        ));
+                    }
+                    None => {
+                        new.children = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::PositionalOnly(v) => match new.positional_only {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated positional_only",
+                        ));
+                    }
+                    None => {
+                        new.positional_only = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::PathStr(v) => match new.path_str {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated path_str",
+                        ));
+                    }
+                    None => {
+                        new.path_str = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::SetField(v) => match new.set_field {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated set_field",
+                        ));
+                    }
+                    None => {
+                        new.set_field = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::LenField(v) => match new.len_field {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated len_field",
+                        ));
+                    }
+                    None => {
+                        new.len_field = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::CacheHash(v) => match new.cache_hash {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated cache_hash",
+                        ));
+                    }
+                    None => {
+                        new.cache_hash = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::Compute(v) => match new.compute {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated compute"));
+                    }
+                    None => {
+                        new.compute = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::ClassVar(v) => match new.classvar {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated classvar"));
+                    }
+                    None => {
+                        new.classvar = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::FormatField(v) => match new.format_field {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated format_field",
+                        ));
+                    }
+                    None => {
+                        new.format_field = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::DefaultPy(v) => match new.default_py {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated default_py"));
+                    }
+                    None => {
+                        new.default_py = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::Doc(v) => match new.doc {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated doc"));
+                    }
+                    None => {
+                        new.doc = Some(v.right);
+                    }
+                },
+                PyderiveFieldAttr::CacheRepr(v) => match new.cache_repr {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated cache_repr",
+                        ));
+                    }
+                    None => {
+                        new.cache_repr = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::Set(v) => match new.set {
+                    Some(_) => {
+                        return Err(syn::Error::new(extract_ident!(v).span(), "duplicated set"));
+                    }
+                    None => {
+                        new.set = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::Flatten(v) => match new.flatten {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated flatten",
+                        ));
+                    }
+                    None => {
+                        new.flatten = Some(take_bool!(v));
+                    }
+                },
+                PyderiveFieldAttr::DataclassFlatten(v) => match new.dataclass_flatten {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated dataclass_flatten",
+                        ));
+                    }
+                    None => {
+                        new.dataclass_flatten = Some(take_bool!(v));
+                    }
+                },
             }
         }
 
+        if let (Some(_), Some(span)) = (&new.default, new_default_span) {
+            return Err(syn::Error::new(
+                span,
+                "new_default=true conflicts with default=<expr>",
+            ));
+        }
+        if let (Some(_), Some(span)) = (&new.default, default_once_span) {
+            return Err(syn::Error::new(
+                span,
+                "default_once=<expr> conflicts with default=<expr>",
+            ));
+        }
+        if let (Some(_), Some(span)) = (new_default_span, default_once_span) {
+            return Err(syn::Error::new(
+                span,
+                "default_once=<expr> conflicts with new_default=true",
+            ));
+        }
+        if let (Some(_), Some(span)) = (new_false_span, kw_only_span) {
+            return Err(syn::Error::new(
+                span,
+                "kw_only=true conflicts with new=false",
+            ));
+        }
+        if let (None, None, None, Some(span)) = (
+            &new.default,
+            new_default_span,
+            default_once_span,
+            default_factory_span,
+        ) {
+            return Err(syn::Error::new(
+                span,
+                "default_factory=true requires default=<expr>",
+            ));
+        }
+
         Ok(new)
     }
 }
@@ -278,54 +620,522 @@ impl<'a> TryFrom<&Vec<Attribute>> for PyderiveFieldOption<'a> {
     type Error = syn::Error;
 
     fn try_from(value: &Vec<Attribute>) -> Result<Self> {
-        type Attr = Punctuated<PyderiveFieldAttr, Token![,]>;
-
-        value
-            .iter()
-            .filter(|a| a.path().is_ident("pyderive"))
-            // FIXME:
-            // Should it raise Error when #[pyderive]?
-            // If not, uncomment the following filter_map.
-            // .filter_map(|a| match &a.meta {
-            //     Meta::List(_) => Some(a),
-            //     _ => None,
-            // })
-            // FIXME:
-            // Should it raise Error when #[pyderive]?
-            // If not, comment out the following filter_map.
-            .map(|a| match &a.meta {
-                Meta::List(m) => Ok(m),
-                _ => Err(syn::Error::new(
-                    a.meta.span(),
-                    "supports #[pyderive(..)] form only",
-                )),
-            })
-            .collect::<Result<Vec<_>>>()?
+        parse_pyderive_attrs::<PyderiveFieldAttr>(value)?
             .into_iter()
-            .map(|m| {
-                m.parse_args_with(Attr::parse_terminated)
-                    // FIXME:
-                    // Should it raise Error when #[pyderive()]?
-                    // If not, remove the following and_then.
-                    .and_then(|r| {
-                        if r.is_empty() {
-                            Err(syn::Error::new(
-                                m.span(),
-                                "effects nothing if argument is empty",
-                            ))
-                        } else {
-                            Ok(r)
+            .collect::<Result<Self>>()
+    }
+}
+
+/// What `PyIter` and `PyReversed` yield for each field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IterMode {
+    /// the value
+    Values,
+    /// the tuple of the Python name and the value
+    Items,
+    /// the Python name
+    Keys,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PyderiveStructOption {
+    pub(crate) order_key: Option<Path>,
+    pub(crate) order_reverse: Option<bool>,
+    pub(crate) richcmp_tuple: Option<bool>,
+    pub(crate) str_with: Option<Path>,
+    pub(crate) order: Option<bool>,
+    pub(crate) iter_mode: Option<IterMode>,
+    pub(crate) repr_name: Option<LitStr>,
+    pub(crate) iter_snapshot: Option<bool>,
+    pub(crate) eq_subclass: Option<bool>,
+    pub(crate) str_repr: Option<bool>,
+    pub(crate) rhs: Option<Vec<Type>>,
+    pub(crate) repr_sorted: Option<bool>,
+    pub(crate) int: Option<LitStr>,
+    pub(crate) by_value: Option<bool>,
+    pub(crate) repr_prefix: Option<LitStr>,
+    pub(crate) repr_suffix: Option<LitStr>,
+    pub(crate) signature: Option<Vec<pyderive_struct::SignatureItem>>,
+    pub(crate) repr_depth: Option<LitInt>,
+    pub(crate) eq_fields: Option<bool>,
+    pub(crate) repr_all: Option<bool>,
+    pub(crate) str_all: Option<bool>,
+    pub(crate) iter_all: Option<bool>,
+    pub(crate) len_all: Option<bool>,
+    pub(crate) eq_identity: Option<bool>,
+    pub(crate) hash: Option<bool>,
+    pub(crate) order_nan: Option<LitStr>,
+    pub(crate) strip_underscore: Option<bool>,
+    pub(crate) str_children: Option<LitStr>,
+    pub(crate) frozen_get: Option<bool>,
+    pub(crate) repr_flatten: Option<bool>,
+}
+
+impl PyderiveStructOption {
+    pub(crate) fn order_reverse(&self) -> bool {
+        self.order_reverse.unwrap_or(false)
+    }
+    pub(crate) fn richcmp_tuple(&self) -> bool {
+        self.richcmp_tuple.unwrap_or(false)
+    }
+    pub(crate) fn order(&self) -> bool {
+        self.order.unwrap_or(false)
+    }
+    pub(crate) fn iter_mode(&self) -> IterMode {
+        self.iter_mode.unwrap_or(IterMode::Values)
+    }
+    pub(crate) fn iter_snapshot(&self) -> bool {
+        self.iter_snapshot.unwrap_or(false)
+    }
+    pub(crate) fn eq_subclass(&self) -> bool {
+        self.eq_subclass.unwrap_or(false)
+    }
+    pub(crate) fn str_repr(&self) -> bool {
+        self.str_repr.unwrap_or(false)
+    }
+    pub(crate) fn repr_sorted(&self) -> bool {
+        self.repr_sorted.unwrap_or(false)
+    }
+    pub(crate) fn by_value(&self) -> bool {
+        self.by_value.unwrap_or(false)
+    }
+    pub(crate) fn eq_fields(&self) -> bool {
+        self.eq_fields.unwrap_or(false)
+    }
+    pub(crate) fn repr_all(&self) -> bool {
+        self.repr_all.unwrap_or(false)
+    }
+    pub(crate) fn str_all(&self) -> bool {
+        self.str_all.unwrap_or(false)
+    }
+    pub(crate) fn iter_all(&self) -> bool {
+        self.iter_all.unwrap_or(false)
+    }
+    pub(crate) fn len_all(&self) -> bool {
+        self.len_all.unwrap_or(false)
+    }
+    pub(crate) fn eq_identity(&self) -> bool {
+        self.eq_identity.unwrap_or(false)
+    }
+    pub(crate) fn order_nan_raise(&self) -> bool {
+        self.order_nan
+            .as_ref()
+            .is_some_and(|v| v.value() == "raise")
+    }
+    pub(crate) fn str_children_str(&self) -> bool {
+        self.str_children
+            .as_ref()
+            .is_some_and(|v| v.value() == "str")
+    }
+    pub(crate) fn strip_underscore(&self) -> bool {
+        self.strip_underscore.unwrap_or(false)
+    }
+    pub(crate) fn frozen_get(&self) -> bool {
+        self.frozen_get.unwrap_or(false)
+    }
+    pub(crate) fn repr_flatten(&self) -> bool {
+        self.repr_flatten.unwrap_or(false)
+    }
+}
+
+impl FromIterator<PyderiveStructAttr> for Result<PyderiveStructOption> {
+    fn from_iter<T: IntoIterator<Item = PyderiveStructAttr>>(iter: T) -> Self {
+        let mut new = PyderiveStructOption::default();
+
+        for opt in iter {
+            match opt {
+                PyderiveStructAttr::OrderKey(v) => match new.order_key {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated order_key"));
+                    }
+                    None => {
+                        new.order_key = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::OrderReverse(v) => match new.order_reverse {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated order_reverse",
+                        ));
+                    }
+                    None => {
+                        new.order_reverse = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::RichcmpTuple(v) => match new.richcmp_tuple {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated richcmp_tuple",
+                        ));
+                    }
+                    None => {
+                        new.richcmp_tuple = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::StrWith(v) => match new.str_with {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated str_with"));
+                    }
+                    None => {
+                        new.str_with = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::Order(v) => match new.order {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated order",
+                        ));
+                    }
+                    None => {
+                        new.order = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::Iter(v) => match new.iter_mode {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated iter"));
+                    }
+                    None => {
+                        let mode = match v.right.value().as_str() {
+                            "values" => IterMode::Values,
+                            "items" => IterMode::Items,
+                            "keys" => IterMode::Keys,
+                            _ => {
+                                return Err(syn::Error::new(
+                                    v.right.span(),
+                                    "expected iter=\"values\", iter=\"items\" or iter=\"keys\"",
+                                ))
+                            }
+                        };
+                        new.iter_mode = Some(mode);
+                    }
+                },
+                PyderiveStructAttr::ReprName(v) => match new.repr_name {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr_name"));
+                    }
+                    None => {
+                        new.repr_name = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::IterSnapshot(v) => match new.iter_snapshot {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated iter_snapshot",
+                        ));
+                    }
+                    None => {
+                        new.iter_snapshot = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::EqSubclass(v) => match new.eq_subclass {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated eq_subclass",
+                        ));
+                    }
+                    None => {
+                        new.eq_subclass = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::StrRepr(v) => match new.str_repr {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated str"));
+                    }
+                    None => {
+                        if v.right.value() != "repr" {
+                            return Err(syn::Error::new(v.right.span(), "expected str=\"repr\""));
                         }
-                    })
-            })
-            // Return #[pyderive()]? Err
-            .collect::<Result<Vec<_>>>()?
+                        new.str_repr = Some(true);
+                    }
+                },
+                PyderiveStructAttr::Signature(v) => match new.signature {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated signature"));
+                    }
+                    None => {
+                        new.signature = Some(v.items.into_iter().collect());
+                    }
+                },
+                PyderiveStructAttr::Rhs(v) => match new.rhs {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated rhs"));
+                    }
+                    None => {
+                        if v.types.is_empty() {
+                            return Err(syn::Error::new(
+                                v.left.span(),
+                                "rhs requires at least one type",
+                            ));
+                        }
+                        new.rhs = Some(v.types.into_iter().collect());
+                    }
+                },
+                PyderiveStructAttr::ReprOrder(v) => match new.repr_sorted {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr_order"));
+                    }
+                    None => {
+                        if v.right.value() != "sorted" {
+                            return Err(syn::Error::new(
+                                v.right.span(),
+                                "expected repr_order=\"sorted\"",
+                            ));
+                        }
+                        new.repr_sorted = Some(true);
+                    }
+                },
+                PyderiveStructAttr::Int(v) => match new.int {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated int"));
+                    }
+                    None => {
+                        if !matches!(v.right.value().as_str(), "trunc" | "round") {
+                            return Err(syn::Error::new(
+                                v.right.span(),
+                                "expected int=\"trunc\" or int=\"round\"",
+                            ));
+                        }
+                        new.int = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::ByValue(v) => match new.by_value {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated by_value",
+                        ));
+                    }
+                    None => {
+                        new.by_value = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::ReprPrefix(v) => match new.repr_prefix {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr_prefix"));
+                    }
+                    None => {
+                        new.repr_prefix = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::ReprSuffix(v) => match new.repr_suffix {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr_suffix"));
+                    }
+                    None => {
+                        new.repr_suffix = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::ReprDepth(v) => match new.repr_depth {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated repr_depth"));
+                    }
+                    None => {
+                        new.repr_depth = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::EqFields(v) => match new.eq_fields {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated eq"));
+                    }
+                    None => {
+                        if v.right.value() != "fields" {
+                            return Err(syn::Error::new(v.right.span(), "expected eq=\"fields\""));
+                        }
+                        new.eq_fields = Some(true);
+                    }
+                },
+                PyderiveStructAttr::ReprAll(v) => match new.repr_all {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated repr_all",
+                        ));
+                    }
+                    None => {
+                        new.repr_all = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::StrAll(v) => match new.str_all {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated str_all",
+                        ));
+                    }
+                    None => {
+                        new.str_all = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::IterAll(v) => match new.iter_all {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated iter_all",
+                        ));
+                    }
+                    None => {
+                        new.iter_all = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::LenAll(v) => match new.len_all {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated len_all",
+                        ));
+                    }
+                    None => {
+                        new.len_all = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::EqIdentity(v) => match new.eq_identity {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated eq_identity",
+                        ));
+                    }
+                    None => {
+                        new.eq_identity = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::Hash(v) => match new.hash {
+                    Some(_) => {
+                        return Err(syn::Error::new(extract_ident!(v).span(), "duplicated hash"));
+                    }
+                    None => {
+                        new.hash = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::OrderNan(v) => match new.order_nan {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated order_nan"));
+                    }
+                    None => {
+                        if !matches!(v.right.value().as_str(), "false" | "raise") {
+                            return Err(syn::Error::new(
+                                v.right.span(),
+                                "expected order_nan=\"false\" or order_nan=\"raise\"",
+                            ));
+                        }
+                        new.order_nan = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::StripUnderscore(v) => match new.strip_underscore {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated strip_underscore",
+                        ));
+                    }
+                    None => {
+                        new.strip_underscore = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::StrChildren(v) => match new.str_children {
+                    Some(_) => {
+                        return Err(syn::Error::new(v.left.span(), "duplicated str_children"));
+                    }
+                    None => {
+                        if !matches!(v.right.value().as_str(), "repr" | "str") {
+                            return Err(syn::Error::new(
+                                v.right.span(),
+                                "expected str_children=\"repr\" or str_children=\"str\"",
+                            ));
+                        }
+                        new.str_children = Some(v.right);
+                    }
+                },
+                PyderiveStructAttr::FrozenGet(v) => match new.frozen_get {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated frozen_get",
+                        ));
+                    }
+                    None => {
+                        new.frozen_get = Some(take_bool!(v));
+                    }
+                },
+                PyderiveStructAttr::ReprFlatten(v) => match new.repr_flatten {
+                    Some(_) => {
+                        return Err(syn::Error::new(
+                            extract_ident!(v).span(),
+                            "duplicated repr_flatten",
+                        ));
+                    }
+                    None => {
+                        new.repr_flatten = Some(take_bool!(v));
+                    }
+                },
+            }
+        }
+
+        Ok(new)
+    }
+}
+
+impl TryFrom<&Vec<Attribute>> for PyderiveStructOption {
+    type Error = syn::Error;
+
+    fn try_from(value: &Vec<Attribute>) -> Result<Self> {
+        parse_pyderive_attrs::<PyderiveStructAttr>(value)?
             .into_iter()
-            .flatten()
             .collect::<Result<Self>>()
     }
 }
 
+fn parse_pyderive_attrs<T: Parse>(value: &[Attribute]) -> Result<Vec<T>> {
+    type Attr<T> = Punctuated<T, Token![,]>;
+
+    value
+        .iter()
+        .filter(|a| a.path().is_ident("pyderive"))
+        // FIXME:
+        // Should it raise Error when #[pyderive]?
+        // If not, uncomment the following filter_map.
+        // .filter_map(|a| match &a.meta {
+        //     Meta::List(_) => Some(a),
+        //     _ => None,
+        // })
+        // FIXME:
+        // Should it raise Error when #[pyderive]?
+        // If not, comment out the following filter_map.
+        .map(|a| match &a.meta {
+            Meta::List(m) => Ok(m),
+            _ => Err(syn::Error::new(
+                a.meta.span(),
+                "supports #[pyderive(..)] form only",
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .map(|m| {
+            m.parse_args_with(Attr::<T>::parse_terminated)
+                // FIXME:
+                // Should it raise Error when #[pyderive()]?
+                // If not, remove the following and_then.
+                .and_then(|r| {
+                    if r.is_empty() {
+                        Err(syn::Error::new(
+                            m.span(),
+                            "effects nothing if argument is empty",
+                        ))
+                    } else {
+                        Ok(r)
+                    }
+                })
+        })
+        // Return #[pyderive()]? Err
+        .collect::<Result<Vec<_>>>()
+        .map(|v| v.into_iter().flatten().collect())
+}
+
 // pyo3 struct
 pub(crate) mod pyo3_struct {
     use super::*;
@@ -334,6 +1144,7 @@ pub(crate) mod pyo3_struct {
         // all supporting option
         syn::custom_keyword!(get_all);
         syn::custom_keyword!(set_all);
+        syn::custom_keyword!(frozen);
         syn::custom_keyword!(rename_all);
     }
 
@@ -393,6 +1204,8 @@ pub(crate) mod pyo3_struct {
         Get(kw::get_all),
         #[allow(dead_code)]
         Set(kw::set_all),
+        #[allow(dead_code)]
+        Frozen(kw::frozen),
         Rename {
             #[allow(dead_code)]
             path: kw::rename_all,
@@ -409,6 +1222,8 @@ pub(crate) mod pyo3_struct {
                 Ok(Self::Get(input.parse()?))
             } else if input.peek(kw::set_all) {
                 Ok(Self::Set(input.parse()?))
+            } else if input.peek(kw::frozen) {
+                Ok(Self::Frozen(input.parse()?))
             } else if input.peek(kw::rename_all) {
                 Ok(Self::Rename {
                     path: input.parse()?,
@@ -502,6 +1317,29 @@ pub(crate) mod pyderive_field {
         syn::custom_keyword!(default);
         syn::custom_keyword!(default_factory);
         syn::custom_keyword!(annotation);
+        syn::custom_keyword!(compare);
+        syn::custom_keyword!(new_default);
+        syn::custom_keyword!(iter_order);
+        syn::custom_keyword!(default_once);
+        syn::custom_keyword!(is_name);
+        syn::custom_keyword!(repr_float);
+        syn::custom_keyword!(try_from);
+        syn::custom_keyword!(init_var);
+        syn::custom_keyword!(children);
+        syn::custom_keyword!(positional_only);
+        syn::custom_keyword!(path_str);
+        syn::custom_keyword!(set_field);
+        syn::custom_keyword!(len_field);
+        syn::custom_keyword!(cache_hash);
+        syn::custom_keyword!(compute);
+        syn::custom_keyword!(classvar);
+        syn::custom_keyword!(format_field);
+        syn::custom_keyword!(default_py);
+        syn::custom_keyword!(doc);
+        syn::custom_keyword!(cache_repr);
+        syn::custom_keyword!(set);
+        syn::custom_keyword!(flatten);
+        syn::custom_keyword!(dataclass_flatten);
     }
 
     #[derive(Debug)]
@@ -543,6 +1381,7 @@ pub(crate) mod pyderive_field {
         Init(OptionFieldAttr<kw::new, LitBool>),
         MatchArgs(OptionFieldAttr<kw::match_args, LitBool>),
         Repr(OptionFieldAttr<kw::repr, LitBool>),
+        ReprMode(ExprAssignGeneric<kw::repr, LitStr>),
         Str(OptionFieldAttr<kw::str, LitBool>),
         Iter(OptionFieldAttr<kw::iter, LitBool>),
         Len(OptionFieldAttr<kw::len, LitBool>),
@@ -551,6 +1390,29 @@ pub(crate) mod pyderive_field {
         Default(ExprAssign),
         DefaultFactory(OptionFieldAttr<kw::default_factory, LitBool>),
         Annotation(ExprAssignGeneric<kw::annotation, LitStr>),
+        Compare(OptionFieldAttr<kw::compare, LitBool>),
+        NewDefault(OptionFieldAttr<kw::new_default, LitBool>),
+        IterOrder(ExprAssignGeneric<kw::iter_order, LitInt>),
+        DefaultOnce(ExprAssignGeneric<kw::default_once, Expr>),
+        IsName(OptionFieldAttr<kw::is_name, LitBool>),
+        ReprFloat(ExprAssignGeneric<kw::repr_float, LitStr>),
+        TryFrom(ExprAssignGeneric<kw::try_from, Type>),
+        InitVar(OptionFieldAttr<kw::init_var, LitBool>),
+        Children(OptionFieldAttr<kw::children, LitBool>),
+        PositionalOnly(OptionFieldAttr<kw::positional_only, LitBool>),
+        PathStr(OptionFieldAttr<kw::path_str, LitBool>),
+        SetField(OptionFieldAttr<kw::set_field, LitBool>),
+        LenField(OptionFieldAttr<kw::len_field, LitBool>),
+        CacheHash(OptionFieldAttr<kw::cache_hash, LitBool>),
+        Compute(ExprAssignGeneric<kw::compute, Expr>),
+        ClassVar(ExprAssignGeneric<kw::classvar, Expr>),
+        FormatField(OptionFieldAttr<kw::format_field, LitBool>),
+        DefaultPy(ExprAssignGeneric<kw::default_py, LitStr>),
+        Doc(ExprAssignGeneric<kw::doc, LitStr>),
+        CacheRepr(OptionFieldAttr<kw::cache_repr, LitBool>),
+        Set(OptionFieldAttr<kw::set, LitBool>),
+        Flatten(OptionFieldAttr<kw::flatten, LitBool>),
+        DataclassFlatten(OptionFieldAttr<kw::dataclass_flatten, LitBool>),
     }
 
     impl Parse for PyderiveFieldAttr {
@@ -561,7 +1423,11 @@ pub(crate) mod pyderive_field {
             } else if lookahead.peek(kw::match_args) {
                 Ok(Self::MatchArgs(input.parse()?))
             } else if lookahead.peek(kw::repr) {
-                Ok(Self::Repr(input.parse()?))
+                if input.peek2(Token![=]) && input.peek3(LitStr) {
+                    Ok(Self::ReprMode(input.parse()?))
+                } else {
+                    Ok(Self::Repr(input.parse()?))
+                }
             } else if lookahead.peek(kw::str) {
                 Ok(Self::Str(input.parse()?))
             } else if lookahead.peek(kw::iter) {
@@ -578,6 +1444,263 @@ pub(crate) mod pyderive_field {
                 Ok(Self::DefaultFactory(input.parse()?))
             } else if lookahead.peek(kw::annotation) {
                 Ok(Self::Annotation(input.parse()?))
+            } else if lookahead.peek(kw::compare) {
+                Ok(Self::Compare(input.parse()?))
+            } else if lookahead.peek(kw::new_default) {
+                Ok(Self::NewDefault(input.parse()?))
+            } else if lookahead.peek(kw::iter_order) {
+                Ok(Self::IterOrder(input.parse()?))
+            } else if lookahead.peek(kw::default_once) {
+                Ok(Self::DefaultOnce(input.parse()?))
+            } else if lookahead.peek(kw::is_name) {
+                Ok(Self::IsName(input.parse()?))
+            } else if lookahead.peek(kw::repr_float) {
+                Ok(Self::ReprFloat(input.parse()?))
+            } else if lookahead.peek(kw::try_from) {
+                Ok(Self::TryFrom(input.parse()?))
+            } else if lookahead.peek(kw::init_var) {
+                Ok(Self::InitVar(input.parse()?))
+            } else if lookahead.peek(kw::children) {
+                Ok(Self::Children(input.parse()?))
+            } else if lookahead.peek(kw::positional_only) {
+                Ok(Self::PositionalOnly(input.parse()?))
+            } else if lookahead.peek(kw::path_str) {
+                Ok(Self::PathStr(input.parse()?))
+            } else if lookahead.peek(kw::set_field) {
+                Ok(Self::SetField(input.parse()?))
+            } else if lookahead.peek(kw::len_field) {
+                Ok(Self::LenField(input.parse()?))
+            } else if lookahead.peek(kw::cache_hash) {
+                Ok(Self::CacheHash(input.parse()?))
+            } else if lookahead.peek(kw::compute) {
+                Ok(Self::Compute(input.parse()?))
+            } else if lookahead.peek(kw::classvar) {
+                Ok(Self::ClassVar(input.parse()?))
+            } else if lookahead.peek(kw::format_field) {
+                Ok(Self::FormatField(input.parse()?))
+            } else if lookahead.peek(kw::default_py) {
+                Ok(Self::DefaultPy(input.parse()?))
+            } else if lookahead.peek(kw::doc) {
+                Ok(Self::Doc(input.parse()?))
+            } else if lookahead.peek(kw::cache_repr) {
+                Ok(Self::CacheRepr(input.parse()?))
+            } else if lookahead.peek(kw::set) {
+                Ok(Self::Set(input.parse()?))
+            } else if lookahead.peek(kw::flatten) {
+                Ok(Self::Flatten(input.parse()?))
+            } else if lookahead.peek(kw::dataclass_flatten) {
+                Ok(Self::DataclassFlatten(input.parse()?))
+            } else {
+                Err(lookahead.error())
+            }
+        }
+    }
+}
+
+// pyderive struct
+pub(crate) mod pyderive_struct {
+    use super::*;
+
+    mod kw {
+        syn::custom_keyword!(order_key);
+        syn::custom_keyword!(order_reverse);
+        syn::custom_keyword!(richcmp_tuple);
+        syn::custom_keyword!(str_with);
+        syn::custom_keyword!(order);
+        syn::custom_keyword!(iter);
+        syn::custom_keyword!(repr_name);
+        syn::custom_keyword!(iter_snapshot);
+        syn::custom_keyword!(eq_subclass);
+        syn::custom_keyword!(str);
+        syn::custom_keyword!(rhs);
+        syn::custom_keyword!(repr_order);
+        syn::custom_keyword!(int);
+        syn::custom_keyword!(by_value);
+        syn::custom_keyword!(repr_prefix);
+        syn::custom_keyword!(repr_suffix);
+        syn::custom_keyword!(signature);
+        syn::custom_keyword!(repr_depth);
+        syn::custom_keyword!(eq);
+        syn::custom_keyword!(repr_all);
+        syn::custom_keyword!(str_all);
+        syn::custom_keyword!(iter_all);
+        syn::custom_keyword!(len_all);
+        syn::custom_keyword!(eq_identity);
+        syn::custom_keyword!(hash);
+        syn::custom_keyword!(order_nan);
+        syn::custom_keyword!(strip_underscore);
+        syn::custom_keyword!(str_children);
+        syn::custom_keyword!(frozen_get);
+        syn::custom_keyword!(repr_flatten);
+    }
+
+    #[derive(Debug)]
+    pub(crate) enum PyderiveStructAttr {
+        OrderKey(ExprAssignGeneric<kw::order_key, Path>),
+        OrderReverse(OptionFieldAttr<kw::order_reverse, LitBool>),
+        RichcmpTuple(OptionFieldAttr<kw::richcmp_tuple, LitBool>),
+        StrWith(ExprAssignGeneric<kw::str_with, Path>),
+        Order(OptionFieldAttr<kw::order, LitBool>),
+        Iter(ExprAssignGeneric<kw::iter, LitStr>),
+        ReprName(ExprAssignGeneric<kw::repr_name, LitStr>),
+        IterSnapshot(OptionFieldAttr<kw::iter_snapshot, LitBool>),
+        EqSubclass(OptionFieldAttr<kw::eq_subclass, LitBool>),
+        StrRepr(ExprAssignGeneric<kw::str, LitStr>),
+        Rhs(TypeList<kw::rhs>),
+        ReprOrder(ExprAssignGeneric<kw::repr_order, LitStr>),
+        Int(ExprAssignGeneric<kw::int, LitStr>),
+        ByValue(OptionFieldAttr<kw::by_value, LitBool>),
+        ReprPrefix(ExprAssignGeneric<kw::repr_prefix, LitStr>),
+        ReprSuffix(ExprAssignGeneric<kw::repr_suffix, LitStr>),
+        Signature(SignatureAttr<kw::signature>),
+        ReprDepth(ExprAssignGeneric<kw::repr_depth, LitInt>),
+        EqFields(ExprAssignGeneric<kw::eq, LitStr>),
+        ReprAll(OptionFieldAttr<kw::repr_all, LitBool>),
+        StrAll(OptionFieldAttr<kw::str_all, LitBool>),
+        IterAll(OptionFieldAttr<kw::iter_all, LitBool>),
+        LenAll(OptionFieldAttr<kw::len_all, LitBool>),
+        EqIdentity(OptionFieldAttr<kw::eq_identity, LitBool>),
+        Hash(OptionFieldAttr<kw::hash, LitBool>),
+        OrderNan(ExprAssignGeneric<kw::order_nan, LitStr>),
+        StripUnderscore(OptionFieldAttr<kw::strip_underscore, LitBool>),
+        StrChildren(ExprAssignGeneric<kw::str_children, LitStr>),
+        FrozenGet(OptionFieldAttr<kw::frozen_get, LitBool>),
+        ReprFlatten(OptionFieldAttr<kw::repr_flatten, LitBool>),
+    }
+
+    /// An item of `#[pyderive(signature = (..))]`, a subset of `#[pyo3(signature = (..))]`.
+    #[derive(Debug, Clone)]
+    pub(crate) enum SignatureItem {
+        /// `/`, the preceding arguments are positional-only
+        Slash(Token![/]),
+        /// `*`, the following arguments are keyword-only
+        Star(Token![*]),
+        /// `name` or `name = <expr>`
+        Arg(Ident, Option<Expr>),
+    }
+
+    impl Parse for SignatureItem {
+        fn parse(input: ParseStream) -> Result<Self> {
+            if input.peek(Token![/]) {
+                Ok(Self::Slash(input.parse()?))
+            } else if input.peek(Token![*]) {
+                let star: Token![*] = input.parse()?;
+                if input.peek(Token![*]) || input.peek(Ident) {
+                    return Err(syn::Error::new(
+                        star.span(),
+                        "signature does not support *args and **kwargs",
+                    ));
+                }
+                Ok(Self::Star(star))
+            } else {
+                let name = input.parse()?;
+                let default = if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    Some(input.parse()?)
+                } else {
+                    None
+                };
+                Ok(Self::Arg(name, default))
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct SignatureAttr<K> {
+        pub(crate) left: K,
+        pub(crate) items: Punctuated<SignatureItem, syn::token::Comma>,
+    }
+
+    impl<K: Parse> Parse for SignatureAttr<K> {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let left = input.parse()?;
+            input.parse::<Token![=]>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let items = content.parse_terminated(SignatureItem::parse, Token![,])?;
+            Ok(Self { left, items })
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct TypeList<K> {
+        pub(crate) left: K,
+        pub(crate) types: Punctuated<Type, syn::token::Comma>,
+    }
+
+    impl<K: Parse> Parse for TypeList<K> {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let left = input.parse()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let types = content.parse_terminated(Type::parse, Token![,])?;
+            Ok(Self { left, types })
+        }
+    }
+
+    impl Parse for PyderiveStructAttr {
+        fn parse(input: ParseStream) -> Result<Self> {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::order_key) {
+                Ok(Self::OrderKey(input.parse()?))
+            } else if lookahead.peek(kw::order_reverse) {
+                Ok(Self::OrderReverse(input.parse()?))
+            } else if lookahead.peek(kw::richcmp_tuple) {
+                Ok(Self::RichcmpTuple(input.parse()?))
+            } else if lookahead.peek(kw::str_with) {
+                Ok(Self::StrWith(input.parse()?))
+            } else if lookahead.peek(kw::order) {
+                Ok(Self::Order(input.parse()?))
+            } else if lookahead.peek(kw::iter) {
+                Ok(Self::Iter(input.parse()?))
+            } else if lookahead.peek(kw::repr_name) {
+                Ok(Self::ReprName(input.parse()?))
+            } else if lookahead.peek(kw::iter_snapshot) {
+                Ok(Self::IterSnapshot(input.parse()?))
+            } else if lookahead.peek(kw::eq_subclass) {
+                Ok(Self::EqSubclass(input.parse()?))
+            } else if lookahead.peek(kw::str) {
+                Ok(Self::StrRepr(input.parse()?))
+            } else if lookahead.peek(kw::rhs) {
+                Ok(Self::Rhs(input.parse()?))
+            } else if lookahead.peek(kw::repr_order) {
+                Ok(Self::ReprOrder(input.parse()?))
+            } else if lookahead.peek(kw::int) {
+                Ok(Self::Int(input.parse()?))
+            } else if lookahead.peek(kw::by_value) {
+                Ok(Self::ByValue(input.parse()?))
+            } else if lookahead.peek(kw::repr_prefix) {
+                Ok(Self::ReprPrefix(input.parse()?))
+            } else if lookahead.peek(kw::repr_suffix) {
+                Ok(Self::ReprSuffix(input.parse()?))
+            } else if lookahead.peek(kw::signature) {
+                Ok(Self::Signature(input.parse()?))
+            } else if lookahead.peek(kw::repr_depth) {
+                Ok(Self::ReprDepth(input.parse()?))
+            } else if lookahead.peek(kw::eq) {
+                Ok(Self::EqFields(input.parse()?))
+            } else if lookahead.peek(kw::repr_all) {
+                Ok(Self::ReprAll(input.parse()?))
+            } else if lookahead.peek(kw::str_all) {
+                Ok(Self::StrAll(input.parse()?))
+            } else if lookahead.peek(kw::iter_all) {
+                Ok(Self::IterAll(input.parse()?))
+            } else if lookahead.peek(kw::len_all) {
+                Ok(Self::LenAll(input.parse()?))
+            } else if lookahead.peek(kw::eq_identity) {
+                Ok(Self::EqIdentity(input.parse()?))
+            } else if lookahead.peek(kw::hash) {
+                Ok(Self::Hash(input.parse()?))
+            } else if lookahead.peek(kw::order_nan) {
+                Ok(Self::OrderNan(input.parse()?))
+            } else if lookahead.peek(kw::strip_underscore) {
+                Ok(Self::StripUnderscore(input.parse()?))
+            } else if lookahead.peek(kw::str_children) {
+                Ok(Self::StrChildren(input.parse()?))
+            } else if lookahead.peek(kw::frozen_get) {
+                Ok(Self::FrozenGet(input.parse()?))
+            } else if lookahead.peek(kw::repr_flatten) {
+                Ok(Self::ReprFlatten(input.parse()?))
             } else {
                 Err(lookahead.error())
             }
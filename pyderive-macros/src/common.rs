@@ -1,13 +1,16 @@
 use std::borrow::Cow;
 
-use quote::format_ident;
+use quote::{quote, quote_spanned};
 use syn::{
-    punctuated::Punctuated, spanned::Spanned, Data, DataEnum, DataStruct, DataUnion, DeriveInput,
-    Expr, Field, Fields, FieldsNamed, Ident, Path, Result, Token, Type, TypePath,
+    parse_quote, punctuated::Punctuated, spanned::Spanned, AngleBracketedGenericArguments, Data,
+    DataEnum, DataStruct, DataUnion, DeriveInput, Expr, Field, Fields, FieldsNamed,
+    GenericArgument, Ident, LitStr, Path, PathArguments, Result, Token, Type, TypePath,
+    TypeReference,
 };
 
 use crate::attr::{
-    pyo3_struct::RenamingRule, PyderiveFieldOption, Pyo3FieldOption, Pyo3StructOption,
+    is_pyclass, pyo3_struct::RenamingRule, PyderiveFieldOption, PyderiveStructOption,
+    Pyo3FieldOption, Pyo3StructOption,
 };
 
 pub(crate) fn is_py(ty: &Type) -> bool {
@@ -19,13 +22,396 @@ pub(crate) fn is_py(ty: &Type) -> bool {
             let mut iter = segments.iter();
             match iter.next() {
                 Some(first) if first.ident.eq("pyo3") => match iter.next() {
-                    Some(second) if second.ident.eq("Py") => iter.next().is_none(),
+                    Some(second) if second.ident.eq("Py") || second.ident.eq("PyObject") => {
+                        iter.next().is_none()
+                    }
                     _ => false,
                 },
-                Some(first) if first.ident.eq("Py") => iter.next().is_none(),
+                // PyObject is a deprecated alias of Py<PyAny>
+                Some(first) if first.ident.eq("Py") || first.ident.eq("PyObject") => {
+                    iter.next().is_none()
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the type is `String`, `Cow<str>` or `&str`,
+/// which are borrowed as `&str`.
+pub(crate) fn is_str(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) if last.ident.eq("String") => last.arguments.is_none(),
+            Some(last) if last.ident.eq("Cow") => match &last.arguments {
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                    args.iter().any(|arg| match arg {
+                        GenericArgument::Type(ty) => is_primitive_str(ty),
+                        _ => false,
+                    })
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        Type::Reference(TypeReference { elem, .. }) => is_primitive_str(elem),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the type is `PathBuf`, `Box<Path>`, `Cow<Path>` or `&Path`,
+/// which are borrowed as `&Path`.
+pub(crate) fn is_path(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) if last.ident.eq("PathBuf") => last.arguments.is_none(),
+            Some(last) if last.ident.eq("Box") || last.ident.eq("Cow") => match &last.arguments {
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                    args.iter().any(|arg| match arg {
+                        GenericArgument::Type(ty) => is_primitive_path(ty),
+                        _ => false,
+                    })
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        Type::Reference(TypeReference { elem, .. }) => is_primitive_path(elem),
+        _ => false,
+    }
+}
+
+/// Returns an error if the struct derives another macro that defines the `__richcmp__()` slot.
+///
+/// PyO3 merges `__eq__()` and `__lt__()` etc. into one `__richcmp__()` slot,
+/// thus at most one of them is allowed.
+/// A derive macro sees the `#[derive(..)]` attributes placed after its own only,
+/// so that the conflict in the same `#[derive(..)]` is left to PyO3.
+pub(crate) fn check_richcmp_conflict(input: &DeriveInput, derive: &str) -> Result<()> {
+    const RICHCMP: [&str; 5] = ["PyEq", "PyOrd", "PyRichCmp", "PyDataclass", "PyComparable"];
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("derive")) {
+        let paths = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+        for path in paths {
+            let Some(last) = path.segments.last() else {
+                continue;
+            };
+            if last.ident != derive && RICHCMP.iter().any(|name| last.ident == name) {
+                return Err(syn::Error::new(
+                    path.span(),
+                    format!(
+                        "{} conflicts with {}, both define the __richcmp__() slot, \
+                         derive PyRichCmp alone for both equality and ordering",
+                        derive, last.ident
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns an associated const whose duplicated definitions tell the conflict
+/// of the derive macros that define the `__richcmp__()` slot,
+/// which works even if they are in the same `#[derive(..)]`.
+pub(crate) fn richcmp_slot_marker(struct_name: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        #[automatically_derived]
+        impl #struct_name {
+            #[doc(hidden)]
+            #[allow(non_upper_case_globals)]
+            const __pyderive_derive_only_one_of_PyEq_PyOrd_PyRichCmp_PyDataclass: () = ();
+        }
+    }
+}
+
+/// Returns the `bool` expression of whether `partial_cmp` (an `Option<Ordering>` expression) matches `pattern`.
+///
+/// If `raise` (`#[pyderive(order_nan="raise")]`), the unordered `None` raises `ValueError`
+/// by `?` instead of `false`.
+pub(crate) fn ordered(
+    partial_cmp: &proc_macro2::TokenStream,
+    pattern: proc_macro2::TokenStream,
+    raise: bool,
+) -> proc_macro2::TokenStream {
+    if raise {
+        quote! {
+            match #partial_cmp {
+                ::std::option::Option::Some(ordering) => matches!(ordering, #pattern),
+                ::std::option::Option::None => {
+                    return ::pyo3::PyResult::Err(::pyo3::exceptions::PyValueError::new_err(
+                        "the instances are unordered, e.g. by a NaN field",
+                    ));
+                }
+            }
+        }
+    } else {
+        quote! { matches!(#partial_cmp, ::std::option::Option::Some(#pattern)) }
+    }
+}
+
+/// Returns the `default` expression typed by `ty`, so that a mismatch points at the default.
+///
+/// PyO3 takes both `T` and `Option<T>` for the default of an `Option<T>` argument,
+/// thus it is converted by `Into` in that case.
+pub(crate) fn typed_default(ty: &Type, default: &Expr) -> proc_macro2::TokenStream {
+    if option_inner(ty).is_some() {
+        quote_spanned! { default.span()=> { let default: #ty = ::std::convert::Into::into(#default); default } }
+    } else {
+        quote_spanned! { default.span()=> { let default: #ty = #default; default } }
+    }
+}
+
+/// Returns `true` if the type is `SystemTime`.
+pub(crate) fn is_system_time(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) => last.ident.eq("SystemTime") && last.arguments.is_none(),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns `true` if the type is `DateTime<Tz>`, `NaiveDateTime`, `NaiveDate` or `NaiveTime` of `chrono`
+/// under the `chrono` feature.
+pub(crate) fn is_chrono(ty: &Type) -> bool {
+    if !cfg!(feature = "chrono") {
+        return false;
+    }
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) if last.ident.eq("DateTime") => !last.arguments.is_none(),
+            Some(last) => {
+                ["NaiveDateTime", "NaiveDate", "NaiveTime"]
+                    .iter()
+                    .any(|name| last.ident.eq(name))
+                    && last.arguments.is_none()
+            }
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns `true` if the type is `f64` or `f32`.
+pub(crate) fn is_float(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath { qself: None, path }) => path.is_ident("f64") || path.is_ident("f32"),
+        _ => false,
+    }
+}
+
+/// Returns `T` if the type is `PhantomData<T>`.
+pub(crate) fn phantom_data_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, &["PhantomData"])
+}
+
+/// Returns `T` if the type is `Box<T>`, `Arc<T>` or `Rc<T>`.
+pub(crate) fn smart_pointer_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, &["Box", "Arc", "Rc"])
+}
+
+/// Returns `T` if the type is `Py<T>`.
+pub(crate) fn py_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, &["Py"])
+}
+
+/// Returns `true` if the type refers to a Python object, e.g. `Py<T>`, `Option<Py<T>>` or `Vec<PyObject>`,
+/// which `#[pyclass(frozen)]` does not freeze.
+pub(crate) fn contains_py(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) if last.ident.eq("Py") || last.ident.eq("PyObject") => true,
+            Some(last) => match &last.arguments {
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                    args.iter().any(|arg| match arg {
+                        GenericArgument::Type(ty) => contains_py(ty),
+                        _ => false,
+                    })
+                }
                 _ => false,
+            },
+            None => false,
+        },
+        Type::Tuple(tuple) => tuple.elems.iter().any(contains_py),
+        Type::Array(array) => contains_py(&array.elem),
+        Type::Slice(slice) => contains_py(&slice.elem),
+        Type::Reference(TypeReference { elem, .. }) => contains_py(elem),
+        Type::Paren(paren) => contains_py(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Returns `T` if the type is `Option<T>`.
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, &["Option"])
+}
+
+/// Returns `(T, E)` if the type is `Result<T, E>`.
+pub(crate) fn result_inner(ty: &Type) -> Option<(&Type, &Type)> {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) if last.ident == "Result" => match &last.arguments {
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                    match (args.first(), args.last()) {
+                        (Some(GenericArgument::Type(ok)), Some(GenericArgument::Type(err)))
+                            if args.len() == 2 =>
+                        {
+                            Some((ok, err))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns `T` if the type is one of `names` with a single type argument `T`.
+fn generic_inner<'a>(ty: &'a Type, names: &[&str]) -> Option<&'a Type> {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { ref segments, .. },
+        }) => match segments.last() {
+            Some(last) if names.iter().any(|name| last.ident.eq(name)) => match &last.arguments {
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                    match args.first() {
+                        Some(GenericArgument::Type(ty)) if args.len() == 1 => Some(ty),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The Rust ident of the Python name, e.g. the argument of __new__(),
+// a keyword is a raw ident, and None if the name is not an identifier, e.g. kebab-case.
+fn py_ident(name: &str) -> Option<Ident> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+    if !valid {
+        return None;
+    }
+    syn::parse_str::<Ident>(name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{name}")))
+        .ok()
+}
+
+// The expression of #[pyderive(default_py="<expr>")], that uses `py` and `?`.
+fn default_py(lit: &LitStr, ty: &Type) -> Result<Expr> {
+    // the soft keywords, e.g. `match`, are not leading names of an expression
+    const KEYWORDS: [&str; 7] = ["None", "True", "False", "not", "lambda", "await", "yield"];
+
+    let code = lit.value();
+    if code.contains('\0') {
+        return Err(syn::Error::new(
+            lit.span(),
+            "default_py must not contain a null char",
+        ));
+    }
+    // the leading dotted name, e.g. `os.path.join` of `os.path.join('a', 'b')`
+    let name = code
+        .trim_start()
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .next()
+        .unwrap_or_default();
+    if name.is_empty() {
+        return Err(syn::Error::new(
+            lit.span(),
+            "default_py must be a Python expression",
+        ));
+    }
+    let mut segments = name.split('.').take_while(|s| !s.is_empty());
+    let module = segments.next().unwrap_or_default();
+    let attrs = segments.collect::<Vec<_>>();
+
+    let import = if module.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && !KEYWORDS.contains(&module)
+    {
+        quote! {
+            // not a module, e.g. a builtin
+            if !::pyo3::types::PyAnyMethods::hasattr(
+                ::pyo3::types::PyModule::import(py, "builtins")?.as_any(),
+                #module,
+            )? {
+                let mut module = ::pyo3::types::PyModule::import(py, #module)?;
+                globals.set_item(#module, &module)?;
+                // a submodule is imported unless the parent imports it, like `import pkg.sub`
+                let mut name = ::std::string::String::from(#module);
+                for attr in [#(#attrs),*] {
+                    name.push('.');
+                    name.push_str(attr);
+                    module = match ::pyo3::types::PyAnyMethods::getattr(module.as_any(), attr) {
+                        ::pyo3::PyResult::Ok(obj) => match obj.cast_into::<::pyo3::types::PyModule>() {
+                            ::std::result::Result::Ok(module) => module,
+                            ::std::result::Result::Err(_) => break,
+                        },
+                        ::pyo3::PyResult::Err(_) => ::pyo3::types::PyModule::import(py, name.as_str())?,
+                    };
+                }
             }
         }
+    } else {
+        quote! {}
+    };
+
+    Ok(parse_quote! {
+        {
+            let globals = ::pyo3::types::PyDict::new(py);
+            #import
+            let code = ::std::ffi::CString::new(#code)
+                .map_err(|e| ::pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+            ::pyo3::types::PyAnyMethods::extract::<#ty>(&py.eval(&code, ::std::option::Option::Some(&globals), ::std::option::Option::None)?)?
+        }
+    })
+}
+
+fn is_primitive_path(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath {
+            qself: None,
+            path: Path { segments, .. },
+        }) => match segments.last() {
+            Some(last) => last.ident.eq("Path") && last.arguments.is_none(),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_primitive_str(ty: &Type) -> bool {
+    match &ty {
+        Type::Path(TypePath { qself: None, path }) => path.is_ident("str"),
         _ => false,
     }
 }
@@ -37,6 +423,8 @@ pub(crate) struct FieldData<'a> {
     pub(crate) field: Field,
     pub(crate) get: bool,
     pub(crate) set: bool,
+    // #[pyderive(frozen_get)] of #[pyclass(frozen)], fields are treated as readable except the hidden fields
+    frozen_get: bool,
     // String -> Some(String) to support Tuple struct
     pub(crate) pyname: Cow<'a, str>,
     // String -> Some(Ident) to support Tuple struct
@@ -52,41 +440,146 @@ pub(crate) struct FieldData<'a> {
     pub(crate) default: Option<Expr>,
     default_factory: Option<bool>,
     pub(crate) annotation: Option<Cow<'a, str>>,
+    compare: Option<bool>,
+    pub(crate) iter_order: Option<usize>,
+    is_name: Option<bool>,
+    pub(crate) repr_float: Option<LitStr>,
+    pub(crate) try_from: Option<Type>,
+    init_var: Option<bool>,
+    children: Option<bool>,
+    positional_only: Option<bool>,
+    path_str: Option<bool>,
+    set_field: Option<bool>,
+    len_field: Option<bool>,
+    cache_hash: Option<bool>,
+    pub(crate) compute: Option<Expr>,
+    pub(crate) classvar: Option<Expr>,
+    format_field: Option<bool>,
+    repr_debug: Option<bool>,
+    repr_name: Option<bool>,
+    pub(crate) doc: Option<LitStr>,
+    cache_repr: Option<bool>,
+    flatten: Option<bool>,
+    dataclass_flatten: Option<bool>,
+    hidden: bool,
 }
 
 impl FieldData<'_> {
     #[allow(clippy::wrong_self_convention)]
     #[allow(clippy::new_ret_no_self)]
     pub(crate) fn new(&self) -> bool {
-        self.new.unwrap_or(true)
+        self.new
+            .unwrap_or(!self.hidden() && self.compute.is_none() && !self.flatten())
+    }
+    /// The type of the argument of `__new__()`.
+    pub(crate) fn arg_type(&self) -> &Type {
+        match phantom_data_inner(&self.field.ty) {
+            Some(inner) if self.init_var() => inner,
+            _ => self.try_from.as_ref().unwrap_or(&self.field.ty),
+        }
     }
-    pub(crate) fn match_args(&self) -> bool {
-        self.match_args.unwrap_or(self.get)
+    /// `positional` is whether the field is a positional argument of `__new__()`
+    pub(crate) fn match_args(&self, positional: bool) -> bool {
+        self.match_args.unwrap_or(self.get && positional)
     }
     pub(crate) fn repr(&self) -> bool {
-        self.repr.unwrap_or(self.get || self.set)
+        self.repr
+            .unwrap_or(self.get || self.set || (self.frozen_get && !self.hidden()))
     }
     pub(crate) fn str(&self) -> bool {
-        self.str.unwrap_or(self.get || self.set)
+        self.str
+            .unwrap_or(self.get || self.set || (self.frozen_get && !self.hidden()))
     }
     pub(crate) fn iter(&self) -> bool {
-        self.iter.unwrap_or(self.get)
+        self.iter
+            .unwrap_or(self.get || (self.frozen_get && !self.hidden()))
     }
     pub(crate) fn len(&self) -> bool {
-        self.len.unwrap_or(self.get)
+        self.len
+            .unwrap_or(self.get || (self.frozen_get && !self.hidden()))
     }
     pub(crate) fn kw_only(&self) -> bool {
         self.kw_only.unwrap_or(false)
     }
     pub(crate) fn dataclass_field(&self) -> bool {
-        self.dataclass_field.unwrap_or(true)
+        // a classvar is a pseudo-field of dataclass
+        self.dataclass_field
+            .unwrap_or(!self.cache_hash() && !self.cache_repr())
     }
     pub(crate) fn default_factory(&self) -> bool {
         self.default_factory.unwrap_or(false)
     }
+    pub(crate) fn compare(&self) -> bool {
+        self.compare.unwrap_or(!self.hidden())
+    }
+    pub(crate) fn is_name(&self) -> bool {
+        self.is_name.unwrap_or(false)
+    }
+    pub(crate) fn init_var(&self) -> bool {
+        self.init_var.unwrap_or(false)
+    }
+    pub(crate) fn children(&self) -> bool {
+        self.children.unwrap_or(false)
+    }
+    pub(crate) fn positional_only(&self) -> bool {
+        self.positional_only.unwrap_or(false)
+    }
+    pub(crate) fn path_str(&self) -> bool {
+        self.path_str.unwrap_or(is_path(&self.field.ty))
+    }
+    pub(crate) fn set_field(&self) -> bool {
+        self.set_field.unwrap_or(false)
+    }
+    pub(crate) fn len_field(&self) -> bool {
+        self.len_field.unwrap_or(false)
+    }
+    pub(crate) fn cache_hash(&self) -> bool {
+        self.cache_hash.unwrap_or(false)
+    }
+    pub(crate) fn cache_repr(&self) -> bool {
+        self.cache_repr.unwrap_or(false)
+    }
+    // the cache_hash, cache_repr and classvar fields are not a part of the instance in Python
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+    pub(crate) fn repr_debug(&self) -> bool {
+        self.repr_debug.unwrap_or(false)
+    }
+    pub(crate) fn repr_name(&self) -> bool {
+        self.repr_name.unwrap_or(false)
+    }
+    pub(crate) fn format_field(&self) -> bool {
+        self.format_field.unwrap_or(false)
+    }
+    pub(crate) fn flatten(&self) -> bool {
+        self.flatten.unwrap_or(false)
+    }
+    /// Whether `__dataclass_fields__` inlines the fields of the flatten field,
+    /// which defines `__getattr__()` also, thus it is opt-in.
+    pub(crate) fn dataclass_flatten(&self) -> bool {
+        self.flatten() && self.dataclass_flatten.unwrap_or(false)
+    }
 
     pub(crate) fn try_from_input(input: &DeriveInput) -> Result<Vec<Self>> {
+        // #[pyclass] consumes itself and #[pyo3(..)] before the derive macros run
+        // when it is placed above #[derive(..)], so they are invisible here.
+        if !input.attrs.iter().any(is_pyclass) {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "#[pyclass] is not found, place #[derive(..)] before #[pyclass]",
+            ));
+        }
+
         let pyo3_struct_op = Pyo3StructOption::try_from(&input.attrs)?;
+        let pyderive_struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+
+        if pyderive_struct_opt.frozen_get() && !pyo3_struct_op.frozen {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "frozen_get requires #[pyclass(frozen)]",
+            ));
+        }
 
         let empty = Punctuated::<Field, Token![,]>::new();
         let fields = match &input.data {
@@ -122,7 +615,10 @@ impl FieldData<'_> {
                 let pyderive_field_opt = PyderiveFieldOption::try_from(&field.attrs)?;
 
                 let get = pyo3_struct_op.get || pyo3_field_opt.get;
-                let set = pyo3_struct_op.set || pyo3_field_opt.set;
+                let pyo3_set = pyo3_struct_op.set || pyo3_field_opt.set;
+                // #[pyderive(set=false)] marks a field of #[pyclass(set_all)] read-only for the derive macros,
+                // PyO3 still generates the setter
+                let set = pyo3_set && pyderive_field_opt.set.unwrap_or(true);
                 let pyname = match pyo3_field_opt.name {
                     Some(name) => name,
                     None => {
@@ -132,30 +628,208 @@ impl FieldData<'_> {
                                 .rename
                                 .rename(&field.ident.as_ref().unwrap().to_string()),
                         };
-                        Cow::from(r)
+                        // #[pyderive(strip_underscore)] exposes `type_` as `type`,
+                        // PyO3 names the getter/setter by itself, thus it must be renamed explicitly
+                        match r.strip_suffix('_') {
+                            Some(stripped)
+                                if pyderive_struct_opt.strip_underscore()
+                                    && !stripped.trim_matches('_').is_empty() =>
+                            {
+                                if get || pyo3_set {
+                                    return Err(syn::Error::new(
+                                        field.span(),
+                                        format!(
+                                            "strip_underscore does not rename the getter/setter of PyO3, \
+                                             add #[pyo3(name = \"{stripped}\")] to the field"
+                                        ),
+                                    ));
+                                }
+                                Cow::from(stripped.to_string())
+                            }
+                            _ => Cow::from(r),
+                        }
+                    }
+                };
+                let pyident = py_ident(&pyname).unwrap_or_else(|| field.ident.clone().unwrap());
+
+                let hidden = pyderive_field_opt.hidden();
+
+                // #[pyderive(new_default)] is a shorthand of default=<Ty as Default>::default()
+                // #[pyderive(default_once=<expr>)] evaluates <expr> at the first time and clones it
+                let ty = &field.ty;
+                let default = match (pyderive_field_opt.new_default, pyderive_field_opt.default_once) {
+                    (Some(true), _) => {
+                        Some(parse_quote! { <#ty as ::std::default::Default>::default() })
+                    }
+                    (_, Some(expr)) => Some(parse_quote! {
+                        {
+                            static DEFAULT_ONCE: ::std::sync::OnceLock<#ty> = ::std::sync::OnceLock::new();
+                            ::std::clone::Clone::clone(DEFAULT_ONCE.get_or_init(|| #expr))
+                        }
+                    }),
+                    _ => pyderive_field_opt.default,
+                };
+
+                // #[pyderive(default_py="<expr>")] evaluates the Python <expr> on every call,
+                // where the leading name of <expr> is imported unless it is a builtin, e.g. datetime.datetime.now()
+                let default = match (&pyderive_field_opt.default_py, default) {
+                    (Some(lit), None) => Some(default_py(lit, pyderive_field_opt.try_from.as_ref().unwrap_or(ty))?),
+                    (Some(lit), Some(_)) => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "default_py conflicts with default, new_default and default_once",
+                        ))
+                    }
+                    (None, default) => default,
+                };
+                if let (Some(lit), Some(true)) = (&pyderive_field_opt.default_py, pyderive_field_opt.default_factory) {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "default_py conflicts with default_factory",
+                    ));
+                }
+
+                // the instance holds the same value as the class attribute
+                let default = match (&pyderive_field_opt.classvar, default) {
+                    (Some(expr), None) => Some(expr.clone()),
+                    (Some(expr), Some(_)) => {
+                        return Err(syn::Error::new(
+                            expr.span(),
+                            "classvar conflicts with default",
+                        ))
                     }
+                    (None, default) => default,
                 };
-                let pyident = format_ident!("{}", pyname);
+
+                if let (Some(expr), Some(true)) = (&pyderive_field_opt.classvar, pyderive_field_opt.new) {
+                    return Err(syn::Error::new(
+                        expr.span(),
+                        "classvar conflicts with new=true",
+                    ));
+                }
+
+                if let Some(spec) = &pyderive_field_opt.repr_float {
+                    if !is_float(&field.ty) {
+                        return Err(syn::Error::new(
+                            spec.span(),
+                            "repr_float=\"<spec>\" supports f64 and f32 fields only",
+                        ));
+                    }
+                }
+
+                if let Some(true) = pyderive_field_opt.path_str {
+                    if !is_path(&field.ty) {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "path_str supports PathBuf, Box<Path>, Cow<Path> and &Path fields only",
+                        ));
+                    }
+                }
+
+                if let Some(true) = pyderive_field_opt.init_var {
+                    if phantom_data_inner(&field.ty).is_none() {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "init_var requires PhantomData<T> field, the argument type of __new__() is T",
+                        ));
+                    }
+                    if let Some(false) = pyderive_field_opt.new {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "init_var conflicts with new=false",
+                        ));
+                    }
+                }
+
+                if let (Some(expr), Some(true)) = (&pyderive_field_opt.compute, pyderive_field_opt.new) {
+                    return Err(syn::Error::new(
+                        expr.span(),
+                        "compute conflicts with new=true",
+                    ));
+                }
+
+                if let Some(true) = pyderive_field_opt.cache_hash {
+                    if !pyo3_struct_op.frozen {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "cache_hash requires #[pyclass(frozen)]",
+                        ));
+                    }
+                }
+
+                if let Some(true) = pyderive_field_opt.cache_repr {
+                    if !pyo3_struct_op.frozen {
+                        return Err(syn::Error::new(
+                            field.ty.span(),
+                            "cache_repr requires #[pyclass(frozen)]",
+                        ));
+                    }
+                }
+
+                if let (Some(true), None | Some(false)) =
+                    (pyderive_field_opt.dataclass_flatten, pyderive_field_opt.flatten)
+                {
+                    return Err(syn::Error::new(
+                        field.ty.span(),
+                        "dataclass_flatten requires flatten",
+                    ));
+                }
+
+                if let (Some(true), Some(false)) =
+                    (pyderive_field_opt.positional_only, pyderive_field_opt.new)
+                {
+                    return Err(syn::Error::new(
+                        field.ty.span(),
+                        "positional_only conflicts with new=false",
+                    ));
+                }
+
+                // #[pyderive(repr_all)] etc. on the struct includes the fields regardless of get/set,
+                // except the fields not a part of the instance in Python
+                let all = |on: bool| if on && !hidden { Some(true) } else { None };
 
                 Ok(FieldData {
                     index,
                     field: field.to_owned(),
                     get,
                     set,
+                    frozen_get: pyderive_struct_opt.frozen_get(),
                     pyname,
                     pyident,
                     //
                     new: pyderive_field_opt.new,
                     match_args: pyderive_field_opt.match_args,
-                    repr: pyderive_field_opt.repr,
-                    str: pyderive_field_opt.str,
-                    iter: pyderive_field_opt.iter,
-                    len: pyderive_field_opt.len,
+                    repr: pyderive_field_opt.repr.or(all(pyderive_struct_opt.repr_all())),
+                    str: pyderive_field_opt.str.or(all(pyderive_struct_opt.str_all())),
+                    iter: pyderive_field_opt.iter.or(all(pyderive_struct_opt.iter_all())),
+                    len: pyderive_field_opt.len.or(all(pyderive_struct_opt.len_all())),
                     kw_only: pyderive_field_opt.kw_only,
                     dataclass_field: pyderive_field_opt.dataclass_field,
-                    default: pyderive_field_opt.default,
+                    default,
                     default_factory: pyderive_field_opt.default_factory,
                     annotation: pyderive_field_opt.annotation,
+                    compare: pyderive_field_opt.compare,
+                    iter_order: pyderive_field_opt.iter_order,
+                    is_name: pyderive_field_opt.is_name,
+                    repr_float: pyderive_field_opt.repr_float,
+                    try_from: pyderive_field_opt.try_from,
+                    init_var: pyderive_field_opt.init_var,
+                    children: pyderive_field_opt.children,
+                    positional_only: pyderive_field_opt.positional_only,
+                    path_str: pyderive_field_opt.path_str,
+                    set_field: pyderive_field_opt.set_field,
+                    len_field: pyderive_field_opt.len_field,
+                    cache_hash: pyderive_field_opt.cache_hash,
+                    compute: pyderive_field_opt.compute,
+                    classvar: pyderive_field_opt.classvar,
+                    format_field: pyderive_field_opt.format_field,
+                    repr_debug: pyderive_field_opt.repr_debug,
+                    repr_name: pyderive_field_opt.repr_name,
+                    doc: pyderive_field_opt.doc,
+                    cache_repr: pyderive_field_opt.cache_repr,
+                    flatten: pyderive_field_opt.flatten,
+                    dataclass_flatten: pyderive_field_opt.dataclass_flatten,
+                    hidden,
                 })
             })
             .collect::<Result<Vec<_>>>()
@@ -1,8 +1,21 @@
+pub mod class_var;
+pub mod comparable;
+pub mod contains_key;
+pub mod copy_replace;
+pub mod dataclass;
 pub mod dataclass_fields;
+pub mod doc;
 pub mod eq;
+pub mod field_hash;
+pub mod format;
+pub mod from_dict;
+pub mod getitem;
+pub mod int_enum;
 pub mod iter;
 pub mod len;
+pub mod mapping;
 pub mod match_args;
+pub mod name;
 pub mod namedtuple_asdict;
 pub mod namedtuple_field_defaults;
 pub mod namedtuple_fields;
@@ -10,7 +23,10 @@ pub mod namedtuple_make;
 pub mod namedtuple_replace;
 pub mod new;
 pub mod ord;
+pub mod property;
 pub mod repr;
 pub mod reversed;
+pub mod rhs;
 pub mod richcmp;
+pub mod set_protocol;
 pub mod str;
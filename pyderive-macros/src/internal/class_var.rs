@@ -0,0 +1,39 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+use crate::common::FieldData;
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    let attrs = data
+        .iter()
+        .filter_map(|d| {
+            let ty = &d.field.ty;
+            let pyname = d.pyname.as_ref();
+            let fn_name = format_ident!("__pyderive_internal_classvar_{}", d.pyident);
+            d.classvar.as_ref().map(|expr| {
+                quote! {
+                    #[classattr]
+                    #[pyo3(name = #pyname)]
+                    #[allow(non_snake_case)]
+                    pub fn #fn_name() -> #ty {
+                        #expr
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #(#attrs)*
+        }
+    };
+
+    Ok(expanded.into())
+}
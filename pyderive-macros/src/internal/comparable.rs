@@ -0,0 +1,18 @@
+use proc_macro::TokenStream;
+use syn::DeriveInput;
+
+use crate::attr::PyderiveStructOption;
+use crate::common::check_richcmp_conflict;
+use crate::internal::{eq, field_hash};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    check_richcmp_conflict(&input, "PyComparable")?;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+
+    // __eq__ and __hash__ read the same compare fields, so that equal objects hash equal,
+    // thus it always compares field by field instead of PartialEq of the struct
+    let mut expanded = eq::eq_fields(&input, struct_opt.eq_subclass(), struct_opt.eq_identity())?;
+    expanded.extend(field_hash::implementation(input)?);
+
+    Ok(expanded)
+}
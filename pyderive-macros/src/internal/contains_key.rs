@@ -0,0 +1,39 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::common::FieldData;
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    // the same keys as PyMapping
+    let keys = data
+        .iter()
+        .filter(|d| d.get)
+        .map(|d| &d.pyname)
+        .collect::<Vec<_>>();
+    let matched = if keys.is_empty() {
+        quote! { false }
+    } else {
+        quote! { ::std::matches!(key, #(#keys)|*) }
+    };
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __contains__(&self, key: &::pyo3::Bound<'_, ::pyo3::PyAny>) -> ::std::primitive::bool {
+                // a non-str probe is never a key
+                match key.extract::<&::std::primitive::str>() {
+                    #[allow(unused_variables)]
+                    ::std::result::Result::Ok(key) => #matched,
+                    ::std::result::Result::Err(_) => false,
+                }
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
@@ -0,0 +1,127 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::common::{is_py, py_inner, FieldData};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    let current = |d: &FieldData| {
+        let ident = &d.field.ident;
+        if is_py(&d.field.ty) {
+            quote! { this.#ident.clone_ref(py).into_any() }
+        } else {
+            quote! { (&this.#ident).into_pyobject(py)?.into_any().unbind() }
+        }
+    };
+
+    // positional_only fields are passed by position,
+    // the value in changes takes precedence over the current one
+    let args = data
+        .iter()
+        .filter(|d| d.new() && d.positional_only())
+        .map(|d| {
+            let pyident = d.pyident.to_string();
+            let value = if d.init_var() {
+                let msg = format!("__replace__() missing init_var field '{}'", pyident);
+                quote! { return ::std::result::Result::Err(::pyo3::exceptions::PyTypeError::new_err(#msg)) }
+            } else {
+                current(d)
+            };
+
+            quote! {
+                match changes.get_item(::pyo3::intern!(py, #pyident))? {
+                    ::std::option::Option::Some(v) => {
+                        changes.del_item(::pyo3::intern!(py, #pyident))?;
+                        args.push(v.unbind());
+                    }
+                    ::std::option::Option::None => args.push(#value),
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the current values of the other __new__() arguments,
+    // init_var fields have no value so that they must be given by changes
+    let items = data
+        .iter()
+        .filter(|d| d.new() && !d.positional_only() && !d.init_var())
+        .map(|d| {
+            let pyident = d.pyident.to_string();
+            let value = current(d);
+
+            quote! { kwargs.set_item(::pyo3::intern!(py, #pyident), #value)?; }
+        })
+        .collect::<Vec<_>>();
+
+    // a flatten field is constructed by the keyword arguments of __new__(),
+    // thus its current values are the ones of the class of the field
+    let flattens = data
+        .iter()
+        .filter(|d| d.flatten())
+        .map(|d| {
+            let ident = &d.field.ident;
+            let child = if py_inner(&d.field.ty).is_some() {
+                quote! { this.#ident.bind(py).try_borrow()? }
+            } else {
+                quote! { this.#ident }
+            };
+            quote! { kwargs.update(#child.__pyderive_internal_replace_kwargs(py)?.as_mapping())?; }
+        })
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[pyo3(signature = (**changes))]
+            pub fn __replace__<'py>(
+                slf: &::pyo3::Bound<'py, Self>,
+                changes: ::std::option::Option<&::pyo3::Bound<'py, ::pyo3::types::PyDict>>,
+            ) -> ::pyo3::PyResult<::pyo3::Bound<'py, Self>> {
+                let py = slf.py();
+                let changes = match changes {
+                    ::std::option::Option::Some(changes) => changes.clone(),
+                    ::std::option::Option::None => ::pyo3::types::PyDict::new(py),
+                };
+                #[allow(unused_mut)]
+                let mut args: ::std::vec::Vec<::pyo3::Py<::pyo3::PyAny>> = ::std::vec::Vec::new();
+                let kwargs = {
+                    let this = slf.try_borrow()?;
+                    #(#args)*
+                    this.__pyderive_internal_replace_kwargs(py)?
+                };
+                kwargs.update(changes.as_mapping())?;
+                // calls __new__() of the class, the same as copy.replace() of dataclass
+                let args = ::pyo3::types::PyTuple::new(py, args)?;
+                ::std::result::Result::Ok(
+                    slf.get_type()
+                        .call(args, ::std::option::Option::Some(&kwargs))?
+                        .cast_into::<Self>()?,
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl #struct_name {
+            /// The current values of the `__new__()` arguments passed by keyword,
+            /// that a `#[pyderive(flatten)]` field of another class expands.
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            pub fn __pyderive_internal_replace_kwargs<'py>(
+                &self,
+                py: ::pyo3::Python<'py>,
+            ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::types::PyDict>> {
+                let this = self;
+                let kwargs = ::pyo3::types::PyDict::new(py);
+                #(#items)*
+                #(#flattens)*
+                ::pyo3::PyResult::Ok(kwargs)
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
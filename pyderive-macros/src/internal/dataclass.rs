@@ -0,0 +1,50 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::attr::{PyderiveStructOption, Pyo3StructOption};
+use crate::common::check_richcmp_conflict;
+use crate::internal::{eq, field_hash, match_args, new, repr, richcmp};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    check_richcmp_conflict(&input, "PyDataclass")?;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+    let pyo3_opt = Pyo3StructOption::try_from(&input.attrs)?;
+
+    let mut expanded = TokenStream::new();
+    expanded.extend(new::implementation(input.clone())?);
+    expanded.extend(repr::implementation(input.clone())?);
+    expanded.extend(match_args::implementation(input.clone())?);
+
+    // Python drops __hash__ of the class that defines __eq__,
+    // we make it explicit like @dataclass(eq=True) does,
+    // and hash=<bool> overrides it like unsafe_hash of @dataclass.
+    match struct_opt.hash {
+        Some(true) => expanded.extend(field_hash::dataclass(input.clone())?),
+        // leaves __hash__ to the user, e.g. PyFieldHash
+        Some(false) => {}
+        None if pyo3_opt.frozen => expanded.extend(field_hash::dataclass(input.clone())?),
+        None => {
+            let unhashable = quote! {
+                #[pymethods]
+                #[automatically_derived]
+                impl #struct_name {
+                    #[classattr]
+                    const __hash__: ::std::option::Option<::pyo3::Py<::pyo3::PyAny>> = ::std::option::Option::None;
+                }
+            };
+            expanded.extend(TokenStream::from(unhashable));
+        }
+    }
+
+    // PyO3 merges __eq__ and __lt__ etc. into __richcmp__ in each #[pymethods],
+    // thus PyEq and PyOrd conflict with each other.
+    if struct_opt.order() {
+        expanded.extend(richcmp::dataclass(input)?);
+    } else {
+        expanded.extend(eq::implementation(input)?);
+    }
+
+    Ok(expanded)
+}
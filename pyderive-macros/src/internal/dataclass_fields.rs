@@ -4,7 +4,7 @@ use quote::{format_ident, quote};
 use syn::spanned::Spanned;
 use syn::DeriveInput;
 
-use crate::common::FieldData;
+use crate::common::{py_inner, typed_default, FieldData};
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
@@ -15,14 +15,35 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
         .filter(|d| d.dataclass_field())
         .collect::<Vec<_>>();
 
+    let mut flattens = fields.iter().filter(|d| d.dataclass_flatten());
+    if let (Some(_), Some(dup)) = (flattens.next(), flattens.next()) {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(flatten)] field",
+        ));
+    }
+
     let mut kw_only = false;
     let assignments = fields.iter().map(|d| {
+        // the fields of the class of the flatten field, in place of the field
+        if d.dataclass_flatten() {
+            let class = py_inner(&d.field.ty).unwrap_or(&d.field.ty);
+            return Ok(quote! {
+                fields.update(
+                    py.get_type::<#class>()
+                        .getattr(::pyo3::intern!(py, "__dataclass_fields__"))?
+                        .cast::<::pyo3::types::PyMapping>()?
+                )?;
+            });
+        }
+
         let pyname = &d.pyname;
         let new = &d.new();
         let repr = &d.repr();
 
         let (default, default_factory) = match &d.default {
             Some(default) => {
+                let default = typed_default(d.arg_type(), default);
                 if d.default_factory() {
                     let name = format!("pyderive_internal_{}_{}_factory\0", struct_name, pyname);
 
@@ -61,6 +82,18 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
             kw_only = true;
         }
 
+        // {"doc": <doc>} or None, Field wraps it by MappingProxyType
+        let metadata = match d.doc.as_ref() {
+            Some(doc) => quote! {
+                {
+                    let metadata = ::pyo3::types::PyDict::new(py);
+                    metadata.set_item(::pyo3::intern!(py, "doc"), #doc)?;
+                    metadata.into_any().unbind()
+                }
+            },
+            None => quote! { py.None() },
+        };
+
         // annotation or None
         let annotation = match d.annotation.as_ref() {
             Some(ty) => {
@@ -70,11 +103,14 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
             None => quote! { py.None() },
         };
 
-        // new=false -> ClassVar
-        let field_type = if *new {
-            format_ident!("{}", "_FIELD")
-        } else {
+        // classvar -> ClassVar, init_var -> InitVar,
+        // new=false is a field with init=False as dataclasses.field(init=False)
+        let field_type = if d.init_var() {
+            format_ident!("{}", "_FIELD_INITVAR")
+        } else if d.classvar.is_some() {
             format_ident!("{}", "_FIELD_CLASSVAR")
+        } else {
+            format_ident!("{}", "_FIELD")
         };
 
         let r = quote! {
@@ -88,7 +124,7 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
                     ::pyo3::types::PyBool::new(py, #repr), // repr
                     py.None(), // hash
                     py.None(), // compare
-                    py.None(), // metadata
+                    #metadata, // metadata
                     ::pyo3::types::PyBool::new(py, #kw_only), // kw_only
                 );
                 Field.call1(args)
@@ -100,7 +136,7 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
                     ::pyo3::types::PyBool::new(py, #repr), // repr
                     py.None(), // hash
                     py.None(), // compare
-                    py.None(), // metadata
+                    #metadata, // metadata
                 );
                 Field.call1(args)
             }?;
@@ -130,6 +166,47 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     })
     .collect::<Result<Vec<_>, syn::Error>>()?;
 
+    // the inlined fields are the attributes of the instance also,
+    // so that dataclasses.asdict() etc. read them by getattr()
+    let getattr = match fields.iter().find(|d| d.dataclass_flatten()) {
+        Some(d) => {
+            let ident = &d.field.ident;
+            let class = py_inner(&d.field.ty).unwrap_or(&d.field.ty);
+            let child = if py_inner(&d.field.ty).is_some() {
+                quote! { slf.borrow().#ident.bind(py).clone().into_any() }
+            } else {
+                quote! {
+                    ::pyo3::IntoPyObjectExt::into_bound_py_any(
+                        ::std::clone::Clone::clone(&slf.borrow().#ident),
+                        py,
+                    )?
+                }
+            };
+            quote! {
+                #[pymethods]
+                #[automatically_derived]
+                impl #struct_name {
+                    pub fn __getattr__<'py>(
+                        slf: &::pyo3::Bound<'py, Self>,
+                        name: &::pyo3::Bound<'py, ::pyo3::types::PyString>,
+                    ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::PyAny>> {
+                        let py = slf.py();
+                        let names = py
+                            .get_type::<#class>()
+                            .getattr(::pyo3::intern!(py, "__dataclass_fields__"))?;
+                        if !names.contains(name)? {
+                            return ::pyo3::PyResult::Err(
+                                ::pyo3::exceptions::PyAttributeError::new_err(name.clone().unbind())
+                            );
+                        }
+                        #child.getattr(name)
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
     let expanded = quote! {
         #[pymethods]
         #[automatically_derived]
@@ -149,6 +226,8 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
                 let _FIELD = dataclasses.getattr("_FIELD")?;
                 #[allow(non_snake_case)]
                 let _FIELD_CLASSVAR = dataclasses.getattr("_FIELD_CLASSVAR")?;
+                #[allow(non_snake_case)]
+                let _FIELD_INITVAR = dataclasses.getattr("_FIELD_INITVAR")?;
 
                 // cache attr. names
                 let pystr_name = ::pyo3::intern!(py, "name");
@@ -163,6 +242,8 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
                 Ok(fields)
             }
         }
+
+        #getattr
     };
 
     Ok(expanded.into())
@@ -0,0 +1,62 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Attribute, DeriveInput, Expr, ExprLit, Lit, Meta};
+
+use crate::common::FieldData;
+
+// The text of the doc comments, `/// text` is `#[doc = " text"]`
+fn doc_comment(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => {
+                    let line = s.value();
+                    Some(line.strip_prefix(' ').unwrap_or(&line).to_string())
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    // the doc comment of the struct and then an attribute listing like Google style docstrings
+    let mut doc = doc_comment(&input.attrs).join("\n").trim().to_string();
+
+    let attributes = data
+        .iter()
+        .filter_map(|d| {
+            d.doc
+                .as_ref()
+                .map(|doc| format!("    {}: {}", d.pyname, doc.value()))
+        })
+        .collect::<Vec<_>>();
+    if !attributes.is_empty() {
+        if !doc.is_empty() {
+            doc.push_str("\n\n");
+        }
+        doc.push_str("Attributes:\n");
+        doc.push_str(&attributes.join("\n"));
+    }
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[classattr]
+            #[pyo3(name = "__doc__")]
+            fn __pyderive_internal_doc() -> &'static ::std::primitive::str {
+                #doc
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
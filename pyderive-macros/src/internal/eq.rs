@@ -2,16 +2,201 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
 
+use crate::attr::PyderiveStructOption;
+use crate::common::{check_richcmp_conflict, is_py, option_inner, richcmp_slot_marker, FieldData};
+
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    check_richcmp_conflict(&input, "PyEq")?;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+
+    if struct_opt.eq_fields() {
+        return eq_fields(&input, struct_opt.eq_subclass(), struct_opt.eq_identity());
+    }
+
+    // the same object is equal to itself without comparing the fields,
+    // it assumes PartialEq is reflexive, e.g. no NaN field
+    let identity = |this| {
+        if struct_opt.eq_identity() {
+            (
+                quote! { ::std::ptr::eq(#this, other) || },
+                quote! { !::std::ptr::eq(#this, other) && },
+            )
+        } else {
+            (quote! {}, quote! {})
+        }
+    };
+
+    let expanded = if struct_opt.eq_subclass() {
+        let (identity_eq, identity_ne) = identity(quote! { self });
+        quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __eq__(&self, other: &Self) -> ::std::primitive::bool { #identity_eq self.eq(other) }
+                pub fn __ne__(&self, other: &Self) -> ::std::primitive::bool { #identity_ne self.ne(other) }
+            }
+        }
+    } else {
+        let (identity_eq, identity_ne) = identity(quote! { this });
+        // like dataclass, returns NotImplemented unless type(other) is type(self)
+        quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __eq__(
+                    slf: &::pyo3::Bound<'_, Self>,
+                    other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+                ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                    let py = slf.py();
+                    if !other.get_type().is(&slf.get_type()) {
+                        return ::pyo3::PyResult::Ok(py.NotImplemented());
+                    }
+                    let this = &*slf.try_borrow()?;
+                    let other = &*other.cast::<Self>()?.try_borrow()?;
+                    let r = #identity_eq ::std::cmp::PartialEq::eq(this, other);
+                    ::pyo3::PyResult::Ok(::pyo3::types::PyBool::new(py, r).to_owned().into_any().unbind())
+                }
+                pub fn __ne__(
+                    slf: &::pyo3::Bound<'_, Self>,
+                    other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+                ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                    let py = slf.py();
+                    if !other.get_type().is(&slf.get_type()) {
+                        return ::pyo3::PyResult::Ok(py.NotImplemented());
+                    }
+                    let this = &*slf.try_borrow()?;
+                    let other = &*other.cast::<Self>()?.try_borrow()?;
+                    let r = #identity_ne ::std::cmp::PartialEq::ne(this, other);
+                    ::pyo3::PyResult::Ok(::pyo3::types::PyBool::new(py, r).to_owned().into_any().unbind())
+                }
+            }
+        }
+    };
 
+    let marker = richcmp_slot_marker(struct_name);
     let expanded = quote! {
+        #expanded
+        #marker
+    };
+
+    Ok(expanded.into())
+}
+
+// eq="fields" compares the compare fields one by one without PartialEq of the struct,
+// where Py<T> and Option<Py<T>> fields are compared by Python ==
+pub(crate) fn eq_fields(
+    input: &DeriveInput,
+    eq_subclass: bool,
+    eq_identity: bool,
+) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(input)?;
+
+    let comparisons = data
+        .iter()
+        .filter(|d| d.compare())
+        .map(|d| {
+            let ident = &d.field.ident;
+            let ty = &d.field.ty;
+
+            if is_py(ty) {
+                quote! {
+                    ::pyo3::types::PyAnyMethods::eq(self.#ident.bind(py).as_any(), &other.#ident)?
+                }
+            } else if option_inner(ty).is_some_and(is_py) {
+                quote! {
+                    match (&self.#ident, &other.#ident) {
+                        (::std::option::Option::Some(a), ::std::option::Option::Some(b)) => {
+                            ::pyo3::types::PyAnyMethods::eq(a.bind(py).as_any(), b)?
+                        }
+                        (::std::option::Option::None, ::std::option::Option::None) => true,
+                        _ => false,
+                    }
+                }
+            } else {
+                quote! { ::std::cmp::PartialEq::eq(&self.#ident, &other.#ident) }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let methods = if eq_subclass {
+        quote! {
+            pub fn __eq__(&self, py: ::pyo3::Python<'_>, other: &Self) -> ::pyo3::PyResult<::std::primitive::bool> {
+                self.__pyderive_internal_eq_fields(py, other)
+            }
+            pub fn __ne__(&self, py: ::pyo3::Python<'_>, other: &Self) -> ::pyo3::PyResult<::std::primitive::bool> {
+                ::pyo3::PyResult::Ok(!self.__pyderive_internal_eq_fields(py, other)?)
+            }
+        }
+    } else {
+        // like dataclass, returns NotImplemented unless type(other) is type(self)
+        quote! {
+            pub fn __eq__(
+                slf: &::pyo3::Bound<'_, Self>,
+                other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                let py = slf.py();
+                if !other.get_type().is(&slf.get_type()) {
+                    return ::pyo3::PyResult::Ok(py.NotImplemented());
+                }
+                let other = other.cast::<Self>()?.try_borrow()?;
+                let r = slf.try_borrow()?.__pyderive_internal_eq_fields(py, &other)?;
+                ::pyo3::PyResult::Ok(::pyo3::types::PyBool::new(py, r).to_owned().into_any().unbind())
+            }
+            pub fn __ne__(
+                slf: &::pyo3::Bound<'_, Self>,
+                other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                let py = slf.py();
+                if !other.get_type().is(&slf.get_type()) {
+                    return ::pyo3::PyResult::Ok(py.NotImplemented());
+                }
+                let other = other.cast::<Self>()?.try_borrow()?;
+                let r = !slf.try_borrow()?.__pyderive_internal_eq_fields(py, &other)?;
+                ::pyo3::PyResult::Ok(::pyo3::types::PyBool::new(py, r).to_owned().into_any().unbind())
+            }
+        }
+    };
+
+    let identity = if eq_identity {
+        quote! {
+            if ::std::ptr::eq(self, other) {
+                return ::pyo3::PyResult::Ok(true);
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let marker = richcmp_slot_marker(struct_name);
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #struct_name {
+            #[allow(unused_variables)]
+            fn __pyderive_internal_eq_fields(
+                &self,
+                py: ::pyo3::Python<'_>,
+                other: &Self,
+            ) -> ::pyo3::PyResult<::std::primitive::bool> {
+                #identity
+                // short-circuit like the tuple comparison
+                #(
+                    if !(#comparisons) {
+                        return ::pyo3::PyResult::Ok(false);
+                    }
+                )*
+                ::pyo3::PyResult::Ok(true)
+            }
+        }
+
         #[pymethods]
         #[automatically_derived]
         impl #struct_name {
-            pub fn __eq__(&self, other: &Self) -> ::std::primitive::bool { self.eq(other) }
-            pub fn __ne__(&self, other: &Self) -> ::std::primitive::bool { self.ne(other) }
+            #methods
         }
+
+        #marker
     };
 
     Ok(expanded.into())
@@ -0,0 +1,102 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, DeriveInput};
+
+use crate::common::{contains_py, is_py, FieldData};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    expand(input, false)
+}
+
+// PyDataclass hashes the fields by Python hash() like @dataclass does,
+// so that a field without Hash, e.g. f64, is hashable as far as it is in Python.
+pub(crate) fn dataclass(input: DeriveInput) -> syn::Result<TokenStream> {
+    expand(input, true)
+}
+
+fn expand(input: DeriveInput, python: bool) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    let statements = data
+        .iter()
+        .filter(|d| d.compare())
+        .map(|d| {
+            let ident = &d.field.ident;
+
+            if python {
+                quote! {
+                    let obj = ::pyo3::IntoPyObjectExt::into_bound_py_any(&self.#ident, py)?;
+                    ::std::hash::Hash::hash(&::pyo3::types::PyAnyMethods::hash(&obj)?, &mut hasher);
+                }
+            } else if is_py(&d.field.ty) {
+                quote! { ::std::hash::Hash::hash(&self.#ident.bind(py).hash()?, &mut hasher); }
+            } else {
+                quote! { ::std::hash::Hash::hash(&self.#ident, &mut hasher); }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut caches = data.iter().filter(|d| d.cache_hash());
+    let cache = caches.next();
+    if let Some(dup) = caches.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(cache_hash)] field",
+        ));
+    }
+
+    // #[pyclass(frozen)] is shallow, the object that a Py<T> field refers to may mutate
+    if cache.is_some() {
+        if let Some(d) = data
+            .iter()
+            .find(|d| d.compare() && contains_py(&d.field.ty))
+        {
+            return Err(syn::Error::new(
+                d.field.ty.span(),
+                "cache_hash conflicts with Py<T> field, the object may mutate after the hash is cached, \
+                 exclude it by #[pyderive(compare=false)]",
+            ));
+        }
+    }
+
+    // the class is frozen, so that the hash never changes once computed,
+    // a race just computes the same value twice
+    let (lookup, store) = match cache {
+        Some(d) => {
+            let ident = &d.field.ident;
+            (
+                quote! {
+                    if let ::std::option::Option::Some(hash) = self.#ident.get() {
+                        return ::pyo3::PyResult::Ok(*hash);
+                    }
+                },
+                quote! { let _ = self.#ident.set(hash); },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[allow(unused_variables)]
+            pub fn __hash__(&self, py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<::std::primitive::u64> {
+                use ::std::hash::Hasher;
+
+                #lookup
+
+                let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+
+                #(#statements)*
+
+                let hash = hasher.finish();
+                #store
+                ::pyo3::PyResult::Ok(hash)
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
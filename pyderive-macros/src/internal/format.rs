@@ -0,0 +1,50 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, DeriveInput};
+
+use crate::common::{is_py, FieldData};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    let mut format_fields = data.iter().filter(|d| d.format_field());
+    let Some(d) = format_fields.next() else {
+        return Err(syn::Error::new(
+            input.span(),
+            "PyFormat requires a #[pyderive(format_field)] field",
+        ));
+    };
+    if let Some(dup) = format_fields.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(format_field)] field",
+        ));
+    }
+
+    let ident = &d.field.ident;
+    let obj = if is_py(&d.field.ty) {
+        quote! { slf.#ident.bind(py).as_any().clone() }
+    } else {
+        quote! { (&slf.#ident).into_pyobject(py)?.into_any() }
+    };
+
+    // the Python __format__() of the field handles the spec, e.g. f"{obj:.1%}"
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __format__(
+                slf: ::pyo3::PyRef<'_, Self>,
+                format_spec: &::std::primitive::str,
+            ) -> ::pyo3::PyResult<::std::string::String> {
+                let py = slf.py();
+                #obj
+                    .call_method1(::pyo3::intern!(py, "__format__"), (format_spec,))?
+                    .extract()
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
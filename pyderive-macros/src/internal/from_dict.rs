@@ -0,0 +1,68 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, DeriveInput};
+
+use crate::attr::{pyderive_struct::SignatureItem, PyderiveStructOption};
+use crate::common::FieldData;
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+    let data = FieldData::try_from_input(&input)?;
+
+    // the positional only arguments of __new__() in order, like PyNew,
+    // they cannot be passed by keyword
+    let positional_only = match &struct_opt.signature {
+        Some(items)
+            if items
+                .iter()
+                .any(|item| matches!(item, SignatureItem::Slash(_))) =>
+        {
+            items
+                .iter()
+                .take_while(|item| !matches!(item, SignatureItem::Slash(_)))
+                .filter_map(|item| match item {
+                    SignatureItem::Arg(name, _) => Some(name.unraw().to_string()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        }
+        Some(_) => Vec::new(),
+        None => data
+            .iter()
+            .filter(|d| d.new() && d.positional_only())
+            .map(|d| d.pyname.to_string())
+            .collect::<Vec<_>>(),
+    };
+
+    let length = positional_only.len();
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[classmethod]
+            pub fn from_dict<'py>(
+                cls: &::pyo3::Bound<'py, ::pyo3::types::PyType>,
+                mapping: &::pyo3::Bound<'py, ::pyo3::types::PyDict>,
+            ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::PyAny>> {
+                let kwargs = mapping.copy()?;
+                let mut args = ::std::vec::Vec::<::pyo3::Bound<'py, ::pyo3::PyAny>>::new();
+                // a missing one stops here, and __new__() raises TypeError on the rest
+                let names: [&::std::primitive::str; #length] = [#(#positional_only),*];
+                for name in names {
+                    match kwargs.get_item(name)? {
+                        ::std::option::Option::Some(v) => {
+                            kwargs.del_item(name)?;
+                            args.push(v);
+                        }
+                        ::std::option::Option::None => break,
+                    }
+                }
+                cls.call(::pyo3::types::PyTuple::new(cls.py(), args)?, ::std::option::Option::Some(&kwargs))
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
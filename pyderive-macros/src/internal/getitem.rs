@@ -0,0 +1,68 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::common::{is_py, FieldData};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    // the same fields and order as PyIter
+    let mut data = data.iter().filter(|d| d.iter()).collect::<Vec<_>>();
+    data.sort_by_key(|d| (d.iter_order.is_none(), d.iter_order));
+
+    let args = data
+        .iter()
+        .map(|d| {
+            let ident = &d.field.ident;
+
+            if is_py(&d.field.ty) {
+                quote! { (&slf.#ident).clone_ref(py).into_any() }
+            } else {
+                quote! { (&slf.#ident).into_pyobject(py)?.into_any().unbind() }
+            }
+        })
+        .collect::<Vec<_>>();
+    let length = args.len();
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __getitem__(
+                slf: ::pyo3::PyRef<'_, Self>,
+                index: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                let py = slf.py();
+                let values: [::pyo3::Py<::pyo3::PyAny>; #length] = [ #(#args),* ];
+
+                if let ::std::result::Result::Ok(slice) = index.cast::<::pyo3::types::PySlice>() {
+                    let indices = slice.indices(#length as ::std::primitive::isize)?;
+                    let mut selected = ::std::vec::Vec::with_capacity(indices.slicelength);
+                    let mut i = indices.start;
+                    for _ in 0..indices.slicelength {
+                        selected.push(values[i as ::std::primitive::usize].clone_ref(py));
+                        i += indices.step;
+                    }
+                    return ::std::result::Result::Ok(
+                        ::pyo3::types::PyList::new(py, selected)?.into_any().unbind()
+                    );
+                }
+
+                // extraction respects __index__()
+                let i = index.extract::<::std::primitive::isize>()?;
+                let j = if i < 0 { i + #length as ::std::primitive::isize } else { i };
+                if 0 <= j && j < #length as ::std::primitive::isize {
+                    ::std::result::Result::Ok(values[j as ::std::primitive::usize].clone_ref(py))
+                } else {
+                    ::std::result::Result::Err(::pyo3::exceptions::PyIndexError::new_err(
+                        "index out of range",
+                    ))
+                }
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
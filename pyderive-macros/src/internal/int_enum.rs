@@ -0,0 +1,99 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new(
+                struct_name.span(),
+                "PyIntEnum supports only an enum",
+            ))
+        }
+    };
+    if let Some(v) = variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(syn::Error::new(
+            v.ident.span(),
+            "PyIntEnum supports only unit variants",
+        ));
+    }
+
+    // Self::Red as isize is the discriminant, whether it is explicit or not
+    let value_arms = variants
+        .iter()
+        .map(|v| {
+            let ident = &v.ident;
+            quote! { Self::#ident => Self::#ident as ::std::primitive::isize }
+        })
+        .collect::<Vec<_>>();
+    let name_arms = variants
+        .iter()
+        .map(|v| {
+            let ident = &v.ident;
+            let name = ident.to_string();
+            quote! { Self::#ident => #name }
+        })
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[getter]
+            pub fn name(&self) -> &'static ::std::primitive::str {
+                match self {
+                    #(#name_arms,)*
+                }
+            }
+
+            #[getter]
+            pub fn value(&self) -> ::std::primitive::isize {
+                match self {
+                    #(#value_arms,)*
+                }
+            }
+
+            pub fn __int__(&self) -> ::std::primitive::isize {
+                self.value()
+            }
+
+            pub fn __index__(&self) -> ::std::primitive::isize {
+                self.value()
+            }
+
+            pub fn __repr__(slf: &::pyo3::Bound<'_, Self>) -> ::pyo3::PyResult<::std::string::String> {
+                let t = ::pyo3::types::PyAnyMethods::get_type(slf.as_any());
+                let qualname = t.qualname()?;
+                let this = slf.borrow();
+                ::pyo3::PyResult::Ok(::std::format!("<{}.{}: {}>", qualname, this.name(), this.value()))
+            }
+
+            // compares as an int, like IntEnum
+            pub fn __eq__(
+                &self,
+                other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::std::primitive::bool> {
+                if let ::std::result::Result::Ok(other) = other.cast::<Self>() {
+                    return ::pyo3::PyResult::Ok(self.value() == other.try_borrow()?.value());
+                }
+                ::pyo3::types::PyAnyMethods::eq(self.value().into_pyobject(other.py())?.as_any(), other)
+            }
+
+            pub fn __ne__(
+                &self,
+                other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::std::primitive::bool> {
+                self.__eq__(other).map(|r| !r)
+            }
+
+            pub fn __hash__(&self, py: ::pyo3::Python<'_>) -> ::pyo3::PyResult<::std::primitive::isize> {
+                ::pyo3::types::PyAnyMethods::hash(self.value().into_pyobject(py)?.as_any())
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
@@ -1,30 +1,93 @@
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote};
-use syn::DeriveInput;
+use syn::{spanned::Spanned, DeriveInput};
 
-use crate::common::{is_py, FieldData};
+use crate::attr::{IterMode, PyderiveStructOption};
+use crate::common::{is_py, smart_pointer_inner, FieldData};
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
     let data = FieldData::try_from_input(&input)?;
 
+    // the children field is the sole source of the iteration, e.g., a tree node
+    let mut children = data.iter().filter(|d| d.children());
+    if let Some(d) = children.next() {
+        if let Some(dup) = children.next() {
+            return Err(syn::Error::new(
+                dup.field.span(),
+                "duplicated #[pyderive(children)] field",
+            ));
+        }
+        if struct_opt.iter_mode.is_some() {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "#[pyderive(children)] conflicts with #[pyderive(iter=\"..\")]",
+            ));
+        }
+
+        let ident = &d.field.ident;
+        let expanded = quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __iter__<'py>(
+                    slf: ::pyo3::PyRef<'py, Self>,
+                ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::types::PyIterator>> {
+                    let py = slf.py();
+                    (&slf.#ident).into_pyobject(py)?.into_any().try_iter()
+                }
+            }
+        };
+
+        return Ok(expanded.into());
+    }
+
     let iter_name = format_ident!("_____pyderive_InternalIteratorFor{}", struct_name);
 
+    // fields with iter_order come first, and the others keep declaration order
+    let mut data = data.iter().filter(|d| d.iter()).collect::<Vec<_>>();
+    data.sort_by_key(|d| (d.iter_order.is_none(), d.iter_order));
+
     let args = data
         .iter()
-        .filter(|d| d.iter())
         .map(|d| {
-            let ident = &d.field.ident;
+            let name = &d.pyname;
 
-            if is_py(&d.field.ty) {
-                quote! { (&slf.#ident).clone_ref(py).into_any() }
-            } else {
-                quote! { (&slf.#ident).into_pyobject(py)?.into_any().unbind() }
+            let key = quote! { ::pyo3::types::PyString::new(py, #name).into_any().unbind() };
+            let value = value(d);
+
+            match struct_opt.iter_mode() {
+                IterMode::Values => value,
+                IterMode::Items => quote! {
+                    ::pyo3::types::PyTuple::new(py, [#key, #value])?.into_any().unbind()
+                },
+                IterMode::Keys => key,
             }
         })
         .collect::<Vec<_>>();
     let length = args.len();
 
+    // iterates a tuple, the builtin iterator does not take a lock on __next__()
+    if struct_opt.iter_snapshot() {
+        let expanded = quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __iter__<'py>(
+                    slf: ::pyo3::PyRef<'py, Self>,
+                ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::types::PyIterator>> {
+                    let py = slf.py();
+                    let values: [::pyo3::Py<::pyo3::PyAny>; #length] = [ #(#args),* ];
+                    ::pyo3::types::PyTuple::new(py, values.into_iter())?.try_iter()
+                }
+            }
+        };
+
+        return Ok(expanded.into());
+    }
+
     let expanded = quote! {
         #[pyclass]
         #[pyo3(name="pyderive_iterator")]
@@ -66,3 +129,23 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
 
     Ok(expanded.into())
 }
+
+/// Returns the expression of the `Py<PyAny>` value of the field of `slf`.
+///
+/// The field is read from the struct directly, thus it needs no getter,
+/// and `Box<T>`, `Arc<T>` and `Rc<T>` yield the pointee.
+pub(crate) fn value(d: &FieldData) -> TokenStream2 {
+    let ident = &d.field.ident;
+
+    let mut ty = &d.field.ty;
+    let mut expr = quote! { &slf.#ident };
+    while let Some(inner) = smart_pointer_inner(ty) {
+        ty = inner;
+        expr = quote! { &**(#expr) };
+    }
+    if is_py(ty) {
+        quote! { (#expr).clone_ref(py).into_any() }
+    } else {
+        quote! { (#expr).into_pyobject(py)?.into_any().unbind() }
+    }
+}
@@ -1,20 +1,55 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{spanned::Spanned, DeriveInput};
 
-use crate::common::FieldData;
+use crate::common::{is_py, FieldData};
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
     let data = FieldData::try_from_input(&input)?;
 
-    let length = data.iter().filter(|d| d.len()).count();
+    let mut len_fields = data.iter().filter(|d| d.len_field());
+    let len_field = len_fields.next();
+    if let Some(dup) = len_fields.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(len_field)] field",
+        ));
+    }
 
-    let expanded = quote! {
-        #[pymethods]
-        #[automatically_derived]
-        impl #struct_name {
-            pub fn __len__(&self) -> ::std::primitive::usize { #length }
+    let expanded = match len_field {
+        // the Python len() of the field overrides the number of fields
+        Some(d) => {
+            let ident = &d.field.ident;
+            let obj = if is_py(&d.field.ty) {
+                quote! { slf.#ident.bind(py).as_any().clone() }
+            } else {
+                quote! { (&slf.#ident).into_pyobject(py)?.into_any() }
+            };
+
+            quote! {
+                #[pymethods]
+                #[automatically_derived]
+                impl #struct_name {
+                    pub fn __len__(
+                        slf: ::pyo3::PyRef<'_, Self>,
+                    ) -> ::pyo3::PyResult<::std::primitive::usize> {
+                        let py = slf.py();
+                        ::pyo3::types::PyAnyMethods::len(&#obj)
+                    }
+                }
+            }
+        }
+        None => {
+            let length = data.iter().filter(|d| d.len()).count();
+
+            quote! {
+                #[pymethods]
+                #[automatically_derived]
+                impl #struct_name {
+                    pub fn __len__(&self) -> ::std::primitive::usize { #length }
+                }
+            }
         }
     };
 
@@ -0,0 +1,95 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::attr::Pyo3StructOption;
+use crate::common::{is_py, FieldData};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let pyo3_opt = Pyo3StructOption::try_from(&input.attrs)?;
+    let data = FieldData::try_from_input(&input)?;
+
+    let keys = data
+        .iter()
+        .filter(|d| d.get)
+        .map(|d| &d.pyname)
+        .collect::<Vec<_>>();
+
+    let getitem_arms = data
+        .iter()
+        .filter(|d| d.get)
+        .map(|d| {
+            let ident = &d.field.ident;
+            let name = &d.pyname;
+
+            if is_py(&d.field.ty) {
+                quote! { #name => ::pyo3::PyResult::Ok(self.#ident.clone_ref(py).into_any()) }
+            } else {
+                quote! {
+                    #name => ::pyo3::PyResult::Ok((&self.#ident).into_pyobject(py)?.into_any().unbind())
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let setitem_arms = data
+        .iter()
+        .filter(|d| d.set)
+        .map(|d| {
+            let ident = &d.field.ident;
+            let name = &d.pyname;
+
+            quote! {
+                #name => {
+                    self.#ident = value.extract()?;
+                    ::pyo3::PyResult::Ok(())
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // __setitem__() takes &mut self, which a frozen class does not allow,
+    // and it is meaningless without set fields
+    let setitem = if setitem_arms.is_empty() || pyo3_opt.frozen {
+        quote! {}
+    } else {
+        quote! {
+            pub fn __setitem__(
+                &mut self,
+                key: &str,
+                value: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<()> {
+                match key {
+                    #(#setitem_arms,)*
+                    _ => ::pyo3::PyResult::Err(::pyo3::exceptions::PyKeyError::new_err(key.to_string())),
+                }
+            }
+        }
+    };
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __getitem__(
+                &self,
+                py: ::pyo3::Python<'_>,
+                key: &str,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                match key {
+                    #(#getitem_arms,)*
+                    _ => ::pyo3::PyResult::Err(::pyo3::exceptions::PyKeyError::new_err(key.to_string())),
+                }
+            }
+
+            #setitem
+
+            pub fn keys(&self) -> ::std::vec::Vec<&'static ::std::primitive::str> {
+                ::std::vec![#(#keys),*]
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
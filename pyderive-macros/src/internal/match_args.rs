@@ -2,19 +2,55 @@ use std::iter;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{ext::IdentExt, spanned::Spanned, DeriveInput};
 
+use crate::attr::{pyderive_struct::SignatureItem, PyderiveStructOption};
 use crate::common::FieldData;
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
     let data = FieldData::try_from_input(&input)?;
 
-    let names = data
+    // the positional arguments of __new__() in order, like PyNew,
+    // the keyword only fields are not matched positionally, like dataclass
+    let positional = match &struct_opt.signature {
+        Some(items) => items
+            .iter()
+            .take_while(|item| !matches!(item, SignatureItem::Star(_)))
+            .filter_map(|item| match item {
+                SignatureItem::Arg(name, _) => {
+                    data.iter().find(|d| d.pyident.unraw() == name.unraw())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        None => data
+            .iter()
+            .take_while(|d| !d.kw_only())
+            .filter(|d| d.new())
+            .collect::<Vec<_>>(),
+    };
+    let position = |d: &FieldData| positional.iter().position(|p| p.index == d.index);
+
+    for d in data.iter() {
+        if d.match_args(false) && !d.new() {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "match_args=true conflicts with a field that is not an argument of __new__()",
+            ));
+        }
+    }
+
+    // __match_args__ follows the positional arguments of __new__(),
+    // so that `match Class(a, b)` binds the same fields as `Class(a, b)` takes
+    let mut fields = data
         .iter()
-        .filter(|d| d.match_args())
-        .map(|d| &d.pyname)
+        .filter(|d| d.match_args(position(d).is_some()))
         .collect::<Vec<_>>();
+    fields.sort_by_key(|d| position(d).unwrap_or(usize::MAX));
+
+    let names = fields.iter().map(|d| &d.pyname).collect::<Vec<_>>();
 
     let types = iter::repeat(quote! { &'static ::std::primitive::str }).take(names.len());
 
@@ -0,0 +1,50 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, DeriveInput};
+
+use crate::common::{is_py, FieldData};
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    let mut names = data.iter().filter(|d| d.is_name());
+    let d = match names.next() {
+        Some(d) => d,
+        None => {
+            return Err(syn::Error::new(
+                struct_name.span(),
+                "PyName requires a field marked by #[pyderive(is_name)]",
+            ))
+        }
+    };
+    if let Some(dup) = names.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(is_name)] field",
+        ));
+    }
+
+    let ident = &d.field.ident;
+    let value = if is_py(&d.field.ty) {
+        quote! { self.#ident.clone_ref(py).into_any() }
+    } else {
+        quote! { (&self.#ident).into_pyobject(py)?.into_any().unbind() }
+    };
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[getter]
+            pub fn name(
+                &self,
+                py: ::pyo3::Python<'_>,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                ::pyo3::PyResult::Ok(#value)
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
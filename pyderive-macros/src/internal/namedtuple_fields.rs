@@ -31,8 +31,9 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
         #[automatically_derived]
         impl #struct_name {
             #[classattr]
-            pub fn _fields() -> ( #(#return_type),* ) {
-                ( #(#names),* )
+            #[allow(clippy::unused_unit)]
+            pub fn _fields() -> ( #(#return_type,)* ) {
+                ( #(#names,)* )
             }
         }
     };
@@ -1,8 +1,10 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::DeriveInput;
+use proc_macro2::TokenTree;
+use quote::{quote, ToTokens};
+use syn::{ext::IdentExt, spanned::Spanned, DeriveInput, Expr};
 
-use crate::common::FieldData;
+use crate::attr::{pyderive_struct::SignatureItem, PyderiveStructOption};
+use crate::common::{py_inner, typed_default, FieldData};
 
 // #[pyderive]                          -> __new__(field):     ...
 // #[pyderive(default=xxx)]             -> __new__(field=xxx): ...
@@ -10,27 +12,212 @@ use crate::common::FieldData;
 // #[pyderive(new=false)]              -> __new__():          field=default()
 // #[pyderive(new=true, default=xxx)]  -> __new__(field=xxx): ...
 // #[pyderive(new=false, default=xxx)] -> __new__():          field=xxx
+// #[pyderive(try_from=Src)]           -> __new__(field: Src): field=TryFrom::try_from(field)?
+// #[pyderive(init_var)]               -> __new__(field: T):   field=PhantomData, self.__post_init__(field)?
+// #[pyderive(positional_only)]        -> __new__(field, /):  ...
+// #[pyderive(default=f(py)?)]         -> __new__(field=None): field=field.unwrap_or(f(py)?)
+// #[pyderive(compute=|this| ..)]      -> __new__():          field=default(), then field=(|this| ..)(&this)
+//
+// #[pyderive(flatten)]                -> __new__(**kwargs):  field=Child(**kwargs)
+//
+// #[pyderive(signature=(b, /, a=1))] on the struct -> __new__(b, /, a=1): ...
+//   the arguments follow the given order, and a name without `=` takes the default of the field
+
+// PyO3 evaluates the default of the signature in a non-capturing closure,
+// thus the default that uses `py` or `?` is evaluated in the body of __new__() instead.
+// The argument is `Option<T>` extracted by `#[pyo3(from_py_with)]`, which keeps a passed `None` as `Some(None)`,
+// thus a false positive (e.g. a shadowed `py`) only moves the evaluation into the body.
+fn is_body_default(d: &FieldData) -> bool {
+    fn walk(tokens: proc_macro2::TokenStream) -> bool {
+        tokens.into_iter().any(|t| match t {
+            TokenTree::Ident(ident) => ident == "py",
+            TokenTree::Punct(punct) => punct.as_char() == '?',
+            TokenTree::Group(group) => walk(group.stream()),
+            TokenTree::Literal(_) => false,
+        })
+    }
+
+    d.new()
+        && d.default
+            .as_ref()
+            .map(Expr::to_token_stream)
+            .is_some_and(walk)
+}
 
 // For new=true
 fn fn_signature(d: &FieldData) -> proc_macro2::TokenStream {
     let pyident = &d.pyident;
+    let ty = d.arg_type();
     match &d.default {
-        Some(expr) => quote! { #pyident=#expr },
+        Some(_) if is_body_default(d) => {
+            // typed None, the field type may be an Option
+            quote! { #pyident=::std::option::Option::None::<#ty> }
+        }
+        Some(expr) => {
+            let default = typed_default(ty, expr);
+            quote! { #pyident=#default }
+        }
         None => quote! { #pyident },
     }
 }
 
+// Reorders the new=true fields by #[pyderive(signature=..)],
+// and overrides the defaults of the fields by the given ones.
+fn apply_signature<'a>(
+    data: &[FieldData<'a>],
+    items: &[SignatureItem],
+) -> syn::Result<(Vec<proc_macro2::TokenStream>, Vec<FieldData<'a>>)> {
+    if let Some(d) = data
+        .iter()
+        .find(|d| d.new() && (d.positional_only() || d.kw_only()))
+    {
+        return Err(syn::Error::new(
+            d.field.span(),
+            "positional_only and kw_only conflict with signature of the struct, use / and * in the signature instead",
+        ));
+    }
+
+    let mut signature = Vec::new();
+    let mut ordered: Vec<FieldData> = Vec::new();
+    for item in items {
+        match item {
+            SignatureItem::Slash(t) => signature.push(t.to_token_stream()),
+            SignatureItem::Star(t) => signature.push(t.to_token_stream()),
+            SignatureItem::Arg(name, default) => {
+                let Some(d) = data.iter().find(|d| d.pyident.unraw() == name.unraw()) else {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("no field named `{name}` in signature"),
+                    ));
+                };
+                if !d.new() {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("field `{name}` is not an argument of __new__()"),
+                    ));
+                }
+                if ordered.iter().any(|o| o.pyident.unraw() == name.unraw()) {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("duplicated `{name}` in signature"),
+                    ));
+                }
+
+                let mut d = d.clone();
+                if default.is_some() {
+                    d.default.clone_from(default);
+                }
+                signature.push(fn_signature(&d));
+                ordered.push(d);
+            }
+        }
+    }
+
+    if let Some(d) = data
+        .iter()
+        .find(|d| d.new() && !ordered.iter().any(|o| o.pyident == d.pyident))
+    {
+        return Err(syn::Error::new(
+            d.field.span(),
+            format!("field `{}` is missing in signature", d.pyident),
+        ));
+    }
+
+    Ok((signature, ordered))
+}
+
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
     let data = FieldData::try_from_input(&input)?;
 
+    // the Python name is the argument name, thus it must be an identifier
+    if let Some(d) = data
+        .iter()
+        .find(|d| d.new() && d.pyident.unraw() != d.pyname)
+    {
+        return Err(syn::Error::new(
+            d.field.span(),
+            format!(
+                "the Python name `{}` is not a valid argument name of __new__(), rename it by #[pyo3(name = \"..\")]",
+                d.pyname
+            ),
+        ));
+    }
+
+    let mut flattens = data.iter().filter(|d| d.flatten());
+    let flatten = flattens.next();
+    if let Some(dup) = flattens.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(flatten)] field",
+        ));
+    }
+    if let Some(d) = flatten {
+        if d.new()
+            || d.default.is_some()
+            || d.try_from.is_some()
+            || d.init_var()
+            || d.compute.is_some()
+        {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "flatten conflicts with new, default, try_from, init_var and compute",
+            ));
+        }
+        if struct_opt.signature.is_some() {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "flatten conflicts with signature of the struct",
+            ));
+        }
+        if data.iter().any(|d| d.new() && d.pyident == "py") {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "flatten conflicts with the field named `py`",
+            ));
+        }
+    }
+
+    if let Some(items) = &struct_opt.signature {
+        let (signature, ordered) = apply_signature(&data, items)?;
+        return expand(struct_name, &data, signature, &ordered);
+    }
+
+    // positional_only fields must lead the arguments
+    let mut seen_other = false;
+    for (i, d) in data.iter().enumerate().filter(|(_, d)| d.new()) {
+        if !d.positional_only() {
+            seen_other = true;
+        } else if seen_other {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "positional_only field must precede the other arguments of __new__()",
+            ));
+        } else if data[..=i].iter().any(|d| d.kw_only()) {
+            return Err(syn::Error::new(
+                d.field.span(),
+                "positional_only conflicts with kw_only",
+            ));
+        }
+    }
+
     // #[pyo3(signature=..)]
     let mut signature = Vec::new();
 
+    signature.extend(
+        data.iter()
+            .filter(|d| d.new() && d.positional_only())
+            .map(fn_signature),
+    );
+    if !signature.is_empty() {
+        signature.push(quote! { / });
+    }
+
     signature.extend(
         data.iter()
             .take_while(|d| !d.kw_only())
-            .filter(|d| d.new())
+            .filter(|d| d.new() && !d.positional_only())
             .map(fn_signature),
     );
 
@@ -46,18 +233,72 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
         signature.extend(rest_args);
     }
 
+    // the rest of the keyword arguments construct the flatten field
+    if flatten.is_some() {
+        signature.push(quote! { **__pyderive_flatten_kwargs });
+    }
+
+    let args = data.iter().filter(|d| d.new()).cloned().collect::<Vec<_>>();
+    expand(struct_name, &data, signature, &args)
+}
+
+// `args` is the new=true fields in the order of the signature
+fn expand(
+    struct_name: &syn::Ident,
+    data: &[FieldData<'_>],
+    signature: Vec<proc_macro2::TokenStream>,
+    args: &[FieldData],
+) -> syn::Result<TokenStream> {
     // constructor arguments
-    let new_args = data
+    let mut new_args = args
         .iter()
-        .filter(|d| d.new())
         .map(|d| {
-            let ty = d.field.ty.to_owned();
-            let pyident = d.pyident.to_owned();
+            let ty = d.arg_type();
+            let pyident = &d.pyident;
 
-            quote! { #pyident: #ty }
+            if is_body_default(d) {
+                // None only if the argument is missing, not if None is passed
+                quote! {
+                    #[pyo3(from_py_with = #struct_name::__pyderive_internal_new_arg::<#ty>)]
+                    #pyident: ::std::option::Option<#ty>
+                }
+            } else {
+                quote! { #pyident: #ty }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // the rest of the keyword arguments
+    if data.iter().any(|d| d.flatten()) {
+        new_args.push(quote! {
+            __pyderive_flatten_kwargs: ::std::option::Option<&::pyo3::Bound<'_, ::pyo3::types::PyDict>>
+        });
+    }
+
+    // evaluates the defaults in the body
+    let body_defaults = args
+        .iter()
+        .filter(|d| is_body_default(d))
+        .map(|d| {
+            let pyident = &d.pyident;
+            let expr = &d.default;
+
+            quote! {
+                let #pyident = match #pyident {
+                    ::std::option::Option::Some(v) => v,
+                    ::std::option::Option::None => #expr,
+                };
+            }
         })
         .collect::<Vec<_>>();
 
+    // `py` is available in the defaults unless a field occupies the name
+    let py_arg = if data.iter().any(|d| d.new() && d.pyident == "py") {
+        quote! {}
+    } else {
+        quote! { py: ::pyo3::Python<'_>, }
+    };
+
     // Self arguments
     let self_args = data
         .iter()
@@ -66,17 +307,85 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
             let ident = d.field.ident.as_ref().unwrap();
             let pyident = d.pyident.to_owned();
 
-            if d.new() {
-                quote! { #ident: #pyident }
+            if d.init_var() {
+                quote! { #ident: ::std::marker::PhantomData }
+            } else if d.flatten() {
+                // calls __new__() of the class of the field, e.g. a Py<Child> or a Clone Child field
+                let class = py_inner(&ty).unwrap_or(&ty);
+                quote! {
+                    #ident: ::pyo3::types::PyAnyMethods::extract(
+                        &::pyo3::types::PyAnyMethods::call(
+                            py.get_type::<#class>().as_any(),
+                            (),
+                            __pyderive_flatten_kwargs,
+                        )?
+                    )?
+                }
+            } else if d.new() {
+                match &d.try_from {
+                    Some(src) => quote! {
+                        #ident: <#ty as ::std::convert::TryFrom<#src>>::try_from(#pyident)
+                            .map_err(|e| ::pyo3::exceptions::PyValueError::new_err(::std::string::ToString::to_string(&e)))?
+                    },
+                    None => quote! { #ident: #pyident },
+                }
             } else {
                 match &d.default {
                     Some(expr) => quote! { #ident: #expr },
-                    None => quote! { #ident: #ty::default() },
+                    None => quote! { #ident: <#ty as ::std::default::Default>::default() },
                 }
             }
         })
         .collect::<Vec<_>>();
 
+    // init_var fields are passed to __post_init__()
+    let init_vars = data
+        .iter()
+        .filter(|d| d.init_var())
+        .map(|d| &d.pyident)
+        .collect::<Vec<_>>();
+
+    // computed fields run after the other fields are set, in the order of declaration
+    let computes = data
+        .iter()
+        .filter_map(|d| {
+            let ty = &d.field.ty;
+            let ident = d.field.ident.as_ref().unwrap();
+            d.compute.as_ref().map(|expr| {
+                quote! {
+                    this.#ident = {
+                        let compute: &dyn ::std::ops::Fn(&Self) -> #ty = &#expr;
+                        compute(&this)
+                    };
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let post_init = if init_vars.is_empty() {
+        quote! {}
+    } else {
+        quote! { this.__post_init__(#(#init_vars),*)?; }
+    };
+
+    let helper = if data.iter().any(|d| d.new() && is_body_default(d)) {
+        quote! {
+            #[automatically_derived]
+            impl #struct_name {
+                #[doc(hidden)]
+                fn __pyderive_internal_new_arg<'a, 'py, T: ::pyo3::FromPyObject<'a, 'py>>(
+                    obj: &'a ::pyo3::Bound<'py, ::pyo3::PyAny>,
+                ) -> ::pyo3::PyResult<::std::option::Option<T>> {
+                    ::pyo3::types::PyAnyMethods::extract::<T>(obj)
+                        .map(::std::option::Option::Some)
+                        .map_err(::std::convert::Into::into)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         #[pymethods]
         #[automatically_derived]
@@ -84,12 +393,22 @@ pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
             #[new]
             #[pyo3(signature = ( #( #signature ),* ))]
             #[allow(non_snake_case)]
+            #[allow(unused_variables)]
+            #[allow(clippy::too_many_arguments)]
             pub fn __pyderive_internal_py_new(
+                #py_arg
                 #(#new_args),*
-            ) -> Self {
-                Self { #(#self_args),* }
+            ) -> ::pyo3::PyResult<Self> {
+                #(#body_defaults)*
+                #[allow(unused_mut)]
+                let mut this = Self { #(#self_args),* };
+                #(#computes)*
+                #post_init
+                ::pyo3::PyResult::Ok(this)
             }
         }
+
+        #helper
     };
 
     Ok(expanded.into())
@@ -2,46 +2,81 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
 
+use crate::attr::PyderiveStructOption;
+use crate::common::{check_richcmp_conflict, ordered, richcmp_slot_marker};
+
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    check_richcmp_conflict(&input, "PyOrd")?;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+
+    let partial_cmp = match &struct_opt.order_key {
+        Some(key) => quote! {
+            ::std::cmp::PartialOrd::partial_cmp(&#key(self), &#key(other))
+        },
+        None => quote! { self.partial_cmp(other) },
+    };
+    let partial_cmp = if struct_opt.order_reverse() {
+        quote! { #partial_cmp.map(Ordering::reverse) }
+    } else {
+        partial_cmp
+    };
+
+    let raise = struct_opt.order_nan_raise();
+    let lt = ordered(&partial_cmp, quote! { Ordering::Less }, raise);
+    let le = ordered(
+        &partial_cmp,
+        quote! { Ordering::Less | Ordering::Equal },
+        raise,
+    );
+    let gt = ordered(&partial_cmp, quote! { Ordering::Greater }, raise);
+    let ge = ordered(
+        &partial_cmp,
+        quote! { Ordering::Greater | Ordering::Equal },
+        raise,
+    );
+
+    // order_nan="raise" raises ValueError on the unordered instances
+    let (ret, [lt, le, gt, ge]) = if raise {
+        (
+            quote! { ::pyo3::PyResult<::std::primitive::bool> },
+            [lt, le, gt, ge].map(|r| quote! { ::pyo3::PyResult::Ok(#r) }),
+        )
+    } else {
+        (quote! { ::std::primitive::bool }, [lt, le, gt, ge])
+    };
 
     let expanded = quote! {
         #[pymethods]
         #[automatically_derived]
         impl #struct_name {
-            pub fn __lt__(&self, other: &Self) -> ::std::primitive::bool {
+            pub fn __lt__(&self, other: &Self) -> #ret {
                 use ::std::cmp::Ordering;
-                matches!(
-                    self.partial_cmp(other),
-                    ::std::option::Option::Some(Ordering::Less)
-                )
+                #lt
             }
 
-            pub fn __le__(&self, other: &Self) -> ::std::primitive::bool {
+            pub fn __le__(&self, other: &Self) -> #ret {
                 use ::std::cmp::Ordering;
-                matches!(
-                    self.partial_cmp(other),
-                    ::std::option::Option::Some(Ordering::Less | Ordering::Equal)
-                )
+                #le
             }
 
-            pub fn __gt__(&self, other: &Self) -> ::std::primitive::bool {
+            pub fn __gt__(&self, other: &Self) -> #ret {
                 use ::std::cmp::Ordering;
-                matches!(
-                    self.partial_cmp(other),
-                    ::std::option::Option::Some(Ordering::Greater)
-                )
+                #gt
             }
 
-            pub fn __ge__(&self, other: &Self) -> ::std::primitive::bool {
+            pub fn __ge__(&self, other: &Self) -> #ret {
                 use ::std::cmp::Ordering;
-                matches!(
-                    self.partial_cmp(other),
-                    ::std::option::Option::Some(Ordering::Greater | Ordering::Equal)
-                )
+                #ge
             }
         }
     };
 
+    let marker = richcmp_slot_marker(struct_name);
+    let expanded = quote! {
+        #expanded
+        #marker
+    };
+
     Ok(expanded.into())
 }
@@ -0,0 +1,43 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, FnArg, ImplItem, ItemImpl};
+
+pub fn implementation(mut input: ItemImpl) -> syn::Result<TokenStream> {
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(syn::Error::new(
+            path.span(),
+            "support inherent impl, not trait impl",
+        ));
+    }
+
+    for item in input.items.iter_mut() {
+        match item {
+            ImplItem::Fn(f) => {
+                match f.sig.inputs.first() {
+                    Some(FnArg::Receiver(r)) if r.reference.is_some() && r.mutability.is_none() => {
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            f.sig.span(),
+                            "property requires a method taking &self",
+                        ))
+                    }
+                }
+                f.attrs.push(syn::parse_quote! { #[getter] });
+            }
+            other => {
+                return Err(syn::Error::new(
+                    other.span(),
+                    "support fn only in the impl block",
+                ))
+            }
+        }
+    }
+
+    let expanded = quote! {
+        #[pymethods]
+        #input
+    };
+
+    Ok(expanded.into())
+}
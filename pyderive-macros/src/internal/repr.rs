@@ -1,54 +1,428 @@
-use std::iter;
-
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{spanned::Spanned, DeriveInput, Type, TypeTuple};
 
-use crate::common::{is_py, FieldData};
+use crate::attr::PyderiveStructOption;
+use crate::common::{
+    contains_py, is_chrono, is_py, is_str, is_system_time, py_inner, result_inner,
+    smart_pointer_inner, FieldData,
+};
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
     let data = FieldData::try_from_input(&input)?;
 
-    // args of format!(..)
-    let args = data
+    let mut caches = data.iter().filter(|d| d.cache_repr());
+    let cache = caches.next();
+    if let Some(dup) = caches.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(cache_repr)] field",
+        ));
+    }
+
+    // #[pyclass(frozen)] is shallow, the object that a Py<T> field refers to may mutate
+    if cache.is_some() {
+        if let Some(d) = data.iter().find(|d| d.repr() && contains_py(&d.field.ty)) {
+            return Err(syn::Error::new(
+                d.field.ty.span(),
+                "cache_repr conflicts with Py<T> field, the object may mutate after the repr is cached, \
+                 exclude it by #[pyderive(repr=false)]",
+            ));
+        }
+    }
+
+    // the class is frozen, so that the repr never changes once computed,
+    // a race just computes the same value twice
+    let (lookup, store) = match cache {
+        Some(d) => {
+            if let Some(lit) = &struct_opt.repr_depth {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "repr_depth conflicts with #[pyderive(cache_repr)], the repr depends on the nesting",
+                ));
+            }
+            let ident = &d.field.ident;
+            (
+                quote! {
+                    if let ::std::option::Option::Some(s) = slf.get().#ident.get() {
+                        return ::pyo3::PyResult::Ok(::std::clone::Clone::clone(s));
+                    }
+                },
+                quote! { let _ = this.#ident.set(::std::clone::Clone::clone(&s)); },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    let data = ordered_fields(&struct_opt, &data, |d| d.repr());
+    let (qualname, body) = body(&struct_opt, &data, false)?;
+
+    // the names of a flatten field are the ones of the class of the field
+    let names = data
         .iter()
-        .filter(|d| d.repr())
         .map(|d| {
-            let ident = d.field.ident.to_owned().unwrap();
-            let name = &d.pyname;
-
-            if is_py(&d.field.ty) {
-                quote! { #name, (&this.#ident).bind(py).repr()? }
+            if d.flatten() {
+                let class = flatten_class(d);
+                quote! {
+                    names.extend(
+                        py.get_type::<#class>()
+                            .getattr(::pyo3::intern!(py, "__pyderive_repr_fields__"))?
+                            .extract::<::std::vec::Vec<::std::string::String>>()?
+                    );
+                }
             } else {
-                quote! { #name, (&this.#ident).into_pyobject(py)?.repr()? }
+                let name = &d.pyname;
+                quote! { names.push(::std::string::String::from(#name)); }
             }
         })
         .collect::<Vec<_>>();
 
-    // fmt of format!(..)
-    let fmt = iter::repeat("{}={}")
-        .take(args.len())
-        .collect::<Vec<_>>()
-        .join(", ");
-    let fmt = "{}(".to_string() + &fmt + ")";
+    // the nesting level of the instances of the struct in the current thread,
+    // the instances deeper than repr_depth are rendered as "..."
+    let depth = match &struct_opt.repr_depth {
+        Some(lit) => {
+            let limit = lit.base10_parse::<usize>()?;
+            if limit == 0 {
+                return Err(syn::Error::new(lit.span(), "repr_depth must be positive"));
+            }
+            quote! {
+                ::std::thread_local! {
+                    static DEPTH: ::std::cell::Cell<::std::primitive::usize> = const { ::std::cell::Cell::new(0) };
+                }
+                struct DepthGuard;
+                impl ::std::ops::Drop for DepthGuard {
+                    fn drop(&mut self) {
+                        DEPTH.with(|depth| depth.set(depth.get() - 1));
+                    }
+                }
+
+                if DEPTH.with(|depth| depth.get()) >= #limit {
+                    return ::pyo3::PyResult::Ok(::std::string::String::from("..."));
+                }
+                DEPTH.with(|depth| depth.set(depth.get() + 1));
+                let _guard = DepthGuard;
+            }
+        }
+        None => quote! {},
+    };
+
+    // the class is a flatten field of another class
+    let flatten_items = if struct_opt.repr_flatten() {
+        let repr_items = render_items(&data, false);
+        let str_items = render_items(&data, true);
+        quote! {
+            #[automatically_derived]
+            impl #struct_name {
+                /// The pairs of the Python name and the rendered value of the fields in the repr,
+                /// by `str()` instead of `repr()` if `as_str`,
+                /// that a `#[pyderive(flatten)]` field of another class inlines.
+                #[doc(hidden)]
+                #[allow(unused_variables)]
+                pub fn __pyderive_repr_items(
+                    &self,
+                    py: ::pyo3::Python<'_>,
+                    as_str: ::std::primitive::bool,
+                ) -> ::pyo3::PyResult<::std::vec::Vec<(::std::string::String, ::std::string::String)>> {
+                    let this = self;
+                    #[allow(unused_mut)]
+                    let mut items = ::std::vec::Vec::<(::std::string::String, ::std::string::String)>::new();
+                    if as_str {
+                        #(#str_items)*
+                    } else {
+                        #(#repr_items)*
+                    }
+                    ::pyo3::PyResult::Ok(items)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let expanded = quote! {
         #[pymethods]
         #[automatically_derived]
         impl #struct_name {
             pub fn __repr__(slf: &::pyo3::Bound<'_, Self>) -> ::pyo3::PyResult<::std::string::String> {
-                let t = slf.get_type();
-                let qualname = t.qualname()?;
+                #lookup
+                #depth
+                #qualname
 
                 let py = slf.py();
                 let this = slf.borrow();
 
-                let s = format!(#fmt, qualname, #(#args),*);
+                #body
+                #store
                 ::pyo3::PyResult::Ok(s)
             }
+
+            // the Python names of the fields in the repr, for introspection
+            #[classattr]
+            pub fn __pyderive_repr_fields__(
+                py: ::pyo3::Python<'_>,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::types::PyTuple>> {
+                #[allow(unused_mut)]
+                let mut names = ::std::vec::Vec::<::std::string::String>::new();
+                #(#names)*
+                ::pyo3::PyResult::Ok(::pyo3::types::PyTuple::new(py, names)?.unbind())
+            }
         }
+
+        #flatten_items
     };
 
     Ok(expanded.into())
 }
+
+/// Returns the fields that `include` selects in declaration order, or by the displayed name.
+pub(crate) fn ordered_fields<'a, 'b>(
+    struct_opt: &PyderiveStructOption,
+    data: &'b [FieldData<'a>],
+    include: impl Fn(&FieldData) -> bool,
+) -> Vec<&'b FieldData<'a>> {
+    let mut data = data.iter().filter(|d| include(d)).collect::<Vec<_>>();
+    if struct_opt.repr_sorted() {
+        data.sort_by(|a, b| a.pyname.cmp(&b.pyname));
+    }
+    data
+}
+
+/// Returns the statements that bind `qualname` and that bind the rendered string `s` of `this`,
+/// shared by `__repr__()` and `__str__()`, which render the fields by `str()` if `as_str`.
+pub(crate) fn body(
+    struct_opt: &PyderiveStructOption,
+    data: &[&FieldData],
+    as_str: bool,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let qualname = match (&struct_opt.repr_prefix, &struct_opt.repr_name) {
+        (Some(_), Some(name)) => {
+            return Err(syn::Error::new(
+                name.span(),
+                "repr_name conflicts with repr_prefix, the prefix replaces \"<name>(\"",
+            ));
+        }
+        (Some(_), None) => quote! {},
+        (None, Some(name)) => quote! { let qualname = #name; },
+        (None, None) => quote! {
+            let t = slf.get_type();
+            let qualname = t.qualname()?;
+        },
+    };
+    let prefix = match &struct_opt.repr_prefix {
+        Some(prefix) => quote! { #prefix },
+        None => quote! { format!("{}(", qualname) },
+    };
+    let suffix = match &struct_opt.repr_suffix {
+        Some(suffix) => quote! { #suffix },
+        None => quote! { ")" },
+    };
+
+    let positional = struct_opt.repr_prefix.is_some() || struct_opt.repr_suffix.is_some();
+    let body = if data.iter().any(|d| d.flatten()) {
+        // the number of the flatten fields is known at runtime only
+        let items = render_items(data, as_str);
+        let join = join_items(positional);
+        quote! {
+            #[allow(unused_mut)]
+            let mut items = ::std::vec::Vec::<(::std::string::String, ::std::string::String)>::new();
+            #(#items)*
+            let s = format!("{}{}{}", #prefix, #join, #suffix);
+        }
+    } else {
+        let values = data
+            .iter()
+            .map(|d| render_field(d, as_str))
+            .collect::<Vec<_>>();
+
+        // repr_prefix/repr_suffix replace "<qualname>(" and ")",
+        // and the fields are rendered positionally, e.g. Decimal('1.5')
+        let (fmt, args) = if positional {
+            (vec!["{}"; values.len()].join(", "), values)
+        } else {
+            let args = data
+                .iter()
+                .zip(values)
+                .map(|(d, value)| {
+                    let name = &d.pyname;
+                    quote! { #name, #value }
+                })
+                .collect::<Vec<_>>();
+            (vec!["{}={}"; args.len()].join(", "), args)
+        };
+        let fmt = "{}".to_string() + &fmt + "{}";
+        quote! {
+            let s = format!(#fmt, #prefix, #(#args,)* #suffix);
+        }
+    };
+
+    Ok((qualname, body))
+}
+
+/// Returns the statements that push the pairs of the name and the rendered value of the fields to `items`,
+/// a flatten field extends them by the ones of the class of the field.
+fn render_items(data: &[&FieldData], as_str: bool) -> Vec<TokenStream2> {
+    data.iter()
+        .map(|d| {
+            if d.flatten() {
+                let ident = &d.field.ident;
+                let borrow = if py_inner(&d.field.ty).is_some() {
+                    quote! { this.#ident.bind(py).borrow() }
+                } else {
+                    quote! { this.#ident }
+                };
+                quote! { items.extend(#borrow.__pyderive_repr_items(py, #as_str)?); }
+            } else {
+                let name = &d.pyname;
+                let value = render_field(d, as_str);
+                quote! {
+                    items.push((
+                        ::std::string::String::from(#name),
+                        ::std::string::ToString::to_string(&#value),
+                    ));
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the expression of the string that joins `items`,
+/// `name=value` or `value` if `positional`.
+fn join_items(positional: bool) -> TokenStream2 {
+    if positional {
+        quote! {
+            items.iter().map(|(_, value)| value.as_str()).collect::<::std::vec::Vec<_>>().join(", ")
+        }
+    } else {
+        quote! {
+            items.iter().map(|(name, value)| format!("{}={}", name, value)).collect::<::std::vec::Vec<_>>().join(", ")
+        }
+    }
+}
+
+/// The class of a flatten field, `T` of `Py<T>` or the type itself.
+fn flatten_class<'a>(d: &'a FieldData) -> &'a Type {
+    py_inner(&d.field.ty).unwrap_or(&d.field.ty)
+}
+
+/// Returns the expression of the rendered field of `this`, which is `std::fmt::Display`,
+/// by `str()` instead of `repr()` if `as_str` (the elements are still rendered by `repr()`).
+fn render_field(d: &FieldData, as_str: bool) -> TokenStream2 {
+    let ident = d.field.ident.as_ref().unwrap();
+
+    if d.repr_debug() {
+        // the Rust Debug inserted verbatim, e.g. for an opaque field
+        quote! { format!("{:?}", &this.#ident) }
+    } else if d.repr_name() {
+        // the __name__ of a callable, e.g. on_click instead of <function on_click at 0x..>,
+        // the object without __name__ falls back to repr()
+        quote! {
+            {
+                let obj = ::pyo3::IntoPyObjectExt::into_bound_py_any(&this.#ident, py)?;
+                match obj.getattr(::pyo3::intern!(py, "__name__")) {
+                    ::pyo3::PyResult::Ok(name) => name.str()?,
+                    ::pyo3::PyResult::Err(_) => obj.repr()?,
+                }
+            }
+        }
+    } else if let Some(spec) = &d.repr_float {
+        // Python format spec, e.g. format(1.0, ".3f") == "1.000"
+        quote! {
+            (&this.#ident).into_pyobject(py)?.call_method1(
+                ::pyo3::intern!(py, "__format__"),
+                (#spec,)
+            )?
+        }
+    } else if d.path_str() {
+        // the string form like a plain quoted string, not PosixPath('..')
+        quote! {
+            ::pyo3::types::PyString::new(
+                py,
+                &::std::convert::AsRef::<::std::path::Path>::as_ref(&this.#ident).to_string_lossy()
+            ).repr()?
+        }
+    } else if is_system_time(&d.field.ty) || is_chrono(&d.field.ty) {
+        // an ISO 8601 string of the datetime, date or time, e.g. '1970-01-01T00:00:00+00:00'
+        quote! {
+            (&this.#ident).into_pyobject(py)?
+                .call_method0(::pyo3::intern!(py, "isoformat"))?
+                .repr()?
+        }
+    } else if as_str {
+        render_str(&d.field.ty, quote! { &this.#ident })
+    } else {
+        render(&d.field.ty, quote! { &this.#ident })
+    }
+}
+
+/// Returns the expression of the Python `str()` of `expr` of the type `ty`,
+/// where a tuple and `Result<T, E>` are rendered as [`render`] does, like `str()` of a tuple calls `repr()`.
+fn render_str(ty: &Type, expr: TokenStream2) -> TokenStream2 {
+    if let Some(inner) = smart_pointer_inner(ty) {
+        return render_str(inner, quote! { &**(#expr) });
+    }
+
+    match ty {
+        Type::Tuple(_) => render(ty, expr),
+        ty if result_inner(ty).is_some() => render(ty, expr),
+        ty if is_py(ty) => quote! { (#expr).bind(py).str()? },
+        ty if is_str(ty) => quote! { ::std::convert::AsRef::<str>::as_ref(#expr) },
+        _ => quote! { (#expr).into_pyobject(py)?.str()? },
+    }
+}
+
+/// Returns the expression of the Python `repr()` of `expr` of the type `ty`,
+/// which is `std::fmt::Display`.
+///
+/// A tuple is rendered element by element recursively,
+/// so that it has no arity limit of `IntoPyObject`,
+/// and `Box<T>`, `Arc<T>` and `Rc<T>` are rendered as `T`.
+/// `Result<T, E>`, which has no `IntoPyObject`, is rendered as `Ok(..)` or `Err(..)`.
+fn render(ty: &Type, expr: TokenStream2) -> TokenStream2 {
+    // renders the pointee of Box, Arc and Rc
+    if let Some(inner) = smart_pointer_inner(ty) {
+        return render(inner, quote! { &**(#expr) });
+    }
+
+    if let Some((ok, err)) = result_inner(ty) {
+        let ok = render(ok, quote! { value });
+        let err = render(err, quote! { error });
+        return quote! {
+            match #expr {
+                ::std::result::Result::Ok(value) => format!("Ok({})", #ok),
+                ::std::result::Result::Err(error) => format!("Err({})", #err),
+            }
+        };
+    }
+
+    match ty {
+        Type::Tuple(TypeTuple { elems, .. }) if !elems.is_empty() => {
+            let elems = elems
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| {
+                    let index = syn::Index::from(i);
+                    render(ty, quote! { &(#expr).#index })
+                })
+                .collect::<Vec<_>>();
+            // (x,) for 1-tuple like Python
+            let fmt = if elems.len() == 1 {
+                "({},)".to_string()
+            } else {
+                format!("({})", vec!["{}"; elems.len()].join(", "))
+            };
+            quote! { format!(#fmt, #(#elems),*) }
+        }
+        ty if is_py(ty) => quote! { (#expr).bind(py).repr()? },
+        // borrow as &str, no intermediate clone
+        ty if is_str(ty) => quote! {
+            ::pyo3::types::PyString::new(
+                py,
+                ::std::convert::AsRef::<str>::as_ref(#expr)
+            ).repr()?
+        },
+        _ => quote! { (#expr).into_pyobject(py)?.repr()? },
+    }
+}
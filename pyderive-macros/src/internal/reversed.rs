@@ -2,29 +2,59 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::DeriveInput;
 
-use crate::common::{is_py, FieldData};
+use crate::attr::{IterMode, PyderiveStructOption};
+use crate::common::FieldData;
+use crate::internal::iter::value;
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
     let data = FieldData::try_from_input(&input)?;
 
     let iter_name = format_ident!("_____pyderive_InternalReversedIteratorFor{}", struct_name);
 
+    // fields with iter_order come first, and the others keep declaration order
+    let mut data = data.iter().filter(|d| d.iter()).collect::<Vec<_>>();
+    data.sort_by_key(|d| (d.iter_order.is_none(), d.iter_order));
+
     let args = data
         .iter()
-        .filter(|d| d.iter())
         .map(|d| {
-            let ident = &d.field.ident;
+            let name = &d.pyname;
+
+            let key = quote! { ::pyo3::types::PyString::new(py, #name).into_any().unbind() };
+            let value = value(d);
 
-            if is_py(&d.field.ty) {
-                quote! { (&slf.#ident).clone_ref(py).into_any() }
-            } else {
-                quote! { (&slf.#ident).into_pyobject(py)?.into_any().unbind() }
+            match struct_opt.iter_mode() {
+                IterMode::Values => value,
+                IterMode::Items => quote! {
+                    ::pyo3::types::PyTuple::new(py, [#key, #value])?.into_any().unbind()
+                },
+                IterMode::Keys => key,
             }
         })
         .collect::<Vec<_>>();
     let length = args.len();
 
+    // iterates a tuple, the builtin iterator does not take a lock on __next__()
+    if struct_opt.iter_snapshot() {
+        let expanded = quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __reversed__<'py>(
+                    slf: ::pyo3::PyRef<'py, Self>,
+                ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::types::PyIterator>> {
+                    let py = slf.py();
+                    let values: [::pyo3::Py<::pyo3::PyAny>; #length] = [ #(#args),* ];
+                    ::pyo3::types::PyTuple::new(py, values.into_iter().rev())?.try_iter()
+                }
+            }
+        };
+
+        return Ok(expanded.into());
+    }
+
     let expanded = quote! {
         #[pyclass]
         #[pyo3(name="pyderive_reverseiterator")]
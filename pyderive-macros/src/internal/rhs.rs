@@ -0,0 +1,87 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Ident, Type};
+
+use crate::attr::PyderiveStructOption;
+
+/// Returns `None` if `#[pyderive(rhs(..))]` is not given,
+/// then the caller falls back to the `&Self` operand implementation.
+pub fn implementation(
+    input: &DeriveInput,
+    pyname: Ident,
+    op_trait: Ident,
+    method: Ident,
+    reflected: bool,
+) -> syn::Result<Option<TokenStream>> {
+    let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+
+    let Some(types) = &struct_opt.rhs else {
+        return Ok(None);
+    };
+
+    let is_self = |ty: &Type| match ty {
+        Type::Path(p) => {
+            p.qself.is_none() && (p.path.is_ident("Self") || p.path.is_ident(struct_name))
+        }
+        _ => false,
+    };
+
+    // by_value clones self and the Self operand for the impl of `Self`
+    let this = if struct_opt.by_value() {
+        quote! { ::std::clone::Clone::clone(self) }
+    } else {
+        quote! { self }
+    };
+    let calc = if reflected {
+        quote! { #op_trait::#method(other, #this) }
+    } else {
+        quote! { #op_trait::#method(#this, other) }
+    };
+
+    let clone_other = if struct_opt.by_value() {
+        quote! { let other = ::std::clone::Clone::clone(other); }
+    } else {
+        quote! {}
+    };
+
+    // try each type in order, the first succeeded extraction wins
+    let arms = types
+        .iter()
+        .map(|ty| {
+            if is_self(ty) {
+                quote! {
+                    if let ::std::result::Result::Ok(other) = other.cast::<Self>() {
+                        let other = &*other.try_borrow()?;
+                        #clone_other
+                        return ::pyo3::IntoPyObjectExt::into_py_any(#calc, py);
+                    }
+                }
+            } else {
+                quote! {
+                    if let ::std::result::Result::Ok(other) = other.extract::<#ty>() {
+                        return ::pyo3::IntoPyObjectExt::into_py_any(#calc, py);
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn #pyname(
+                &self,
+                other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                use ::std::ops::#op_trait;
+                let py = other.py();
+                #(#arms)*
+                ::std::result::Result::Ok(py.NotImplemented())
+            }
+        }
+    };
+
+    Ok(Some(expanded.into()))
+}
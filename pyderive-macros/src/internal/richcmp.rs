@@ -3,42 +3,223 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
 
+use crate::attr::PyderiveStructOption;
+use crate::common::{check_richcmp_conflict, is_py, ordered, richcmp_slot_marker, FieldData};
+
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    check_richcmp_conflict(&input, "PyRichCmp")?;
+    expand(input, false)
+}
+
+/// The ordering of `PyDataclass`, where `==` and `!=` compare the fields even if `order_key` is given,
+/// like `@dataclass(eq=True, order=True)`.
+pub(crate) fn dataclass(input: DeriveInput) -> syn::Result<TokenStream> {
+    expand(input, true)
+}
+
+fn expand(input: DeriveInput, dataclass: bool) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
 
-    let expanded = quote! {
-        #[pymethods]
-        #[automatically_derived]
-        impl #struct_name {
-            pub fn __richcmp__(
-                &self,
-                other: &Self,
-                op: ::pyo3::pyclass::CompareOp
-            ) -> ::std::primitive::bool {
-                use ::std::cmp::Ordering;
-                use ::pyo3::pyclass::CompareOp;
-                match op {
-                    CompareOp::Eq => self.eq(other),
-                    CompareOp::Ne => self.ne(other),
-                    CompareOp::Lt => matches!(
-                        self.partial_cmp(other),
-                        ::std::option::Option::Some(Ordering::Less)
-                    ),
-                    CompareOp::Le => matches!(
-                        self.partial_cmp(other),
-                        ::std::option::Option::Some(Ordering::Less | Ordering::Equal)
-                    ),
-                    CompareOp::Gt => matches!(
-                        self.partial_cmp(other),
-                        ::std::option::Option::Some(Ordering::Greater)
-                    ),
-                    CompareOp::Ge => matches!(
-                        self.partial_cmp(other),
-                        ::std::option::Option::Some(Ordering::Greater | Ordering::Equal)
-                    )
+    // like PyEq, == and != of PyDataclass return NotImplemented unless type(other) is type(self),
+    // which requires the Python object of self
+    let exact = dataclass && !struct_opt.eq_subclass();
+    let this = if exact {
+        quote! { this }
+    } else {
+        quote! { self }
+    };
+
+    let (eq, ne) = match &struct_opt.order_key {
+        Some(key) if !dataclass => (
+            quote! { ::std::cmp::PartialEq::eq(&#key(#this), &#key(other)) },
+            quote! { ::std::cmp::PartialEq::ne(&#key(#this), &#key(other)) },
+        ),
+        _ => (
+            quote! { ::std::cmp::PartialEq::eq(#this, other) },
+            quote! { ::std::cmp::PartialEq::ne(#this, other) },
+        ),
+    };
+    let partial_cmp = match &struct_opt.order_key {
+        Some(key) => {
+            quote! { ::std::cmp::PartialOrd::partial_cmp(&#key(#this), &#key(other)) }
+        }
+        // every use reports the same error at the same span, which rustc reports once
+        None if dataclass => quote! {
+            {
+                #[allow(non_snake_case)]
+                fn pyderive_order_requires_derive_PartialOrd<T: ::std::cmp::PartialOrd>(
+                    a: &T,
+                    b: &T,
+                ) -> ::std::option::Option<Ordering> {
+                    a.partial_cmp(b)
+                }
+                pyderive_order_requires_derive_PartialOrd(#this, other)
+            }
+        },
+        None => quote! { ::std::cmp::PartialOrd::partial_cmp(#this, other) },
+    };
+    let partial_cmp = if struct_opt.order_reverse() {
+        quote! { #partial_cmp.map(Ordering::reverse) }
+    } else {
+        partial_cmp
+    };
+
+    let raise = struct_opt.order_nan_raise();
+    let lt = ordered(&partial_cmp, quote! { Ordering::Less }, raise);
+    let le = ordered(
+        &partial_cmp,
+        quote! { Ordering::Less | Ordering::Equal },
+        raise,
+    );
+    let gt = ordered(&partial_cmp, quote! { Ordering::Greater }, raise);
+    let ge = ordered(
+        &partial_cmp,
+        quote! { Ordering::Greater | Ordering::Equal },
+        raise,
+    );
+
+    let cmp = quote! {
+        match op {
+            CompareOp::Eq => #eq,
+            CompareOp::Ne => #ne,
+            CompareOp::Lt => #lt,
+            CompareOp::Le => #le,
+            CompareOp::Gt => #gt,
+            CompareOp::Ge => #ge,
+        }
+    };
+
+    let expanded = if struct_opt.richcmp_tuple() {
+        let data = FieldData::try_from_input(&input)?;
+
+        let args = data
+            .iter()
+            .filter(|d| d.get)
+            .map(|d| {
+                let ident = &d.field.ident;
+
+                if is_py(&d.field.ty) {
+                    quote! { (&#this.#ident).clone_ref(py).into_any() }
+                } else {
+                    quote! { (&#this.#ident).into_pyobject(py)?.into_any().unbind() }
+                }
+            })
+            .collect::<Vec<_>>();
+        let length = args.len();
+
+        let (receiver, borrow, check) = if exact {
+            (
+                quote! { slf: &::pyo3::Bound<'_, Self>, },
+                quote! { let this = &*slf.try_borrow()?; },
+                quote! {
+                    if matches!(op, CompareOp::Eq | CompareOp::Ne) && !other.get_type().is(&slf.get_type()) {
+                        return ::pyo3::PyResult::Ok(py.NotImplemented());
+                    }
+                },
+            )
+        } else {
+            (quote! { &self, }, quote! {}, quote! {})
+        };
+
+        quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __richcmp__(
+                    #receiver
+                    other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+                    op: ::pyo3::pyclass::CompareOp
+                ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                    use ::std::cmp::Ordering;
+                    use ::pyo3::pyclass::CompareOp;
+
+                    let py = other.py();
+                    #borrow
+
+                    if let Ok(other) = other.cast::<Self>() {
+                        #check
+                        let other = other.borrow();
+                        let other: &Self = &other;
+                        let r = #cmp;
+                        return Ok(::pyo3::types::PyBool::new(py, r).to_owned().into_any().unbind());
+                    }
+
+                    // compares with a tuple of the `get` fields
+                    if let Ok(other) = other.cast::<::pyo3::types::PyTuple>() {
+                        if other.len() == #length {
+                            let elements: [::pyo3::Py<::pyo3::PyAny>; #length] = [ #(#args),* ];
+                            let this = ::pyo3::types::PyTuple::new(py, elements)?;
+                            match this.rich_compare(other, op) {
+                                Ok(r) => return Ok(r.unbind()),
+                                Err(e) if e.is_instance_of::<::pyo3::exceptions::PyTypeError>(py) => {}
+                                Err(e) => return Err(e),
+                            }
+                        }
+                    }
+
+                    Ok(py.NotImplemented())
                 }
             }
         }
+    } else if exact {
+        quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __richcmp__(
+                    slf: &::pyo3::Bound<'_, Self>,
+                    other: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+                    op: ::pyo3::pyclass::CompareOp
+                ) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                    use ::std::cmp::Ordering;
+                    use ::pyo3::pyclass::CompareOp;
+
+                    let py = slf.py();
+                    if matches!(op, CompareOp::Eq | CompareOp::Ne) && !other.get_type().is(&slf.get_type()) {
+                        return ::pyo3::PyResult::Ok(py.NotImplemented());
+                    }
+                    let ::std::result::Result::Ok(other) = other.cast::<Self>() else {
+                        return ::pyo3::PyResult::Ok(py.NotImplemented());
+                    };
+                    let this = &*slf.try_borrow()?;
+                    let other = &*other.try_borrow()?;
+                    let r = #cmp;
+                    ::pyo3::PyResult::Ok(::pyo3::types::PyBool::new(py, r).to_owned().into_any().unbind())
+                }
+            }
+        }
+    } else {
+        // order_nan="raise" raises ValueError on the unordered instances
+        let (ret, cmp) = if raise {
+            (
+                quote! { ::pyo3::PyResult<::std::primitive::bool> },
+                quote! { ::pyo3::PyResult::Ok(#cmp) },
+            )
+        } else {
+            (quote! { ::std::primitive::bool }, cmp)
+        };
+        quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __richcmp__(
+                    &self,
+                    other: &Self,
+                    op: ::pyo3::pyclass::CompareOp
+                ) -> #ret {
+                    use ::std::cmp::Ordering;
+                    use ::pyo3::pyclass::CompareOp;
+                    #cmp
+                }
+            }
+        }
+    };
+
+    let marker = richcmp_slot_marker(struct_name);
+    let expanded = quote! {
+        #expanded
+        #marker
     };
 
     Ok(expanded.into())
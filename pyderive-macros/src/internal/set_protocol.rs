@@ -0,0 +1,62 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, DeriveInput};
+
+use crate::common::FieldData;
+
+pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
+    let struct_name = &input.ident;
+    let data = FieldData::try_from_input(&input)?;
+
+    let mut fields = data.iter().filter(|d| d.set_field());
+    let d = match fields.next() {
+        Some(d) => d,
+        None => {
+            return Err(syn::Error::new(
+                struct_name.span(),
+                "PySetProtocol requires a field marked by #[pyderive(set_field)]",
+            ))
+        }
+    };
+    if let Some(dup) = fields.next() {
+        return Err(syn::Error::new(
+            dup.field.span(),
+            "duplicated #[pyderive(set_field)] field",
+        ));
+    }
+
+    let ident = &d.field.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __iter__<'py>(
+                slf: ::pyo3::PyRef<'py, Self>,
+            ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::types::PyIterator>> {
+                let py = slf.py();
+                (&slf.#ident).into_pyobject(py)?.into_any().try_iter()
+            }
+
+            pub fn __len__(&self) -> ::std::primitive::usize {
+                self.#ident.len()
+            }
+
+            // by Python equality, an unhashable item is just not contained
+            pub fn __contains__(
+                slf: ::pyo3::PyRef<'_, Self>,
+                item: &::pyo3::Bound<'_, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::std::primitive::bool> {
+                let py = slf.py();
+                for element in (&slf.#ident).into_pyobject(py)?.into_any().try_iter()? {
+                    if element?.eq(item)? {
+                        return ::pyo3::PyResult::Ok(true);
+                    }
+                }
+                ::pyo3::PyResult::Ok(false)
+            }
+        }
+    };
+
+    Ok(expanded.into())
+}
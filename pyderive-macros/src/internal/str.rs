@@ -1,50 +1,68 @@
-use std::iter;
-
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::DeriveInput;
 
-use crate::common::{is_py, FieldData};
+use crate::attr::PyderiveStructOption;
+use crate::common::FieldData;
+use crate::internal::repr::{body, ordered_fields};
 
 pub fn implementation(input: DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
-    let data = FieldData::try_from_input(&input)?;
+    let struct_opt = PyderiveStructOption::try_from(&input.attrs)?;
+
+    if struct_opt.str_repr() {
+        if struct_opt.str_with.is_some() {
+            return Err(syn::Error::new(
+                struct_name.span(),
+                "str=\"repr\" conflicts with str_with=<path>",
+            ));
+        }
+
+        // like the default __str__() of Python
+        let expanded = quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __str__(slf: &::pyo3::Bound<'_, Self>) -> ::pyo3::PyResult<::std::string::String> {
+                    slf.repr()?.extract()
+                }
+            }
+        };
+
+        return Ok(expanded.into());
+    }
 
-    // args of format!(..)
-    let args = data
-        .iter()
-        .filter(|d| d.str())
-        .map(|d| {
-            let ident = d.field.ident.as_ref().unwrap();
-            let name = &d.pyname;
-
-            if is_py(&d.field.ty) {
-                quote! { #name, (&this.#ident).bind(py).repr()? }
-            } else {
-                quote! { #name, (&this.#ident).into_pyobject(py)?.repr()? }
+    if let Some(path) = &struct_opt.str_with {
+        let expanded = quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __str__(&self) -> ::pyo3::PyResult<::std::string::String> {
+                    #path(self)
+                }
             }
-        })
-        .collect::<Vec<_>>();
+        };
+
+        return Ok(expanded.into());
+    }
+
+    let data = FieldData::try_from_input(&input)?;
 
-    // fmt of format!(..)
-    let fmt = iter::repeat("{}={}")
-        .take(args.len())
-        .collect::<Vec<_>>()
-        .join(", ");
-    let fmt = "{}(".to_string() + &fmt + ")";
+    // like dataclass, the fields are rendered by repr() as default
+    let data = ordered_fields(&struct_opt, &data, |d| d.str());
+    let (qualname, body) = body(&struct_opt, &data, struct_opt.str_children_str())?;
 
     let expanded = quote! {
         #[pymethods]
         #[automatically_derived]
         impl #struct_name {
             pub fn __str__(slf: &::pyo3::Bound<'_, Self>) -> ::pyo3::PyResult<::std::string::String> {
-                let t = slf.get_type();
-                let qualname = t.qualname()?;
+                #qualname
 
                 let this = slf.borrow();
                 let py = slf.py();
 
-                let s = format!(#fmt, qualname, #(#args),*);
+                #body
                 ::pyo3::PyResult::Ok(s)
             }
         }
@@ -4,11 +4,53 @@ mod attr;
 mod common;
 mod internal;
 
+/// Appends a `compile_error!` without `multiple-pymethods` feature
+/// if the expansion has a `#[pymethods]` block.
+///
+/// Each derive macro emits its own `#[pymethods]` block, thus two derives on the same struct,
+/// or a derive and a hand-written `#[pymethods]`, require the feature,
+/// and PyO3 reports conflicting implementations far from the cause without it.
+///
+/// The feature of pyderive enables the one of PyO3 and of this crate at once.
+fn require_multiple_pymethods(expanded: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use proc_macro::{Delimiter, TokenTree};
+
+    let mut pound = false;
+    let mut has_pymethods = false;
+    for token in expanded.clone() {
+        match token {
+            TokenTree::Group(g) if pound && g.delimiter() == Delimiter::Bracket => {
+                has_pymethods |= g.stream().into_iter().last().is_some_and(
+                    |t| matches!(t, TokenTree::Ident(i) if i.to_string() == "pymethods"),
+                );
+                pound = false;
+            }
+            TokenTree::Punct(p) => pound = p.as_char() == '#',
+            _ => pound = false,
+        }
+    }
+
+    if cfg!(feature = "multiple-pymethods") || !has_pymethods {
+        return expanded;
+    }
+
+    let check: proc_macro::TokenStream = quote::quote! {
+        ::std::compile_error!(
+            "pyderive requires `multiple-pymethods` feature, enable it in Cargo.toml"
+        );
+    }
+    .into();
+
+    let mut expanded = expanded;
+    expanded.extend(check);
+    expanded
+}
+
 #[proc_macro_derive(PyRepr, attributes(pyderive))]
 pub fn py_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::repr::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -17,7 +59,34 @@ pub fn py_repr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::str::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyDoc, attributes(pyderive))]
+pub fn py_doc(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::doc::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyFormat, attributes(pyderive))]
+pub fn py_format(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::format::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyFromDict, attributes(pyderive))]
+pub fn py_from_dict(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::from_dict::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -26,7 +95,16 @@ pub fn py_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::len::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyGetItem, attributes(pyderive))]
+pub fn py_getitem(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::getitem::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -35,7 +113,7 @@ pub fn py_len(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_iter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::iter::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -44,7 +122,7 @@ pub fn py_iter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_reversed(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::reversed::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -53,34 +131,70 @@ pub fn py_reversed(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_new(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::new::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
 
-#[proc_macro_derive(PyEq)]
+#[proc_macro_derive(PyEq, attributes(pyderive))]
 pub fn py_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::eq::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyFieldHash, attributes(pyderive))]
+pub fn py_field_hash(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::field_hash::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
 
-#[proc_macro_derive(PyOrd)]
+#[proc_macro_derive(PyComparable, attributes(pyderive))]
+pub fn py_comparable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::comparable::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyOrd, attributes(pyderive))]
 pub fn py_ord(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::ord::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
 
-#[proc_macro_derive(PyRichCmp)]
+#[proc_macro_derive(PyRichCmp, attributes(pyderive))]
 pub fn py_richcmp(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::richcmp::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyMapping, attributes(pyderive))]
+pub fn py_mapping(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::mapping::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyContainsKey, attributes(pyderive))]
+pub fn py_contains_key(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::contains_key::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -89,7 +203,61 @@ pub fn py_richcmp(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_match_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::match_args::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyName, attributes(pyderive))]
+pub fn py_name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::name::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PySetProtocol, attributes(pyderive))]
+pub fn py_set_protocol(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::set_protocol::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyClassVar, attributes(pyderive))]
+pub fn py_class_var(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::class_var::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_attribute]
+pub fn property(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if let Some(t) = attr.into_iter().next() {
+        return syn::Error::new(t.span().into(), "property takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+
+    let input = parse_macro_input!(item as syn::ItemImpl);
+    match internal::property::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyDataclass, attributes(pyderive))]
+pub fn py_dataclass(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::dataclass::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -98,7 +266,16 @@ pub fn py_match_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 pub fn py_field(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::dataclass_fields::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyCopyReplace, attributes(pyderive))]
+pub fn py_copy_replace(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::copy_replace::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -107,7 +284,7 @@ pub fn py_field(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn py_namedtuple_name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::namedtuple_make::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -116,7 +293,7 @@ pub fn py_namedtuple_name(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 pub fn py_namedtuple_fields(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::namedtuple_fields::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -125,7 +302,7 @@ pub fn py_namedtuple_fields(input: proc_macro::TokenStream) -> proc_macro::Token
 pub fn py_namedtuple_asdict(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::namedtuple_asdict::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -134,7 +311,7 @@ pub fn py_namedtuple_asdict(input: proc_macro::TokenStream) -> proc_macro::Token
 pub fn py_namedtuple_replace(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::namedtuple_replace::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
@@ -143,100 +320,276 @@ pub fn py_namedtuple_replace(input: proc_macro::TokenStream) -> proc_macro::Toke
 pub fn py_namedtuple_field_defaults(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     match internal::namedtuple_field_defaults::implementation(input) {
-        Ok(r) => r,
+        Ok(r) => require_multiple_pymethods(r),
         Err(e) => e.into_compile_error().into(),
     }
 }
 
+#[proc_macro_derive(PyIntEnum)]
+pub fn py_int_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match internal::int_enum::implementation(input) {
+        Ok(r) => require_multiple_pymethods(r),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+#[proc_macro_derive(PyClassGetItem)]
+pub fn py_class_getitem(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[classmethod]
+            pub fn __class_getitem__<'py>(
+                cls: &::pyo3::Bound<'py, ::pyo3::types::PyType>,
+                item: &::pyo3::Bound<'py, ::pyo3::PyAny>,
+            ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::PyAny>> {
+                let py = cls.py();
+                py.import(::pyo3::intern!(py, "types"))?
+                    .getattr(::pyo3::intern!(py, "GenericAlias"))?
+                    .call1((cls, item))
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
+#[proc_macro_derive(PyFromStr)]
+pub fn py_from_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[staticmethod]
+            pub fn from_str(s: &str) -> ::pyo3::PyResult<Self> {
+                <Self as ::std::str::FromStr>::from_str(s).map_err(|e| {
+                    ::pyo3::exceptions::PyValueError::new_err(::std::string::ToString::to_string(&e))
+                })
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
+#[proc_macro_derive(PyDefaultNew)]
+pub fn py_default_new(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            #[new]
+            #[allow(non_snake_case)]
+            pub fn __pyderive_internal_py_default_new() -> Self {
+                ::std::default::Default::default()
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
 // ops
 
 macro_rules! impl_unary {
     ($derive:ident, $name:ident, $pyname:ident, $trait:ident::$method:ident) => {
-        #[proc_macro_derive($derive)]
+        #[proc_macro_derive($derive, attributes(pyderive))]
         pub fn $name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             use quote::quote;
 
             let input = parse_macro_input!(input as DeriveInput);
 
             let struct_name = &input.ident;
+            let struct_opt = match attr::PyderiveStructOption::try_from(&input.attrs) {
+                Ok(opt) => opt,
+                Err(e) => return e.into_compile_error().into(),
+            };
 
-            let expanded = quote! {
-                #[pymethods]
-                #[automatically_derived]
-                impl #struct_name {
-                    pub fn $pyname(&self) -> <&Self as $trait>::Output {
-                        use ::std::ops::$trait;
-                        $trait::$method(self)
+            let expanded = if struct_opt.by_value() {
+                quote! {
+                    #[pymethods]
+                    #[automatically_derived]
+                    impl #struct_name {
+                        pub fn $pyname(&self) -> <Self as $trait>::Output {
+                            use ::std::ops::$trait;
+                            $trait::$method(::std::clone::Clone::clone(self))
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    #[pymethods]
+                    #[automatically_derived]
+                    impl #struct_name {
+                        pub fn $pyname(&self) -> <&Self as $trait>::Output {
+                            use ::std::ops::$trait;
+                            $trait::$method(self)
+                        }
                     }
                 }
             };
 
-            expanded.into()
+            require_multiple_pymethods(expanded.into())
         }
     };
 }
 
 macro_rules! impl_binary {
     ($derive:ident, $name:ident, $pyname:ident, $trait:ident::$method:ident) => {
-        #[proc_macro_derive($derive)]
+        #[proc_macro_derive($derive, attributes(pyderive))]
         pub fn $name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-            use quote::quote;
+            use quote::{format_ident, quote};
 
             let input = parse_macro_input!(input as DeriveInput);
 
+            match internal::rhs::implementation(
+                &input,
+                format_ident!("{}", stringify!($pyname)),
+                format_ident!("{}", stringify!($trait)),
+                format_ident!("{}", stringify!($method)),
+                false,
+            ) {
+                Ok(Some(r)) => return require_multiple_pymethods(r),
+                Ok(None) => {}
+                Err(e) => return e.into_compile_error().into(),
+            }
+
             let struct_name = &input.ident;
+            let struct_opt = match attr::PyderiveStructOption::try_from(&input.attrs) {
+                Ok(opt) => opt,
+                Err(e) => return e.into_compile_error().into(),
+            };
 
-            let expanded = quote! {
-                #[pymethods]
-                #[automatically_derived]
-                impl #struct_name {
-                    pub fn $pyname(&self, other: &Self) -> <&Self as $trait<&Self>>::Output {
-                        use ::std::ops::$trait;
-                        $trait::$method(self, other)
+            // by_value clones the operands for the impl of `Self`, not of `&Self`
+            let expanded = if struct_opt.by_value() {
+                quote! {
+                    #[pymethods]
+                    #[automatically_derived]
+                    impl #struct_name {
+                        pub fn $pyname(&self, other: &Self) -> <Self as $trait<Self>>::Output {
+                            use ::std::ops::$trait;
+                            $trait::$method(
+                                ::std::clone::Clone::clone(self),
+                                ::std::clone::Clone::clone(other),
+                            )
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    #[pymethods]
+                    #[automatically_derived]
+                    impl #struct_name {
+                        pub fn $pyname(&self, other: &Self) -> <&Self as $trait<&Self>>::Output {
+                            use ::std::ops::$trait;
+                            $trait::$method(self, other)
+                        }
                     }
                 }
             };
 
-            expanded.into()
+            require_multiple_pymethods(expanded.into())
         }
     };
 }
 
 macro_rules! impl_reflected_binary {
     ($derive:ident, $name:ident, $pyname:ident, $trait:ident::$method:ident) => {
-        #[proc_macro_derive($derive)]
+        #[proc_macro_derive($derive, attributes(pyderive))]
         pub fn $name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-            use quote::quote;
+            use quote::{format_ident, quote};
 
             let input = parse_macro_input!(input as DeriveInput);
 
+            match internal::rhs::implementation(
+                &input,
+                format_ident!("{}", stringify!($pyname)),
+                format_ident!("{}", stringify!($trait)),
+                format_ident!("{}", stringify!($method)),
+                true,
+            ) {
+                Ok(Some(r)) => return require_multiple_pymethods(r),
+                Ok(None) => {}
+                Err(e) => return e.into_compile_error().into(),
+            }
+
             let struct_name = &input.ident;
+            let struct_opt = match attr::PyderiveStructOption::try_from(&input.attrs) {
+                Ok(opt) => opt,
+                Err(e) => return e.into_compile_error().into(),
+            };
 
-            let expanded = quote! {
-                #[pymethods]
-                #[automatically_derived]
-                impl #struct_name {
-                    pub fn $pyname(&self, other: &Self) -> <&Self as $trait<&Self>>::Output {
-                        use ::std::ops::$trait;
-                        $trait::$method(other, self)
+            let expanded = if struct_opt.by_value() {
+                quote! {
+                    #[pymethods]
+                    #[automatically_derived]
+                    impl #struct_name {
+                        pub fn $pyname(&self, other: &Self) -> <Self as $trait<Self>>::Output {
+                            use ::std::ops::$trait;
+                            $trait::$method(
+                                ::std::clone::Clone::clone(other),
+                                ::std::clone::Clone::clone(self),
+                            )
+                        }
+                    }
+                }
+            } else {
+                quote! {
+                    #[pymethods]
+                    #[automatically_derived]
+                    impl #struct_name {
+                        pub fn $pyname(&self, other: &Self) -> <&Self as $trait<&Self>>::Output {
+                            use ::std::ops::$trait;
+                            $trait::$method(other, self)
+                        }
                     }
                 }
             };
 
-            expanded.into()
+            require_multiple_pymethods(expanded.into())
         }
     };
 }
 
 macro_rules! impl_binary_assign {
     ($derive:ident, $name:ident, $pyname:ident, $trait:ident::$method:ident) => {
-        #[proc_macro_derive($derive)]
+        #[proc_macro_derive($derive, attributes(pyderive))]
         pub fn $name(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             use quote::quote;
 
             let input = parse_macro_input!(input as DeriveInput);
 
             let struct_name = &input.ident;
+            let struct_opt = match attr::PyderiveStructOption::try_from(&input.attrs) {
+                Ok(opt) => opt,
+                Err(e) => return e.into_compile_error().into(),
+            };
+
+            let other = if struct_opt.by_value() {
+                quote! { ::std::clone::Clone::clone(other) }
+            } else {
+                quote! { other }
+            };
 
             let expanded = quote! {
                 #[pymethods]
@@ -244,12 +597,12 @@ macro_rules! impl_binary_assign {
                 impl #struct_name {
                     pub fn $pyname(&mut self, other: &Self) {
                         use ::std::ops::$trait;
-                        $trait::$method(self, other);
+                        $trait::$method(self, #other);
                     }
                 }
             };
 
-            expanded.into()
+            require_multiple_pymethods(expanded.into())
         }
     };
 }
@@ -345,7 +698,7 @@ pub fn py_pos(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
 }
 
 #[proc_macro_derive(PyDivMod)]
@@ -370,7 +723,7 @@ pub fn py_divmod(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
 }
 
 #[proc_macro_derive(PyReflectedDivMod)]
@@ -395,7 +748,7 @@ pub fn py_rdivmod(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
 }
 
 #[proc_macro_derive(PyNumeric)]
@@ -439,6 +792,11 @@ pub fn py_numeric(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 Div::div(self, other)
             }
 
+            pub fn __floordiv__(&self, other: &Self) -> <&Self as Div<&Self>>::Output {
+                use ::std::ops::Div;
+                Div::div(self, other)
+            }
+
             pub fn __mod__(&self, other: &Self) -> <&Self as Rem<&Self>>::Output {
                 use ::std::ops::Rem;
                 Rem::rem(self, other)
@@ -464,6 +822,11 @@ pub fn py_numeric(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 DivAssign::div_assign(self, other);
             }
 
+            pub fn __ifloordiv__(&mut self, other: &Self) {
+                use ::std::ops::DivAssign;
+                DivAssign::div_assign(self, other);
+            }
+
             pub fn __imod__(&mut self, other: &Self) {
                 use ::std::ops::RemAssign;
                 RemAssign::rem_assign(self, other);
@@ -479,7 +842,7 @@ pub fn py_numeric(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
 }
 
 #[proc_macro_derive(PyBitwise)]
@@ -551,7 +914,104 @@ pub fn py_bitwise(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
+}
+
+#[proc_macro_derive(PyReflectedNumeric)]
+pub fn py_reflected_numeric(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __radd__(&self, other: &Self) -> <&Self as Add<&Self>>::Output {
+                use ::std::ops::Add;
+                Add::add(other, self)
+            }
+
+            pub fn __rsub__(&self, other: &Self) -> <&Self as Sub<&Self>>::Output {
+                use ::std::ops::Sub;
+                Sub::sub(other, self)
+            }
+
+            pub fn __rmul__(&self, other: &Self) -> <&Self as Mul<&Self>>::Output {
+                use ::std::ops::Mul;
+                Mul::mul(other, self)
+            }
+
+            pub fn __rtruediv__(&self, other: &Self) -> <&Self as Div<&Self>>::Output {
+                use ::std::ops::Div;
+                Div::div(other, self)
+            }
+
+            pub fn __rfloordiv__(&self, other: &Self) -> <&Self as Div<&Self>>::Output {
+                use ::std::ops::Div;
+                Div::div(other, self)
+            }
+
+            pub fn __rmod__(&self, other: &Self) -> <&Self as Rem<&Self>>::Output {
+                use ::std::ops::Rem;
+                Rem::rem(other, self)
+            }
+
+            pub fn __rdivmod__(&self, other: &Self) -> (
+                <&Self as ::std::ops::Div<&Self>>::Output,
+                <&Self as ::std::ops::Rem<&Self>>::Output,
+            ) {
+                use ::std::ops::{Div, Rem};
+                (Div::div(other, self), Rem::rem(other, self))
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
+#[proc_macro_derive(PyReflectedBitwise)]
+pub fn py_reflected_bitwise(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __rand__(&self, other: &Self) -> <&Self as BitAnd<&Self>>::Output {
+                use ::std::ops::BitAnd;
+                BitAnd::bitand(other, self)
+            }
+
+            pub fn __ror__(&self, other: &Self) -> <&Self as BitOr<&Self>>::Output {
+                use ::std::ops::BitOr;
+                BitOr::bitor(other, self)
+            }
+
+            pub fn __rxor__(&self, other: &Self) -> <&Self as BitXor<&Self>>::Output {
+                use ::std::ops::BitXor;
+                BitXor::bitxor(other, self)
+            }
+
+            pub fn __rlshift__(&self, other: &Self) -> <&Self as Shl<&Self>>::Output {
+                use ::std::ops::Shl;
+                Shl::shl(other, self)
+            }
+
+            pub fn __rrshift__(&self, other: &Self) -> <&Self as Shr<&Self>>::Output {
+                use ::std::ops::Shr;
+                Shr::shr(other, self)
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
 }
 
 // convert
@@ -575,16 +1035,85 @@ macro_rules! impl_convert {
                     }
                 }
             };
-            expanded.into()
+            require_multiple_pymethods(expanded.into())
         }
     };
 }
 
 impl_convert!(PyBool, py_bool, __bool__, bool);
-impl_convert!(PyInt, py_int, __int__, i64);
 impl_convert!(PyIndex, py_index, __index__, isize);
 impl_convert!(PyFloat, py_float, __float__, f64);
 
+#[proc_macro_derive(PyInt, attributes(pyderive))]
+pub fn py_int(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+    let struct_opt = match attr::PyderiveStructOption::try_from(&input.attrs) {
+        Ok(opt) => opt,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let expanded = match struct_opt.int.map(|v| v.value()).as_deref() {
+        // int="trunc"/"round" converts through f64, the same as int() and round() of Python float,
+        // where round() rounds half to even, e.g. round(2.5) == 2.
+        // f64::round_ties_even() requires Rust 1.77, which is newer than the MSRV.
+        Some(mode) => {
+            let convert = if mode == "trunc" {
+                quote! { ::std::primitive::f64::trunc(value) }
+            } else {
+                quote! {
+                    if (value - ::std::primitive::f64::trunc(value)).abs() == 0.5 {
+                        2.0 * ::std::primitive::f64::round(value / 2.0)
+                    } else {
+                        ::std::primitive::f64::round(value)
+                    }
+                }
+            };
+            quote! {
+                #[pymethods]
+                #[automatically_derived]
+                impl #struct_name {
+                    pub fn __int__(&self) -> ::pyo3::PyResult<::std::primitive::i64> {
+                        let value: ::std::primitive::f64 = ::std::convert::Into::into(self);
+                        let value = #convert;
+                        if value.is_nan() {
+                            return ::pyo3::PyResult::Err(::pyo3::exceptions::PyValueError::new_err(
+                                "cannot convert float NaN to integer",
+                            ));
+                        }
+                        if value.is_infinite() {
+                            return ::pyo3::PyResult::Err(::pyo3::exceptions::PyOverflowError::new_err(
+                                "cannot convert float infinity to integer",
+                            ));
+                        }
+                        // -2^63 <= value < 2^63, both bounds are exact in f64
+                        if !(-9223372036854775808.0..9223372036854775808.0).contains(&value) {
+                            return ::pyo3::PyResult::Err(::pyo3::exceptions::PyOverflowError::new_err(
+                                "int too large to convert to i64",
+                            ));
+                        }
+                        ::pyo3::PyResult::Ok(value as ::std::primitive::i64)
+                    }
+                }
+            }
+        }
+        None => quote! {
+            #[pymethods]
+            #[automatically_derived]
+            impl #struct_name {
+                pub fn __int__(&self) -> ::std::primitive::i64 {
+                    ::std::convert::Into::into(self)
+                }
+            }
+        },
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
 #[proc_macro_derive(PyBytes)]
 pub fn py_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     use quote::quote;
@@ -603,7 +1132,140 @@ pub fn py_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
+}
+
+#[proc_macro_derive(PyBytesFrom)]
+pub fn py_bytes_from(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    // method call syntax resolves both an inherent and a trait method
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __bytes__(&self) -> ::std::borrow::Cow<[::std::primitive::u8]> {
+                ::std::convert::Into::into(self.as_bytes())
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
+#[proc_macro_derive(PyAlwaysTrue)]
+pub fn py_always_true(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __bool__(&self) -> ::std::primitive::bool {
+                true
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
+#[cfg(feature = "experimental-async")]
+#[proc_macro_derive(PyAIter)]
+pub fn py_aiter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+    let anext_name = quote::format_ident!("_____pyderive_InternalAnextFor{}", struct_name);
+
+    // PyO3 does not support async fn for __anext__() slot,
+    // hence __anext__() returns the coroutine of the async method of the internal class,
+    // which keeps the methods of the struct intact.
+    let expanded = quote! {
+        #[pyclass(frozen)]
+        #[pyo3(name="pyderive_anext")]
+        #[allow(non_camel_case_types)]
+        #[automatically_derived]
+        pub struct #anext_name {
+            slf: ::pyo3::Py<#struct_name>,
+        }
+
+        #[pymethods]
+        #[automatically_derived]
+        impl #anext_name {
+            pub async fn anext(&self) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                let slf = ::pyo3::Python::attach(|py| self.slf.clone_ref(py));
+                match #struct_name::next_item(slf).await {
+                    ::std::option::Option::Some(item) => ::pyo3::Python::attach(|py| {
+                        ::pyo3::IntoPyObjectExt::into_py_any(item, py)
+                    }),
+                    ::std::option::Option::None => ::pyo3::PyResult::Err(
+                        ::pyo3::exceptions::PyStopAsyncIteration::new_err(()),
+                    ),
+                }
+            }
+        }
+
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub fn __aiter__<'a>(self_: PyRef<'a, Self>) -> PyRef<'a, Self> {
+                self_
+            }
+
+            pub fn __anext__<'py>(
+                slf: &::pyo3::Bound<'py, Self>,
+            ) -> ::pyo3::PyResult<::pyo3::Bound<'py, ::pyo3::PyAny>> {
+                let py = slf.py();
+                let anext = ::pyo3::Bound::new(py, #anext_name { slf: slf.clone().unbind() })?;
+                anext.call_method0(::pyo3::intern!(py, "anext"))
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
+}
+
+#[cfg(feature = "experimental-async")]
+#[proc_macro_derive(PyAsyncContextManager)]
+pub fn py_async_context_manager(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    use quote::quote;
+
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let struct_name = &input.ident;
+
+    let expanded = quote! {
+        #[pymethods]
+        #[automatically_derived]
+        impl #struct_name {
+            pub async fn __aenter__(&mut self) -> ::pyo3::PyResult<::pyo3::Py<::pyo3::PyAny>> {
+                let value = self.aenter().await?;
+                ::pyo3::Python::attach(|py| ::pyo3::IntoPyObjectExt::into_py_any(value, py))
+            }
+
+            pub async fn __aexit__(
+                &mut self,
+                exc_type: ::std::option::Option<::pyo3::Py<::pyo3::PyAny>>,
+                exc_value: ::std::option::Option<::pyo3::Py<::pyo3::PyAny>>,
+                traceback: ::std::option::Option<::pyo3::Py<::pyo3::PyAny>>,
+            ) -> ::pyo3::PyResult<::std::primitive::bool> {
+                self.aexit(exc_type, exc_value, traceback).await
+            }
+        }
+    };
+
+    require_multiple_pymethods(expanded.into())
 }
 
 #[cfg(feature = "num-complex")]
@@ -625,5 +1287,5 @@ pub fn py_complex(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         }
     };
 
-    expanded.into()
+    require_multiple_pymethods(expanded.into())
 }
@@ -0,0 +1,27 @@
+use pyderive_macros::*;
+use pyo3::prelude::*;
+
+// a single #[pymethods] block still requires the feature,
+// the struct may have a hand-written #[pymethods] also
+#[derive(PyRepr)]
+#[pyclass(get_all)]
+pub struct Single {
+    field: i64,
+}
+
+// each derive macro implements its own #[pymethods] block
+#[derive(PyRepr, PyEq)]
+#[pyclass(get_all)]
+#[derive(PartialEq)]
+pub struct Combined {
+    field: i64,
+}
+
+// PyDataclass implements multiple #[pymethods] blocks
+#[derive(PyDataclass)]
+#[pyclass(get_all, frozen)]
+#[derive(PartialEq, Hash)]
+pub struct Multiple {
+    field: i64,
+}
+
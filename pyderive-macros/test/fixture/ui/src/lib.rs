@@ -0,0 +1,107 @@
+use pyderive_macros::*;
+use pyo3::prelude::*;
+
+#[derive(PyRepr)]
+#[pyclass]
+pub struct DuplicatedAttr {
+    #[pyderive(repr = true, repr = false)]
+    field: i64,
+}
+
+#[derive(PyRepr)]
+#[pyclass]
+pub struct DuplicatedAttrAcross {
+    #[pyderive(repr)]
+    #[pyderive(str, repr = false)]
+    field: i64,
+}
+
+#[derive(PyNew)]
+#[pyclass]
+pub struct NewFalseKwOnly {
+    a: i64,
+    #[pyderive(new = false, kw_only)]
+    b: i64,
+}
+
+#[derive(PyNew)]
+#[pyclass]
+pub struct NewFalseKwOnlyAcross {
+    #[pyderive(kw_only = true)]
+    #[pyderive(new = false)]
+    a: i64,
+}
+
+#[derive(PyDataclassFields)]
+#[pyclass]
+pub struct DefaultFactoryWithoutDefault {
+    #[pyderive(default_factory = true)]
+    field: i64,
+}
+
+#[derive(PyNew)]
+#[pyclass]
+pub struct PositionalOnlyAfterOther {
+    a: i64,
+    #[pyderive(positional_only)]
+    b: i64,
+}
+
+#[pyclass(get_all)]
+#[derive(PyRepr)]
+pub struct DeriveAfterPyclass {
+    field: i64,
+}
+
+#[derive(PyRepr)]
+#[pyclass]
+#[pyderive(frozen_get)]
+pub struct FrozenGetWithoutFrozen {
+    field: i64,
+}
+
+#[derive(PyFieldHash)]
+#[pyclass(frozen)]
+pub struct CacheHashPyField {
+    field: Py<PyAny>,
+    #[pyderive(cache_hash)]
+    cache: std::sync::OnceLock<u64>,
+}
+
+#[derive(PyRepr)]
+#[pyclass(frozen)]
+pub struct CacheReprPyField {
+    #[pyo3(get)]
+    field: Option<Py<PyAny>>,
+    #[pyderive(cache_repr)]
+    cache: std::sync::OnceLock<String>,
+}
+
+#[derive(PyDataclassFields)]
+#[pyclass(get_all)]
+pub struct DataclassFlattenWithoutFlatten {
+    #[pyderive(dataclass_flatten)]
+    field: i64,
+}
+
+#[derive(PyRepr)]
+#[pyclass]
+#[pyderive(repr_name = "Name", repr_prefix = "<")]
+pub struct ReprNameWithPrefix {
+    field: i64,
+}
+
+#[derive(PyStr)]
+#[pyclass]
+#[pyderive(repr_name = "Name", repr_prefix = "<")]
+pub struct StrNameWithPrefix {
+    field: i64,
+}
+
+#[derive(PyDataclass)]
+#[pyclass(get_all)]
+#[pyderive(order)]
+#[derive(PartialEq)]
+pub struct OrderWithoutPartialOrd {
+    field: i64,
+}
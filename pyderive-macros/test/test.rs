@@ -1,10 +1,29 @@
+mod test_async;
 mod test_bitwise;
+mod test_chrono;
+mod test_class_getitem;
+mod test_class_var;
+mod test_comparable;
+mod test_contains_key;
 mod test_convert;
+mod test_copy_replace;
+mod test_dataclass;
 mod test_dataclass_fields;
+mod test_default_new;
+mod test_doc;
 mod test_eq;
+mod test_field_hash;
+mod test_format;
+mod test_from_dict;
+mod test_from_str;
+mod test_getitem;
+mod test_int_enum;
 mod test_iter;
 mod test_len;
+mod test_mapping;
 mod test_match_args;
+mod test_multiple_pymethods;
+mod test_name;
 mod test_namedtuple_asdict;
 mod test_namedtuple_field_defaults;
 mod test_namedtuple_fields;
@@ -14,7 +33,10 @@ mod test_new;
 mod test_numeric;
 mod test_ops;
 mod test_ord;
+mod test_property;
 mod test_repr;
 mod test_reversed;
 mod test_richcmp;
+mod test_set_protocol;
 mod test_str;
+mod test_ui;
@@ -0,0 +1,122 @@
+#![cfg(feature = "experimental-async")]
+
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn aiter() {
+    #[derive(PyAIter)]
+    #[pyclass]
+    struct PyClass {
+        items: Vec<i64>,
+    }
+
+    impl PyClass {
+        async fn next_item(slf: Py<Self>) -> Option<i64> {
+            Python::attach(|py| {
+                let mut this = slf.borrow_mut(py);
+                if this.items.is_empty() {
+                    None
+                } else {
+                    Some(this.items.remove(0))
+                }
+            })
+        }
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                items: vec![1, 2, 3],
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+import asyncio
+
+async def main(data):
+    assert data.__aiter__() is data
+    assert [name for name in dir(data) if "pyderive" in name] == []
+    values = []
+    async for v in data:
+        values.append(v)
+    return values
+
+assert asyncio.run(main(data)) == [1, 2, 3]
+"#
+        );
+    });
+}
+
+#[test]
+fn async_context_manager() {
+    #[derive(PyAsyncContextManager)]
+    #[pyclass(get_all)]
+    #[derive(Default)]
+    struct PyClass {
+        entered: bool,
+        exited: bool,
+        suppress: bool,
+    }
+
+    impl PyClass {
+        async fn aenter(&mut self) -> PyResult<&'static str> {
+            self.entered = true;
+            Ok("resource")
+        }
+
+        async fn aexit(
+            &mut self,
+            exc_type: Option<Py<PyAny>>,
+            _exc_value: Option<Py<PyAny>>,
+            _traceback: Option<Py<PyAny>>,
+        ) -> PyResult<bool> {
+            self.exited = true;
+            Ok(exc_type.is_some() && self.suppress)
+        }
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        let suppress = Py::new(
+            py,
+            PyClass {
+                suppress: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data suppress,
+            r#"
+import asyncio
+
+async def main(data, suppress):
+    async with data as r:
+        assert r == "resource"
+        assert data.entered
+        assert not data.exited
+    assert data.exited
+
+    try:
+        async with data:
+            raise ValueError
+    except ValueError:
+        pass
+    else:
+        raise AssertionError
+
+    async with suppress:
+        raise ValueError
+    assert suppress.exited
+
+asyncio.run(main(data, suppress))
+"#
+        );
+    });
+}
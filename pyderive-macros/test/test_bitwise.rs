@@ -116,3 +116,55 @@ assert obj.field == 0xfff
         );
     });
 }
+
+#[test]
+fn test_reflected() {
+    #[derive(PyReflectedBitwise)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        field: i64,
+    }
+
+    impl_new!(PyClass);
+    impl_binary!(PyClass, BitAnd::bitand);
+    impl_binary!(PyClass, BitOr::bitor);
+    impl_binary!(PyClass, BitXor::bitxor);
+    impl_binary!(PyClass, Shl::shl);
+    impl_binary!(PyClass, Shr::shr);
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+obj = py_class(0x7fff)
+other = py_class(0x3)
+
+# the class has the reflected methods only,
+# thus the operator dispatches to the reflected method of the right operand
+assert (obj & other).field == 0x3
+assert (obj | other).field == 0x7fff
+assert (obj ^ other).field == 0x7ffc
+assert (obj << other).field == 0x3fff8
+assert (obj >> other).field == 0xfff
+
+assert other.__rand__(obj).field == 0x3
+assert other.__ror__(obj).field == 0x7fff
+assert other.__rxor__(obj).field == 0x7ffc
+assert other.__rlshift__(obj).field == 0x3fff8
+assert other.__rrshift__(obj).field == 0xfff
+
+# the left operand must be an instance of the class
+import operator
+for op in [operator.and_, operator.or_, operator.xor, operator.lshift, operator.rshift]:
+    try:
+        op(2, obj)
+    except TypeError:
+        pass
+    else:
+        raise AssertionError(op)
+"#
+        );
+    });
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_repr_str() {
+    #[derive(PyRepr, PyStr, PyIter)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        datetime: DateTime<Utc>,
+        date: NaiveDate,
+        time: NaiveTime,
+    }
+
+    Python::attach(|py| {
+        let datetime = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        let data = Py::new(
+            py,
+            PyClass {
+                datetime,
+                date: NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+                time: NaiveTime::from_hms_opt(3, 4, 5).unwrap(),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+import datetime
+
+expected = "PyClass(datetime='2024-01-02T03:04:05+00:00', date='2024-01-02', time='03:04:05')"
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+
+assert data.datetime == datetime.datetime(2024, 1, 2, 3, 4, 5, tzinfo=datetime.timezone.utc)
+assert list(data)[1] == datetime.date(2024, 1, 2)
+"#
+        );
+    });
+}
@@ -0,0 +1,33 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_class_getitem() {
+    #[derive(PyClassGetItem)]
+    #[pyclass]
+    struct PyClass {}
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import types
+
+alias = py_class[int]
+assert isinstance(alias, types.GenericAlias)
+assert alias.__origin__ is py_class
+assert alias.__args__ == (int, )
+
+alias = py_class[int, str]
+assert alias.__args__ == (int, str)
+
+def f(x: py_class[int]) -> py_class[str]:
+    pass
+
+assert f.__annotations__['x'] == py_class[int]
+"#
+        );
+    });
+}
@@ -0,0 +1,44 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_class_var() {
+    #[derive(PyClassVar, PyNew, PyRepr, PyDataclassFields)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        field: i64,
+        #[pyderive(classvar = 42)]
+        #[allow(dead_code)]
+        answer: i64,
+        #[pyderive(classvar = "unit".to_string())]
+        #[allow(dead_code)]
+        unit: String,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+assert py_class.answer == 42
+assert py_class.unit == "unit"
+
+a = py_class(1)
+assert a.answer == 42
+assert a.unit == "unit"
+assert repr(a) == "PyClass(field=1)"
+
+from dataclasses import _FIELD, _FIELD_CLASSVAR, fields
+
+assert list(a.__dataclass_fields__) == ["field", "answer", "unit"]
+assert a.__dataclass_fields__["field"]._field_type is _FIELD
+assert a.__dataclass_fields__["answer"]._field_type is _FIELD_CLASSVAR
+assert a.__dataclass_fields__["answer"].default == 42
+assert a.__dataclass_fields__["unit"]._field_type is _FIELD_CLASSVAR
+assert [f.name for f in fields(a)] == ["field"]
+"#
+        );
+    });
+}
@@ -0,0 +1,85 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_eq_hash_agree() {
+    #[derive(PyComparable)]
+    #[pyclass(frozen)]
+    #[allow(dead_code)]
+    struct PyClass {
+        field: i64,
+        string: String,
+        #[pyderive(compare = false)]
+        excluded: f64,
+    }
+
+    Python::attach(|py| {
+        let a = Py::new(
+            py,
+            PyClass {
+                field: 1,
+                string: "a".to_string(),
+                excluded: 0.0,
+            },
+        )
+        .unwrap();
+        let b = Py::new(
+            py,
+            PyClass {
+                field: 1,
+                string: "a".to_string(),
+                excluded: f64::NAN,
+            },
+        )
+        .unwrap();
+        let c = Py::new(
+            py,
+            PyClass {
+                field: 1,
+                string: "b".to_string(),
+                excluded: 0.0,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            a b c,
+            r#"
+assert a == b
+assert not a != b
+assert hash(a) == hash(b)
+assert a != c
+assert not a == c
+assert a != 1
+assert len({a, b, c}) == 2
+"#
+        )
+    });
+}
+
+#[test]
+fn test_py_field() {
+    #[derive(PyComparable)]
+    #[pyclass(frozen)]
+    struct PyClass {
+        field: Py<PyAny>,
+    }
+
+    Python::attach(|py| {
+        let a = Py::new(
+            py,
+            PyClass {
+                field: 1i64.into_pyobject(py).unwrap().into_any().unbind(),
+            },
+        )
+        .unwrap();
+        let b = Py::new(
+            py,
+            PyClass {
+                field: 1.0f64.into_pyobject(py).unwrap().into_any().unbind(),
+            },
+        )
+        .unwrap();
+        py_run!(py, a b, "assert a == b and hash(a) == hash(b)")
+    });
+}
@@ -0,0 +1,59 @@
+use pyo3::prelude::*;
+
+use pyderive_macros::*;
+
+#[test]
+fn test() {
+    #[derive(PyMapping, PyContainsKey)]
+    #[pyclass]
+    #[allow(dead_code)]
+    struct PyClass {
+        #[pyo3(get)]
+        field_a: i64,
+        #[pyo3(get, name = "renamed")]
+        field_b: String,
+        field_c: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                field_a: 1,
+                field_b: "field_a".to_string(),
+                field_c: 2,
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert "field_a" in data
+assert "renamed" in data
+assert "nope" not in data
+assert "field_b" not in data
+assert "field_c" not in data
+
+# key membership, not value membership
+assert 1 not in data
+assert None not in data
+
+for k in data.keys():
+    assert k in data
+"#
+        );
+    });
+}
+
+#[test]
+fn test_empty() {
+    #[derive(PyContainsKey)]
+    #[pyclass]
+    struct PyClass {}
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass {}).unwrap();
+        pyo3::py_run!(py, data, r#"assert "field" not in data"#);
+    });
+}
@@ -49,6 +49,27 @@ assert actual is True
     });
 }
 
+#[test]
+fn always_true() {
+    #[derive(PyAlwaysTrue, PyLen)]
+    #[pyclass]
+    struct PyClass {}
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass {}).unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert len(data) == 0
+actual = bool(data)
+assert isinstance(actual, bool)
+assert actual is True
+"#
+        );
+    });
+}
+
 #[test]
 fn bytes() {
     #[derive(PyBytes)]
@@ -87,6 +108,71 @@ assert actual == b'\x01\x02\x03'
         );
     });
 }
+#[test]
+fn bytes_from() {
+    #[derive(PyBytesFrom)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        text: String,
+    }
+
+    #[pymethods]
+    impl PyClass {
+        #[new]
+        fn new(text: String) -> Self {
+            Self { text }
+        }
+    }
+
+    impl PyClass {
+        fn as_bytes(&self) -> Cow<'_, [u8]> {
+            Cow::from(self.text.to_uppercase().into_bytes())
+        }
+    }
+
+    trait AsBytes {
+        fn as_bytes(&self) -> &[u8];
+    }
+
+    #[derive(PyBytesFrom)]
+    #[pyclass(get_all)]
+    struct PyClassTrait {
+        data: Vec<u8>,
+    }
+
+    #[pymethods]
+    impl PyClassTrait {
+        #[new]
+        fn new(data: Vec<u8>) -> Self {
+            Self { data }
+        }
+    }
+
+    impl AsBytes for PyClassTrait {
+        fn as_bytes(&self) -> &[u8] {
+            &self.data
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_trait = py.get_type::<PyClassTrait>();
+        pyo3::py_run!(
+            py,
+            py_class py_class_trait,
+            r#"
+actual = bytes(py_class("abc"))
+assert isinstance(actual, bytes)
+assert actual == b"ABC"
+
+actual = bytes(py_class_trait([1, 2, 3]))
+assert isinstance(actual, bytes)
+assert actual == b"\x01\x02\x03"
+"#
+        );
+    });
+}
+
 #[test]
 fn int() {
     #[derive(PyInt)]
@@ -118,6 +204,139 @@ assert actual == 1
     });
 }
 
+#[test]
+fn int_trunc_round() {
+    // fixed-point number in hundredths
+    #[derive(PyInt)]
+    #[pyderive(int = "trunc")]
+    #[pyclass(get_all)]
+    struct PyClassTrunc {
+        field: i64,
+    }
+
+    #[derive(PyInt)]
+    #[pyderive(int = "round")]
+    #[pyclass(get_all)]
+    struct PyClassRound {
+        field: i64,
+    }
+
+    impl_new!(PyClassTrunc);
+    impl_new!(PyClassRound);
+
+    impl From<&PyClassTrunc> for f64 {
+        fn from(value: &PyClassTrunc) -> Self {
+            value.field as f64 / 100.0
+        }
+    }
+
+    impl From<&PyClassRound> for f64 {
+        fn from(value: &PyClassRound) -> Self {
+            value.field as f64 / 100.0
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class_trunc = py.get_type::<PyClassTrunc>();
+        let py_class_round = py.get_type::<PyClassRound>();
+        pyo3::py_run!(
+            py,
+            py_class_trunc py_class_round,
+            r#"
+actual = int(py_class_trunc(275))
+assert isinstance(actual, int)
+assert actual == 2
+assert int(py_class_trunc(-275)) == -2
+assert int(py_class_trunc(200)) == 2
+
+actual = int(py_class_round(275))
+assert isinstance(actual, int)
+assert actual == 3
+assert int(py_class_round(-275)) == -3
+# half to even, like round() of Python
+assert int(py_class_round(225)) == round(2.25) == 2
+assert int(py_class_round(250)) == round(2.5) == 2
+assert int(py_class_round(-250)) == round(-2.5) == -2
+assert int(py_class_round(350)) == round(3.5) == 4
+assert int(py_class_round(-350)) == round(-3.5) == -4
+assert int(py_class_round(50)) == round(0.5) == 0
+"#
+        );
+    });
+}
+
+#[test]
+fn int_trunc_round_error() {
+    #[derive(PyInt)]
+    #[pyderive(int = "trunc")]
+    #[pyclass(get_all)]
+    struct PyClassTrunc {
+        field: f64,
+    }
+
+    #[derive(PyInt)]
+    #[pyderive(int = "round")]
+    #[pyclass(get_all)]
+    struct PyClassRound {
+        field: f64,
+    }
+
+    #[pymethods]
+    impl PyClassTrunc {
+        #[new]
+        fn new(field: f64) -> Self {
+            Self { field }
+        }
+    }
+
+    #[pymethods]
+    impl PyClassRound {
+        #[new]
+        fn new(field: f64) -> Self {
+            Self { field }
+        }
+    }
+
+    impl From<&PyClassTrunc> for f64 {
+        fn from(value: &PyClassTrunc) -> Self {
+            value.field
+        }
+    }
+
+    impl From<&PyClassRound> for f64 {
+        fn from(value: &PyClassRound) -> Self {
+            value.field
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class_trunc = py.get_type::<PyClassTrunc>();
+        let py_class_round = py.get_type::<PyClassRound>();
+        pyo3::py_run!(
+            py,
+            py_class_trunc py_class_round,
+            r#"
+for cls in [py_class_trunc, py_class_round]:
+    for value, exc in [
+        (float("nan"), ValueError),
+        (float("inf"), OverflowError),
+        (float("-inf"), OverflowError),
+        (2.0 ** 63, OverflowError),
+        (-(2.0 ** 64), OverflowError),
+    ]:
+        try:
+            int(cls(value))
+        except exc:
+            pass
+        else:
+            raise AssertionError(value)
+
+    assert int(cls(-(2.0 ** 63))) == -(2 ** 63)
+"#
+        );
+    });
+}
+
 #[test]
 fn index() {
     #[derive(PyIndex)]
@@ -0,0 +1,213 @@
+use std::marker::PhantomData;
+
+use pyo3::prelude::*;
+
+use pyderive_macros::*;
+
+#[test]
+fn test() {
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        b: String,
+        e: Py<PyAny>,
+        #[pyderive(default = None)]
+        c: Option<f64>,
+        #[pyderive(new = false)]
+        d: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+import sys
+
+obj = py_class(1, "s", object(), 1.0)
+actual = obj.__replace__(a=2)
+assert actual is not obj
+assert (actual.a, actual.b, actual.c, actual.d) == (2, "s", 1.0, 0)
+assert actual.e is obj.e
+
+actual = obj.__replace__()
+assert (actual.a, actual.b, actual.c, actual.d) == (1, "s", 1.0, 0)
+
+try:
+    obj.__replace__(d=1)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+
+try:
+    obj.__replace__(a="a")
+except TypeError:
+    pass
+else:
+    raise AssertionError
+
+if sys.version_info >= (3, 13):
+    import copy
+
+    actual = copy.replace(obj, b="t", c=None)
+    assert (actual.a, actual.b, actual.c) == (1, "t", None)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_subclass() {
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass(get_all, subclass)]
+    struct PyClass {
+        a: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+class Sub(py_class):
+    pass
+
+actual = Sub(1).__replace__(a=2)
+assert type(actual) is Sub
+assert actual.a == 2
+"#
+        );
+    });
+}
+
+#[test]
+fn test_init_var() {
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        a: i64,
+        #[pyderive(init_var)]
+        scale: PhantomData<i64>,
+    }
+
+    #[pymethods]
+    impl PyClass {
+        fn __post_init__(&mut self, scale: i64) -> PyResult<()> {
+            self.a *= scale;
+            Ok(())
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+obj = py_class(1, 2)
+assert obj.a == 2
+
+# init_var fields must be given like dataclasses
+actual = obj.__replace__(scale=3)
+assert actual.a == 6
+
+try:
+    obj.__replace__()
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_positional_only() {
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(positional_only)]
+        a: i64,
+        b: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+obj = py_class(1, 2)
+
+actual = obj.__replace__(b=3)
+assert (actual.a, actual.b) == (1, 3)
+
+actual = obj.__replace__(a=4)
+assert (actual.a, actual.b) == (4, 2)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_flatten() {
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass(get_all)]
+    #[derive(Clone)]
+    struct Child {
+        x: i64,
+        y: i64,
+    }
+
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        name: String,
+        #[pyderive(flatten)]
+        child: Child,
+    }
+
+    #[derive(PyNew, PyCopyReplace)]
+    #[pyclass(get_all)]
+    struct PyClassPy {
+        #[pyderive(flatten)]
+        child: Py<Child>,
+        z: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_py = py.get_type::<PyClassPy>();
+        pyo3::py_run!(
+            py,
+            py_class py_class_py,
+            r#"
+obj = py_class("a", x=1, y=2)
+
+actual = obj.__replace__(x=3)
+assert (actual.name, actual.child.x, actual.child.y) == ("a", 3, 2)
+
+actual = obj.__replace__(name="b")
+assert (actual.name, actual.child.x, actual.child.y) == ("b", 1, 2)
+
+obj = py_class_py(0, x=1, y=2)
+
+actual = obj.__replace__(y=3, z=4)
+assert (actual.child.x, actual.child.y, actual.z) == (1, 3, 4)
+
+try:
+    obj.__replace__(child=obj.child)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
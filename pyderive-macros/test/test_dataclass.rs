@@ -0,0 +1,372 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_dataclass() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: i64,
+        #[pyderive(repr = false)]
+        fd_name_b: String,
+        #[pyderive(default = 1.0)]
+        fd_name_c: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(0, 'b')
+assert repr(data) == "PyClass(fd_name_a=0, fd_name_c=1.0)"
+assert data == py_class(0, 'b', 1.0)
+assert data != py_class(1, 'b', 1.0)
+assert py_class.__match_args__ == ('fd_name_a', 'fd_name_b', 'fd_name_c')
+assert not hasattr(data, '__lt__') or data.__lt__(data) is NotImplemented
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_order() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all)]
+    #[pyderive(order)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: String,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(0, 'b')
+b = py_class(1, 'a')
+assert a < b
+assert a <= b
+assert b > a
+assert b >= a
+assert not b < a
+assert not b <= a
+assert not a > b
+assert not a >= b
+assert not a < a
+assert a <= a
+assert sorted([b, a]) == [a, b]
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_subclass() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, subclass)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        field: i64,
+    }
+
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, subclass)]
+    #[pyderive(order)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClassOrder {
+        field: i64,
+    }
+
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, subclass)]
+    #[pyderive(order, richcmp_tuple)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClassOrderTuple {
+        field: i64,
+    }
+
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, subclass)]
+    #[pyderive(order, eq_subclass)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClassOrderSubclass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_order = py.get_type::<PyClassOrder>();
+        let py_class_order_tuple = py.get_type::<PyClassOrderTuple>();
+        let py_class_order_subclass = py.get_type::<PyClassOrderSubclass>();
+        py_run!(
+            py,
+            py_class py_class_order py_class_order_tuple py_class_order_subclass,
+            r#"
+for cls in [py_class, py_class_order, py_class_order_tuple]:
+    class Sub(cls):
+        pass
+
+    assert cls(1) == cls(1)
+    assert Sub(1) == Sub(1)
+    assert cls(1) != Sub(1)
+    assert Sub(1) != cls(1)
+    assert cls(1).__eq__(Sub(1)) is NotImplemented
+    assert cls(1).__ne__(Sub(1)) is NotImplemented
+
+class Sub(py_class_order):
+    pass
+
+assert py_class_order(0) < Sub(1)
+assert py_class_order_tuple(1) == (1,)
+
+class Sub(py_class_order_subclass):
+    pass
+
+assert py_class_order_subclass(1) == Sub(1)
+assert not py_class_order_subclass(1) != Sub(1)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_order_key() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all)]
+    #[pyderive(order, order_key = key)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: String,
+    }
+
+    fn key(this: &PyClass) -> &str {
+        &this.fd_name_b
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(0, 'b')
+b = py_class(1, 'a')
+assert b < a
+assert a > b
+assert not a < b
+assert not b > a
+assert sorted([a, b]) == [b, a]
+
+# == compares the fields rather than the key, like dataclass
+c = py_class(2, 'b')
+assert not a < c and not c < a
+assert a != c
+assert a == py_class(0, 'b')
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_weakref() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, weakref)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import weakref
+
+data = py_class(1)
+ref = weakref.ref(data)
+assert ref() is data
+assert repr(ref()) == "PyClass(fd_name_a=1)"
+
+del data
+assert ref() is None
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_hash_frozen() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, frozen)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: String,
+        #[pyderive(compare = false)]
+        fd_name_c: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(0, 'a', 0.0)
+assert hash(a) == hash(py_class(0, 'a', 1.0))
+assert hash(a) != hash(py_class(1, 'a', 0.0))
+assert len({a, py_class(0, 'a', 0.0)}) == 1
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_hash_mutable() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+assert py_class.__hash__ is None
+
+try:
+    hash(py_class(0))
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_hash_float() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all, frozen)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: f64,
+        fd_name_b: Option<String>,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(1.5, None)
+assert hash(a) == hash(py_class(1.5, None))
+assert hash(a) != hash(py_class(1.5, 'a'))
+assert len({py_class(0.0, 'a'), py_class(-0.0, 'a')}) == 1
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_hash_true() {
+    #[derive(PyDataclass)]
+    #[pyclass(get_all)]
+    #[pyderive(hash = true)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+assert py_class.__hash__ is not None
+assert hash(py_class(0)) == hash(py_class(0))
+assert len({py_class(0), py_class(0), py_class(1)}) == 2
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_hash_false() {
+    #[derive(PyDataclass, PyFieldHash)]
+    #[pyclass(get_all, frozen)]
+    #[pyderive(hash = false)]
+    #[derive(PartialEq, Hash)]
+    struct Frozen {
+        fd_name_a: i64,
+    }
+
+    #[derive(PyDataclass)]
+    #[pyclass(get_all)]
+    #[pyderive(hash = false)]
+    #[derive(PartialEq)]
+    struct Mutable {
+        fd_name_a: i64,
+    }
+
+    #[pymethods]
+    impl Mutable {
+        fn __hash__(&self) -> u64 {
+            42
+        }
+    }
+
+    Python::attach(|py| {
+        let frozen = py.get_type::<Frozen>();
+        let mutable = py.get_type::<Mutable>();
+        py_run!(
+            py,
+            frozen mutable,
+            r#"
+assert hash(frozen(0)) == hash(frozen(0))
+assert hash(mutable(0)) == 42
+"#
+        );
+    });
+}
+
+#[test]
+fn test_dataclass_frozen_repr() {
+    #[derive(PyDataclass)]
+    #[pyclass(frozen)]
+    #[pyderive(frozen_get)]
+    #[derive(PartialEq, Hash)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: String,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(0, 'b')
+assert repr(data) == "PyClass(fd_name_a=0, fd_name_b='b')"
+assert hash(data) == hash(py_class(0, 'b'))
+"#
+        );
+    });
+}
@@ -169,6 +169,7 @@ fn test_variation() {
                 fd_name_vec_opt_pystr,
                 fd_name_vec_opt_pyint,
             ))]
+        #[allow(clippy::too_many_arguments)]
         fn new(
             fd_name_bool: bool,
             fd_name_str: String,
@@ -289,11 +290,14 @@ fn test_options() {
         #[pyderive(kw_only = true)]
         kw_only: i64,
         follow_kw_only: i64,
+        #[pyderive(classvar = 2)]
+        classvar: i64,
     }
 
     #[pymethods]
     impl PyClass {
         #[new]
+        #[allow(clippy::too_many_arguments)]
         fn new(
             field: i64,
             class_: i64,
@@ -319,6 +323,7 @@ fn test_options() {
                 no_repr,
                 kw_only,
                 follow_kw_only,
+                classvar: 2,
             }
         }
     }
@@ -343,7 +348,8 @@ for field in fields(a):
             assert field.kw_only is False, field.name
     elif field.name == "class_":
         assert field.type is None
-        assert field._field_type is _FIELD_CLASSVAR, field.name
+        assert field._field_type is _FIELD, field.name
+        assert field.init is False, field.name
         if sys.version_info >= (3, 10):
             assert field.kw_only is False, field.name
     elif field.name == "new_default":
@@ -357,7 +363,8 @@ for field in fields(a):
         assert field.type is None
         assert field.default == 1, field.name
         assert field.default_factory is MISSING, field.name
-        assert field._field_type is _FIELD_CLASSVAR, field.name
+        assert field._field_type is _FIELD, field.name
+        assert field.init is False, field.name
         if sys.version_info >= (3, 10):
             assert field.kw_only is False, field.name
     elif field.name == "new_default_factory":
@@ -371,7 +378,8 @@ for field in fields(a):
         assert field.type is None
         assert field.default is MISSING, field.name
         assert field.default_factory() == 1, field.name
-        assert field._field_type is _FIELD_CLASSVAR, field.name
+        assert field._field_type is _FIELD, field.name
+        assert field.init is False, field.name
         if sys.version_info >= (3, 10):
             assert field.kw_only is False, field.name
     elif field.name == "repr":
@@ -390,6 +398,51 @@ for field in fields(a):
             assert field.kw_only is True, field.name
     else:
         raise AssertionError(field.name)
+
+# ClassVar is not listed by fields()
+field = a.__dataclass_fields__["classvar"]
+assert field._field_type is _FIELD_CLASSVAR
+assert field.default == 2
+assert field.init is False
+assert "omit" not in a.__dataclass_fields__
+"#
+        );
+    });
+}
+
+#[test]
+fn test_init_var() {
+    use std::marker::PhantomData;
+
+    #[derive(PyNew, PyDataclassFields)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        fd_a: i64,
+        #[pyderive(init_var)]
+        fd_b: PhantomData<i64>,
+    }
+
+    impl PyClass {
+        fn __post_init__(&mut self, fd_b: i64) -> PyResult<()> {
+            self.fd_a += fd_b;
+            Ok(())
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+from dataclasses import _FIELD, _FIELD_INITVAR, fields, asdict
+
+data = py_class(1, 2)
+assert data.__dataclass_fields__['fd_a']._field_type is _FIELD
+assert data.__dataclass_fields__['fd_b']._field_type is _FIELD_INITVAR
+assert [f.name for f in fields(data)] == ['fd_a']
+assert asdict(data) == {'fd_a': 3}
 "#
         );
     });
@@ -526,6 +579,7 @@ for field in fields(py_class(10)):
 }
 
 #[test]
+#[allow(clippy::redundant_closure_call)]
 fn test_default_factory() {
     #[derive(PyDataclassFields)]
     #[pyclass(get_all)]
@@ -564,3 +618,101 @@ for field in fields(py_class([10, ])):
         );
     });
 }
+
+#[test]
+fn test_doc_metadata() {
+    #[derive(PyDataclassFields)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(doc = "the width in px")]
+        width: i64,
+        height: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                width: 1,
+                height: 2,
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+from dataclasses import fields
+
+width, height = fields(data)
+assert width.metadata["doc"] == "the width in px"
+assert dict(height.metadata) == {}
+"#
+        );
+    });
+}
+
+#[test]
+fn test_flatten() {
+    #[derive(PyNew, PyDataclassFields)]
+    #[pyclass(get_all)]
+    #[derive(Clone)]
+    struct Child {
+        a: i64,
+        b: String,
+    }
+
+    #[derive(PyNew, PyDataclassFields)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        name: String,
+        #[pyderive(flatten, dataclass_flatten)]
+        child: Child,
+    }
+
+    #[derive(PyNew, PyDataclassFields)]
+    #[pyclass(get_all)]
+    struct PyClassPy {
+        #[pyderive(flatten, dataclass_flatten)]
+        child: Py<Child>,
+    }
+
+    #[derive(PyNew, PyDataclassFields)]
+    #[pyclass(get_all)]
+    struct PyClassNested {
+        #[pyderive(flatten)]
+        child: Py<Child>,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_py = py.get_type::<PyClassPy>();
+        let py_class_nested = py.get_type::<PyClassNested>();
+        pyo3::py_run!(
+            py,
+            py_class py_class_py py_class_nested,
+            r#"
+from dataclasses import asdict, fields
+
+a = py_class("x", a=1, b="y")
+assert [f.name for f in fields(a)] == ["name", "a", "b"]
+assert asdict(a) == {"name": "x", "a": 1, "b": "y"}
+assert a.a == 1
+
+try:
+    a.unknown
+except AttributeError:
+    pass
+else:
+    raise AssertionError
+
+b = py_class_py(a=2, b="z")
+assert asdict(b) == {"a": 2, "b": "z"}
+
+c = py_class_nested(a=3, b="w")
+assert asdict(c) == {"child": {"a": 3, "b": "w"}}
+assert not hasattr(c, "a")
+"#
+        )
+    });
+}
@@ -0,0 +1,47 @@
+use pyo3::prelude::*;
+
+use pyderive_macros::*;
+
+#[test]
+fn test() {
+    #[derive(PyDefaultNew, PyRepr, PyEq)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        a: i64,
+        b: String,
+        c: Vec<i64>,
+    }
+
+    impl Default for PyClass {
+        fn default() -> Self {
+            Self {
+                a: 1,
+                b: "s".to_string(),
+                c: vec![1, 2],
+            }
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+actual = py_class()
+assert (actual.a, actual.b, actual.c) == (1, "s", [1, 2])
+assert repr(actual) == "PyClass(a=1, b='s', c=[1, 2])"
+assert actual == py_class()
+assert actual is not py_class()
+
+try:
+    py_class(1)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
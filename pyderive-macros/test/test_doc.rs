@@ -0,0 +1,57 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_doc() {
+    /// A rectangle.
+    ///
+    /// The origin is the top-left corner.
+    #[derive(PyDoc)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(doc = "the width in px")]
+        width: i64,
+        #[pyo3(name = "h")]
+        #[pyderive(doc = "the height in px")]
+        height: i64,
+        other: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+expected = """A rectangle.
+
+The origin is the top-left corner.
+
+Attributes:
+    width: the width in px
+    h: the height in px"""
+assert py_class.__doc__ == expected, py_class.__doc__
+"#
+        );
+    });
+}
+
+#[test]
+fn test_no_class_doc() {
+    #[derive(PyDoc)]
+    #[pyclass]
+    struct PyClass {
+        #[pyderive(doc = "a field")]
+        #[allow(dead_code)]
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"assert py_class.__doc__ == "Attributes:\n    field: a field""#
+        );
+    });
+}
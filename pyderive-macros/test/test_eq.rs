@@ -86,3 +86,190 @@ assert a != py_class_a(py_class_b(2))
         );
     });
 }
+
+#[test]
+fn test_nan_inf() {
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import operator
+
+values = [float("nan"), float("inf"), float("-inf"), 0.0]
+for op in [operator.eq, operator.ne]:
+    for x in values:
+        for y in values:
+            assert op(py_class(x), py_class(y)) is op(x, y), (op, x, y)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_subclass() {
+    #[derive(PyNew, PyEq)]
+    #[pyclass(subclass)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+class Sub(py_class):
+    pass
+
+assert py_class(1) == py_class(1)
+assert Sub(1) == Sub(1)
+assert py_class(1) != Sub(1)
+assert Sub(1) != py_class(1)
+assert py_class(1).__eq__(Sub(1)) is NotImplemented
+"#
+        );
+    });
+}
+
+#[test]
+fn test_eq_subclass() {
+    #[derive(PyNew, PyEq)]
+    #[pyclass(subclass)]
+    #[pyderive(eq_subclass)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+class Sub(py_class):
+    pass
+
+assert py_class(1) == Sub(1)
+assert Sub(1) == py_class(1)
+assert py_class(1) != Sub(2)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_eq_fields() {
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq)]
+    struct Child {
+        value: i64,
+    }
+
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all)]
+    #[pyderive(eq = "fields")]
+    struct Parent {
+        name: String,
+        child: Py<Child>,
+        optional: Option<Py<Child>>,
+        #[pyderive(compare = false)]
+        ignored: i64,
+    }
+
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all, subclass)]
+    #[pyderive(eq = "fields", eq_subclass)]
+    struct Base {
+        child: Py<Child>,
+    }
+
+    Python::attach(|py| {
+        let parent = py.get_type::<Parent>();
+        let child = py.get_type::<Child>();
+        let base = py.get_type::<Base>();
+        py_run!(
+            py,
+            parent child base,
+            r#"
+a = parent("a", child(1), child(2), 0)
+b = parent("a", child(1), child(2), 1)
+assert a.child is not b.child
+assert a == b
+assert not (a != b)
+
+assert a != parent("b", child(1), child(2), 0)
+assert a != parent("a", child(3), child(2), 0)
+assert a != parent("a", child(1), child(3), 0)
+assert a != parent("a", child(1), None, 0)
+assert parent("a", child(1), None, 0) == parent("a", child(1), None, 0)
+assert a.__eq__(1) is NotImplemented
+
+class Sub(base):
+    pass
+
+assert base(child(1)) == Sub(child(1))
+assert base(child(1)) != Sub(child(2))
+"#
+        );
+    });
+}
+
+#[test]
+fn test_eq_identity() {
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all)]
+    #[pyderive(eq_identity)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        f: f64,
+    }
+
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all)]
+    #[pyderive(eq_identity, eq_subclass)]
+    #[derive(PartialEq)]
+    struct PyClassSubclass {
+        f: f64,
+    }
+
+    #[derive(PyNew, PyEq)]
+    #[pyclass(get_all)]
+    #[pyderive(eq_identity, eq = "fields")]
+    struct PyClassFields {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_subclass = py.get_type::<PyClassSubclass>();
+        let py_class_fields = py.get_type::<PyClassFields>();
+        py_run!(
+            py,
+            py_class py_class_subclass py_class_fields,
+            r#"
+for cls in [py_class, py_class_subclass, py_class_fields]:
+    a = cls(float("nan"))
+    # the same object is equal to itself even with a NaN field
+    assert a == a
+    assert not a != a
+    # the distinct objects are compared by the fields
+    assert a != cls(float("nan"))
+    assert cls(1.0) == cls(1.0)
+    assert cls(1.0) != cls(2.0)
+"#
+        );
+    });
+}
@@ -0,0 +1,150 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run, types::*};
+
+#[test]
+fn test_field_hash() {
+    #[derive(PyFieldHash)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fa: i64,
+        fb: String,
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "a".to_string(),
+            },
+        )
+        .unwrap();
+        let data2 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "a".to_string(),
+            },
+        )
+        .unwrap();
+        let data3 = Py::new(
+            py,
+            PyClass {
+                fa: 2,
+                fb: "a".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data1 data2 data3, "assert hash(data1) == hash(data2)");
+        py_run!(py, data1 data2 data3, "assert hash(data1) != hash(data3)");
+    });
+}
+
+#[test]
+fn test_compare_false() {
+    #[derive(PyFieldHash)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fa: i64,
+        #[pyderive(compare = false)]
+        fb: String,
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "a".to_string(),
+            },
+        )
+        .unwrap();
+        let data2 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "b".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data1 data2, "assert hash(data1) == hash(data2)");
+    });
+}
+
+#[test]
+fn test_py_field() {
+    #[derive(PyFieldHash)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        field: Py<PyString>,
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(
+            py,
+            PyClass {
+                field: PyString::new(py, "a").unbind(),
+            },
+        )
+        .unwrap();
+        let data2 = Py::new(
+            py,
+            PyClass {
+                field: PyString::new(py, "a").unbind(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data1 data2, "assert hash(data1) == hash(data2)");
+    });
+}
+
+#[test]
+fn test_cache_hash() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::OnceLock;
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted(i64);
+
+    impl std::hash::Hash for Counted {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            COUNT.fetch_add(1, Ordering::SeqCst);
+            self.0.hash(state);
+        }
+    }
+
+    #[derive(PyNew, PyFieldHash, PyRepr)]
+    #[pyclass(frozen)]
+    struct PyClass {
+        #[pyderive(repr = false, new = false, default = Counted(1))]
+        field: Counted,
+        #[pyo3(get)]
+        other: i64,
+        #[pyderive(cache_hash)]
+        cache: OnceLock<u64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                field: Counted(1),
+                other: 2,
+                cache: OnceLock::new(),
+            },
+        )
+        .unwrap();
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            data py_class,
+            r#"
+assert hash(data) == hash(data) == hash(data)
+assert repr(data) == "PyClass(other=2)"
+assert py_class(other=2) is not None
+"#
+        );
+        assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+    });
+}
@@ -0,0 +1,58 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_format_field() {
+    #[derive(PyFormat)]
+    #[pyclass]
+    struct Percentage {
+        #[allow(dead_code)]
+        label: String,
+        #[pyderive(format_field)]
+        value: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            Percentage {
+                label: "rate".to_string(),
+                value: 0.1234,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert f"{data:.1%}" == "12.3%"
+assert f"{data:>8.2f}" == "    0.12"
+assert format(data) == str(0.1234)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_format_py_field() {
+    #[derive(PyFormat)]
+    #[pyclass]
+    struct Wrapper {
+        #[pyderive(format_field)]
+        value: Py<PyAny>,
+    }
+
+    Python::attach(|py| {
+        let value = py
+            .eval(c"__import__('decimal').Decimal('1.25')", None, None)
+            .unwrap();
+        let data = Py::new(
+            py,
+            Wrapper {
+                value: value.unbind(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data, r#"assert f"{data:.1f}" == "1.2""#);
+    });
+}
@@ -0,0 +1,86 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_round_trip() {
+    #[derive(PyNew, PyFromDict, PyNamedTupleAsdict, PyEq)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        a: i64,
+        #[pyo3(name = "renamed")]
+        b: String,
+        #[pyderive(default = 1.0)]
+        c: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+obj = py_class(1, "b", 2.0)
+assert obj._asdict() == {"a": 1, "renamed": "b", "c": 2.0}
+assert py_class.from_dict(obj._asdict()) == obj
+
+d = {"a": 1, "renamed": "b"}
+assert py_class.from_dict(d) == py_class(1, "b", 1.0)
+assert d == {"a": 1, "renamed": "b"}
+
+for d in [{"a": 1}, {"a": 1, "renamed": "b", "extra": 0}]:
+    try:
+        py_class.from_dict(d)
+    except TypeError:
+        pass
+    else:
+        raise AssertionError(d)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_positional_only() {
+    #[derive(PyNew, PyFromDict, PyEq)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq)]
+    struct PyClass {
+        #[pyderive(positional_only)]
+        a: i64,
+        #[pyderive(positional_only)]
+        b: i64,
+        #[pyderive(kw_only)]
+        c: i64,
+    }
+
+    #[derive(PyNew, PyFromDict, PyEq)]
+    #[pyclass(get_all)]
+    #[pyderive(signature = (b, /, a, *, c=3))]
+    #[derive(PartialEq)]
+    struct PyClassSignature {
+        a: i64,
+        b: i64,
+        c: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_signature = py.get_type::<PyClassSignature>();
+        py_run!(
+            py,
+            py_class py_class_signature,
+            r#"
+assert py_class.from_dict({"c": 3, "b": 2, "a": 1}) == py_class(1, 2, c=3)
+assert py_class_signature.from_dict({"a": 1, "b": 2}) == py_class_signature(2, 1)
+
+try:
+    py_class.from_dict({"b": 2, "c": 3})
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
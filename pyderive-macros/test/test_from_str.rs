@@ -0,0 +1,64 @@
+use std::fmt;
+use std::str::FromStr;
+
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run};
+
+#[test]
+fn test_round_trip() {
+    #[derive(PyFromStr, PyEq)]
+    #[pyclass]
+    #[derive(PartialEq)]
+    struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    impl FromStr for Point {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (x, y) = s
+                .split_once(',')
+                .ok_or_else(|| format!("missing ',' in {:?}", s))?;
+            Ok(Self {
+                x: x.trim().parse().map_err(|e| format!("{}", e))?,
+                y: y.trim().parse().map_err(|e| format!("{}", e))?,
+            })
+        }
+    }
+
+    impl fmt::Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}, {}", self.x, self.y)
+        }
+    }
+
+    #[pymethods]
+    impl Point {
+        fn __str__(&self) -> String {
+            self.to_string()
+        }
+    }
+
+    Python::attach(|py| {
+        let obj = Py::new(py, Point { x: 1, y: -2 }).unwrap();
+        let py_class = py.get_type::<Point>();
+        py_run!(
+            py,
+            obj py_class,
+            r#"
+assert str(obj) == "1, -2"
+assert py_class.from_str(str(obj)) == obj
+assert isinstance(py_class.from_str("3,4"), py_class)
+
+try:
+    py_class.from_str("1")
+except ValueError as e:
+    assert str(e) == "missing ',' in \"1\"", str(e)
+else:
+    raise AssertionError
+"#
+        )
+    });
+}
@@ -0,0 +1,120 @@
+use pyo3::prelude::*;
+
+use pyderive_macros::*;
+
+#[test]
+fn test_index() {
+    #[derive(PyGetItem)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        b: String,
+        c: Option<f64>,
+        #[pyderive(iter = false)]
+        d: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                a: 1,
+                b: "s".to_string(),
+                c: None,
+                d: 0,
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert data[0] == 1
+assert data[1] == "s"
+assert data[2] is None
+assert data[-1] is None
+assert data[-3] == 1
+
+class Index:
+    def __index__(self):
+        return 1
+
+assert data[Index()] == "s"
+
+for i in [3, -4]:
+    try:
+        data[i]
+    except IndexError:
+        pass
+    else:
+        raise AssertionError
+
+try:
+    data["a"]
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_slice() {
+    #[derive(PyGetItem)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        b: String,
+        c: Option<f64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                a: 1,
+                b: "s".to_string(),
+                c: Some(1.0),
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert data[0:2] == [1, "s"]
+assert data[:] == [1, "s", 1.0]
+assert data[1:] == ["s", 1.0]
+assert data[::-1] == [1.0, "s", 1]
+assert data[::2] == [1, 1.0]
+assert data[-2:] == ["s", 1.0]
+assert data[5:10] == []
+"#
+        );
+    });
+}
+
+#[test]
+fn test_iter_order() {
+    #[derive(PyGetItem, PyIter)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        #[pyderive(iter_order = 0)]
+        b: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass { a: 1, b: 2 }).unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert data[0] == 2
+assert data[:] == list(data)
+"#
+        );
+    });
+}
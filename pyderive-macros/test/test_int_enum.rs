@@ -0,0 +1,73 @@
+use pyo3::prelude::*;
+
+use pyderive_macros::*;
+
+#[test]
+fn test() {
+    #[derive(PyIntEnum)]
+    #[pyclass]
+    enum Color {
+        Red = 0,
+        Green = 1,
+        Blue = 4,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<Color>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+Color = py_class
+
+assert Color.Red == 0
+assert Color.Green == 1
+assert Color.Blue == 4
+assert 0 == Color.Red
+assert Color.Red != 1
+assert not (Color.Red != 0)
+assert Color.Red == 0.0
+assert Color.Red != "Red"
+
+assert Color.Red == Color.Red
+assert Color.Red != Color.Green
+
+assert int(Color.Blue) == 4
+assert [10, 11, 12, 13, 14][Color.Blue] == 14
+assert Color.Green.name == "Green"
+assert Color.Green.value == 1
+assert repr(Color.Green) == "<Color.Green: 1>"
+
+assert hash(Color.Blue) == hash(4)
+assert {0: "a"}[Color.Red] == "a"
+assert len({Color.Red, 0}) == 1
+"#
+        );
+    });
+}
+
+#[test]
+fn test_implicit_discriminant() {
+    #[derive(PyIntEnum)]
+    #[pyclass]
+    enum Level {
+        Low = 1,
+        Middle,
+        High = 10,
+        Highest,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<Level>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+assert py_class.Low == 1
+assert py_class.Middle == 2
+assert py_class.High == 10
+assert py_class.Highest == 11
+"#
+        );
+    });
+}
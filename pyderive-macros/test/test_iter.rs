@@ -174,3 +174,358 @@ assert tuple(a) == (py_class_b(1), 1)
         );
     });
 }
+
+#[test]
+fn test_iter_keys() {
+    #[derive(PyIter, PyReversed, PyMapping)]
+    #[pyclass(get_all, rename_all = "UPPERCASE")]
+    #[pyderive(iter = "keys")]
+    #[derive(Default)]
+    struct PyClass {
+        field_a: i64,
+        field_b: String,
+        #[pyderive(iter = false)]
+        field_c: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert list(data) == ["FIELD_A", "FIELD_B"]
+assert list(reversed(data)) == ["FIELD_B", "FIELD_A"]
+values = []
+for k in data:
+    values.append(data[k])
+assert values == [0, ""]
+"#
+        )
+    });
+}
+
+#[test]
+fn test_iter_values() {
+    #[derive(PyIter, PyReversed)]
+    #[pyclass(get_all)]
+    #[pyderive(iter = "values")]
+    #[derive(Default)]
+    struct PyClass {
+        field_a: i64,
+        field_b: String,
+        #[pyderive(iter = false)]
+        field_c: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert list(data) == [0, ""]
+assert list(reversed(data)) == ["", 0]
+"#
+        )
+    });
+}
+
+#[test]
+fn test_iter_items() {
+    #[derive(PyIter, PyReversed)]
+    #[pyclass(get_all, rename_all = "UPPERCASE")]
+    #[pyderive(iter = "items", iter_snapshot)]
+    #[derive(Default)]
+    struct PyClass {
+        field_a: i64,
+        field_b: String,
+        #[pyderive(iter = false)]
+        field_c: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert list(data) == [("FIELD_A", 0), ("FIELD_B", "")]
+assert list(reversed(data)) == [("FIELD_B", ""), ("FIELD_A", 0)]
+assert dict(data) == {"FIELD_A": 0, "FIELD_B": ""}
+"#
+        )
+    });
+}
+
+#[test]
+fn test_iter_order() {
+    #[derive(PyIter, PyReversed)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fd_name_a: i64,
+        #[pyderive(iter_order = 1)]
+        fd_name_b: i64,
+        fd_name_c: i64,
+        #[pyderive(iter_order = 0)]
+        fd_name_d: i64,
+        fd_name_e: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1,
+                fd_name_b: 2,
+                fd_name_c: 3,
+                fd_name_d: 4,
+                fd_name_e: 5,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert tuple(data) == (4, 2, 1, 3, 5)
+assert tuple(reversed(data)) == (5, 3, 1, 2, 4)
+"#
+        )
+    });
+}
+
+#[test]
+fn test_iter_snapshot() {
+    #[derive(PyIter)]
+    #[pyclass(get_all)]
+    #[pyderive(iter_snapshot)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: String,
+        fd_name_c: Vec<i64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1,
+                fd_name_b: "b".to_string(),
+                fd_name_c: vec![3],
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert tuple(data) == (1, 'b', [3])
+
+it = iter(data)
+assert iter(it) is it
+values = []
+for v in it:
+    values.append(v)
+assert len(values) == 3
+try:
+    next(it)
+except StopIteration:
+    pass
+else:
+    raise AssertionError
+"#
+        )
+    });
+}
+
+#[test]
+fn test_frozen() {
+    #[derive(PyIter, PyLen)]
+    #[pyclass(frozen)]
+    #[pyderive(frozen_get)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    struct PyClass {
+        fd_name_a: i64,
+        #[pyderive(iter = false, len = false)]
+        fd_name_b: f64,
+        fd_name_c: String,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            "
+assert tuple(data) == (0, '')
+assert len(data) == 2
+"
+        )
+    });
+}
+
+#[test]
+fn test_children() {
+    #[derive(PyIter)]
+    #[pyclass(get_all)]
+    struct Node {
+        value: i64,
+        #[pyderive(children)]
+        children: Vec<Py<Node>>,
+    }
+
+    #[pymethods]
+    impl Node {
+        #[new]
+        #[pyo3(signature = (value, children=vec![]))]
+        fn new(value: i64, children: Vec<Py<Node>>) -> Self {
+            Self { value, children }
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<Node>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+leaf_a = py_class(2)
+leaf_b = py_class(3)
+inner = py_class(1, [leaf_a, leaf_b])
+root = py_class(0, [inner, py_class(4)])
+
+assert [c.value for c in root] == [1, 4]
+assert [c.value for c in inner] == [2, 3]
+assert list(leaf_a) == []
+assert list(inner)[0] is leaf_a
+
+# depth-first walk
+values = []
+stack = [root]
+while stack:
+    node = stack.pop()
+    values.append(node.value)
+    for child in reversed(list(node)):
+        stack.append(child)
+assert values == [0, 1, 2, 3, 4]
+"#
+        );
+    });
+}
+
+#[test]
+fn test_smart_pointer() {
+    use std::sync::Arc;
+
+    #[derive(PyIter)]
+    #[pyclass]
+    struct PyClass {
+        #[pyderive(iter)]
+        arc: Arc<String>,
+        #[pyderive(iter)]
+        boxed: Box<i64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                arc: Arc::new("s".to_string()),
+                boxed: Box::new(1),
+            },
+        )
+        .unwrap();
+        py_run!(py, data, r#"assert list(data) == ["s", 1]"#);
+    });
+}
+
+#[test]
+fn test_private_field() {
+    #[derive(PyIter)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        public: i64,
+        #[pyderive(iter = true)]
+        private: String,
+        #[allow(dead_code)]
+        hidden: i64,
+        #[pyderive(iter = true)]
+        boxed: Box<i64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                public: 1,
+                private: "secret".to_string(),
+                hidden: 2,
+                boxed: Box::new(3),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert not hasattr(data, "private")
+assert tuple(data) == (1, "secret", 3)
+"#
+        )
+    });
+}
+
+#[test]
+fn test_independent_iterators() {
+    #[derive(PyIter)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        b: i64,
+        c: i64,
+    }
+
+    #[derive(PyIter)]
+    #[pyclass(get_all)]
+    #[pyderive(iter_snapshot)]
+    struct PyClassSnapshot {
+        a: i64,
+        b: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass { a: 1, b: 2, c: 3 }).unwrap();
+        let snapshot = Py::new(py, PyClassSnapshot { a: 1, b: 2 }).unwrap();
+        py_run!(
+            py,
+            data snapshot,
+            r#"
+pairs = []
+for a in data:
+    for b in data:
+        pairs.append((a, b))
+assert pairs == [
+    (1, 1), (1, 2), (1, 3),
+    (2, 1), (2, 2), (2, 3),
+    (3, 1), (3, 2), (3, 3),
+]
+
+pairs = []
+for a in snapshot:
+    for b in snapshot:
+        pairs.append((a, b))
+assert pairs == [(1, 1), (1, 2), (2, 1), (2, 2)]
+
+it1, it2 = iter(data), iter(data)
+assert it1 is not it2
+assert next(it1) == 1
+assert next(it1) == 2
+assert list(it2) == [1, 2, 3]
+assert list(it1) == [3]
+"#
+        )
+    });
+}
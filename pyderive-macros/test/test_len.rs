@@ -165,3 +165,42 @@ assert len(a) == 1
         );
     });
 }
+
+#[test]
+fn test_len_field() {
+    #[derive(PyLen)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(len_field)]
+        inner: Vec<i64>,
+        other: String,
+    }
+
+    #[derive(PyLen)]
+    #[pyclass]
+    struct PyClassPy {
+        #[pyderive(len_field)]
+        inner: Py<pyo3::types::PyList>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                inner: vec![1, 2, 3],
+                other: "s".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data, "assert len(data) == len(data.inner) == 3");
+
+        let data = Py::new(
+            py,
+            PyClassPy {
+                inner: pyo3::types::PyList::new(py, [1, 2]).unwrap().unbind(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data, "assert len(data) == 2");
+    });
+}
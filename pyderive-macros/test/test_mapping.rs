@@ -0,0 +1,221 @@
+use pyderive_macros::*;
+use pyo3::{prelude::*, py_run, types::*};
+
+#[test]
+fn test_get_set() {
+    #[derive(PyMapping, PyLen)]
+    #[pyclass]
+    #[allow(dead_code)]
+    struct PyClass {
+        #[pyo3(get, set)]
+        fd_name_a: i64,
+        #[pyo3(get)]
+        fd_name_b: String,
+        #[pyo3(get, set)]
+        fd_name_c: Py<PyString>,
+        fd_name_d: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1,
+                fd_name_b: "b".to_string(),
+                fd_name_c: PyString::new(py, "c").unbind(),
+                fd_name_d: 0.0,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert len(data) == 3
+assert data.keys() == ['fd_name_a', 'fd_name_b', 'fd_name_c']
+assert data['fd_name_a'] == 1
+assert data['fd_name_b'] == 'b'
+assert data['fd_name_c'] == 'c'
+
+data['fd_name_a'] = 10
+data['fd_name_c'] = 'cc'
+assert data['fd_name_a'] == 10
+assert data.fd_name_a == 10
+assert data['fd_name_c'] == 'cc'
+
+assert dict(data) == {'fd_name_a': 10, 'fd_name_b': 'b', 'fd_name_c': 'cc'}
+
+try:
+    data['fd_name_a'] = 'str'
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_key_error() {
+    #[derive(PyMapping)]
+    #[pyclass(get_all)]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+for key in ['unknown', 'fd_name_d']:
+    try:
+        data[key]
+    except KeyError as e:
+        assert e.args == (key, )
+    else:
+        raise AssertionError
+
+# no set fields, thus no __setitem__()
+try:
+    data['fd_name_a'] = 1
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_rename_all() {
+    #[derive(PyMapping)]
+    #[pyclass(get_all, set_all, rename_all = "camelCase")]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+        #[pyo3(name = "renamed")]
+        fd_name_b: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert data.keys() == ['fdNameA', 'renamed']
+data['fdNameA'] = 1
+assert data['fdNameA'] == 1
+assert data.fdNameA == 1
+"#
+        );
+    });
+}
+
+#[test]
+fn test_read_only() {
+    #[derive(PyMapping, PyRepr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyo3(set)]
+        writable: i64,
+        read_only: i64,
+    }
+
+    #[derive(PyMapping, PyRepr)]
+    #[pyclass(get_all, set_all)]
+    struct PyClassSetAll {
+        writable: i64,
+        #[pyderive(set = false)]
+        read_only: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                writable: 1,
+                read_only: 2,
+            },
+        )
+        .unwrap();
+        let data_set_all = Py::new(
+            py,
+            PyClassSetAll {
+                writable: 1,
+                read_only: 2,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data data_set_all,
+            r#"
+for obj in [data, data_set_all]:
+    obj['writable'] = 3
+    assert obj.writable == 3
+    try:
+        obj['read_only'] = 4
+    except KeyError:
+        pass
+    else:
+        raise AssertionError
+    assert obj['read_only'] == 2
+    assert repr(obj) == f"{type(obj).__name__}(writable=3, read_only=2)"
+
+try:
+    data.read_only = 4
+except AttributeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_frozen() {
+    #[derive(PyMapping, PyLen)]
+    #[pyclass(get_all, frozen)]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    #[derive(PyMapping)]
+    #[pyclass(get_all)]
+    #[derive(Default)]
+    struct PyClassGetOnly {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        let data_get_only = Py::new(py, PyClassGetOnly::default()).unwrap();
+        py_run!(
+            py,
+            data data_get_only,
+            r#"
+assert len(data) == 1
+assert data['fd_name_a'] == 0
+
+# no __setitem__() without set fields
+for obj in [data, data_get_only]:
+    assert not hasattr(obj, '__setitem__')
+    try:
+        obj['fd_name_a'] = 1
+    except TypeError:
+        pass
+    else:
+        raise AssertionError
+"#
+        );
+    });
+}
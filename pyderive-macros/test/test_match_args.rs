@@ -186,6 +186,60 @@ match py_class(0):
     });
 }
 
+#[test]
+fn test_pyderive_true_option_getter() {
+    #[derive(PyMatchArgs)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        fd_a: i64,
+        #[allow(dead_code)]
+        fd_b: i64,
+        #[pyderive(match_args = true)]
+        #[allow(dead_code)]
+        fd_c: i64,
+    }
+
+    #[pymethods]
+    impl PyClass {
+        #[new]
+        pub fn new(fd_a: i64, fd_b: i64, fd_c: i64) -> Self {
+            Self { fd_a, fd_b, fd_c }
+        }
+        #[getter]
+        pub fn fd_c(&self) -> Option<i64> {
+            self.fd_c.is_positive().then_some(self.fd_c)
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+
+        pyo3::py_run!(
+            py,
+            py_class,
+            "assert py_class.__match_args__ == ('fd_a', 'fd_c')"
+        );
+
+        if py.version_info() >= (3, 10) {
+            pyo3::py_run!(
+                py,
+                py_class,
+                "
+match py_class(0, 0, -1):
+    case py_class(a, None): assert a == 0
+    case _: raise AssertionError
+
+match py_class(0, 0, 1):
+    case py_class(_, None): raise AssertionError
+    case py_class(a, c): assert (a, c) == (0, 1)
+    case _: raise AssertionError
+"
+            );
+        }
+    });
+}
+
 #[test]
 fn test_pyderive_false_empty() {
     #[derive(PyMatchArgs)]
@@ -291,3 +345,74 @@ assert a.__match_args__ == ('field', )
         );
     });
 }
+
+#[test]
+fn test_kw_only() {
+    #[derive(PyNew, PyMatchArgs)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        #[pyderive(kw_only)]
+        b: i64,
+        c: i64,
+        #[pyderive(match_args = true)]
+        d: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        assert_eq!("PyClass", py_class.name().unwrap().to_string());
+
+        if py.version_info() >= (3, 10) {
+            pyo3::py_run!(
+                py,
+                py_class,
+                "
+assert py_class.__match_args__ == ('a', 'd')
+
+match py_class(1, b=2, c=3, d=4):
+    case py_class(x, y, b=2, c=z):
+        assert (x, y, z) == (1, 4, 3)
+    case _: raise AssertionError
+"
+            );
+        }
+    })
+}
+
+#[test]
+fn test_aligned_with_new() {
+    #[derive(PyNew, PyMatchArgs)]
+    #[pyclass(get_all)]
+    #[pyderive(signature=(c, a, b=0))]
+    struct PyClass {
+        a: i64,
+        #[pyderive(new = false, default = 10)]
+        x: i64,
+        b: i64,
+        c: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+
+        pyo3::py_run!(
+            py,
+            py_class,
+            "assert py_class.__match_args__ == ('c', 'a', 'b')"
+        );
+
+        if py.version_info() >= (3, 10) {
+            pyo3::py_run!(
+                py,
+                py_class,
+                "
+match py_class(1, 2, 3):
+    case py_class(c, a, b, x=x):
+        assert (c, a, b, x) == (1, 2, 3, 10)
+    case _: raise AssertionError
+"
+            );
+        }
+    })
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_missing_feature() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let output = Command::new(cargo)
+        .arg("check")
+        .arg("--quiet")
+        .arg("--message-format=short")
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("test/fixture/no_multiple_pymethods/Cargo.toml"))
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("fixture"),
+        )
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+
+    // every derive macro reports it at the derive, including PyRepr and PyEq of the same #[derive(..)],
+    // next to the conflicting implementations that PyO3 reports
+    let expected = [
+        "src/lib.rs:6:10:",
+        "src/lib.rs:13:10:",
+        "src/lib.rs:13:18:",
+        "src/lib.rs:21:10:",
+    ];
+    let actual = stderr
+        .lines()
+        .filter(|line| line.contains("requires `multiple-pymethods` feature"))
+        .collect::<Vec<_>>();
+    assert_eq!(actual.len(), expected.len(), "{stderr}");
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!(actual.starts_with(expected), "{stderr}");
+    }
+}
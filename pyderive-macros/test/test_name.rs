@@ -0,0 +1,48 @@
+use pyderive_macros::*;
+use pyo3::prelude::*;
+
+#[test]
+fn test_name() {
+    #[derive(PyNew, PyName)]
+    #[pyclass]
+    #[allow(dead_code)]
+    struct PyClass {
+        fd_a: i64,
+        #[pyderive(is_name)]
+        fd_b: String,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(1, "abc")
+assert data.name == "abc"
+assert py_class.__name__ == "PyClass"
+"#
+        );
+    });
+}
+
+#[test]
+fn test_name_py() {
+    #[derive(PyName)]
+    #[pyclass]
+    struct PyClass {
+        #[pyderive(is_name = true)]
+        fd: Py<PyAny>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd: (1, "a").into_pyobject(py).unwrap().into_any().unbind(),
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(py, data, r#"assert data.name == (1, "a")"#);
+    });
+}
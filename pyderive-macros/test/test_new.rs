@@ -133,6 +133,60 @@ fn test_name_rename_all() {
     });
 }
 
+#[test]
+fn test_rename_all_kwargs() {
+    #[derive(PyNew)]
+    #[pyclass(get_all, rename_all = "camelCase")]
+    struct PyClass {
+        #[pyderive(positional_only)]
+        first_arg: i64,
+        second_arg: i64,
+        #[pyderive(default = 3)]
+        third_arg: i64,
+        #[pyderive(default = vec![1, 2].into_pyobject(py)?.extract()?)]
+        fourth_arg: Vec<i64>,
+        #[pyderive(kw_only)]
+        fifth_arg: i64,
+        #[pyo3(name = "type")]
+        #[pyderive(default = 6)]
+        kind: i64,
+    }
+
+    #[derive(PyNew)]
+    #[pyclass(get_all, rename_all = "camelCase")]
+    #[pyderive(signature = (secondArg, firstArg = 2))]
+    struct Signature {
+        first_arg: i64,
+        second_arg: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let signature = py.get_type::<Signature>();
+        pyo3::py_run!(
+            py,
+            py_class signature,
+            r#"
+a = py_class(1, secondArg=2, thirdArg=4, fourthArg=[5], fifthArg=6, type=7)
+assert (a.firstArg, a.secondArg, a.thirdArg, a.fourthArg, a.fifthArg, a.type) == (1, 2, 4, [5], 6, 7)
+
+a = py_class(1, 2, fifthArg=5)
+assert (a.thirdArg, a.fourthArg, a.type) == (3, [1, 2], 6)
+
+for kwargs in [{"second_arg": 2, "fifthArg": 5}, {"secondArg": 2, "fifth_arg": 5}, {"firstArg": 1, "secondArg": 2, "fifthArg": 5}]:
+    try:
+        py_class(*([] if "firstArg" in kwargs else [1]), **kwargs)
+        raise AssertionError(kwargs)
+    except TypeError:
+        pass
+
+a = signature(secondArg=1)
+assert (a.firstArg, a.secondArg) == (2, 1)
+"#
+        );
+    });
+}
+
 #[test]
 fn test_pyderive_a() {
     #[derive(PyNew)]
@@ -415,6 +469,213 @@ fn test_pyderive_kw_only_default() {
     });
 }
 
+#[test]
+fn test_pyderive_kw_only_first() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(kw_only)]
+        fd_a: i64,
+        fd_b: i64,
+        #[pyderive(default = 2)]
+        fd_c: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(fd_b=1, fd_a=0)
+assert (a.fd_a, a.fd_b, a.fd_c) == (0, 1, 2)
+
+for args, kwargs in [((0, 1), {}), ((1,), {"fd_a": 0}), ((), {"fd_a": 0})]:
+    try:
+        py_class(*args, **kwargs)
+    except TypeError:
+        pass
+    else:
+        raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_new_default() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fd_a: i64,
+        #[pyderive(new_default)]
+        fd_b: Vec<String>,
+        #[pyderive(new_default = true)]
+        fd_c: Option<f64>,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(1)
+assert data.fd_a == 1
+assert data.fd_b == []
+assert data.fd_c is None
+
+data = py_class(1, ['a'], fd_c=1.0)
+assert data.fd_b == ['a']
+assert data.fd_c == 1.0
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_default_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    fn expensive() -> Vec<String> {
+        COUNT.fetch_add(1, Ordering::SeqCst);
+        vec!["a".to_string()]
+    }
+
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(default_once = expensive())]
+        fd_a: Vec<String>,
+        #[pyderive(new = false, default_once = expensive())]
+        fd_b: Vec<String>,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+for _ in range(3):
+    data = py_class()
+    assert data.fd_a == ['a']
+    assert data.fd_b == ['a']
+
+data = py_class(['b'])
+assert data.fd_a == ['b']
+"#
+        );
+    });
+
+    // once for each of fd_a and fd_b
+    assert_eq!(COUNT.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_pyderive_try_from() {
+    use std::num::NonZeroU32;
+
+    #[derive(PyNew)]
+    #[pyclass]
+    struct PyClass {
+        #[pyderive(try_from = u32)]
+        fd_a: NonZeroU32,
+        #[pyderive(try_from = i64, default = 1)]
+        fd_b: u8,
+    }
+
+    #[pymethods]
+    impl PyClass {
+        #[getter]
+        fn fd_a(&self) -> u32 {
+            self.fd_a.get()
+        }
+        #[getter]
+        fn fd_b(&self) -> u8 {
+            self.fd_b
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(1)
+assert data.fd_a == 1
+assert data.fd_b == 1
+
+data = py_class(2, fd_b=255)
+assert data.fd_a == 2
+assert data.fd_b == 255
+
+for args in [(0, ), (1, 256), (1, -1)]:
+    try:
+        py_class(*args)
+    except ValueError:
+        pass
+    else:
+        raise AssertionError(args)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_init_var() {
+    use std::marker::PhantomData;
+
+    #[derive(PyNew)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        fd_a: i64,
+        #[pyderive(init_var, default = 2)]
+        scale: PhantomData<i64>,
+        #[pyo3(get)]
+        #[pyderive(new = false)]
+        fd_b: i64,
+    }
+
+    impl PyClass {
+        fn __post_init__(&mut self, scale: i64) -> PyResult<()> {
+            if scale < 0 {
+                return Err(pyo3::exceptions::PyValueError::new_err("negative scale"));
+            }
+            self.fd_b = self.fd_a * scale;
+            Ok(())
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(3)
+assert data.fd_a == 3
+assert data.fd_b == 6
+assert not hasattr(data, "scale")
+
+data = py_class(3, scale=10)
+assert data.fd_b == 30
+
+try:
+    py_class(3, -1)
+except ValueError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
 #[test]
 fn test_pyderive_kw_only_no_trailing_new_field() {
     #[derive(PyNew)]
@@ -423,7 +684,6 @@ fn test_pyderive_kw_only_no_trailing_new_field() {
     struct PyClass {
         fd_a: i64,
         #[pyderive(kw_only)]
-        #[pyderive(new = false)]
         fd_b: i64,
         #[pyderive(new = false)]
         fd_c: i64,
@@ -431,9 +691,9 @@ fn test_pyderive_kw_only_no_trailing_new_field() {
 
     Python::attach(|py| {
         let py_class = py.get_type::<PyClass>();
-        pyo3::py_run!(py, py_class, "assert py_class(1).fd_a == 1");
-        pyo3::py_run!(py, py_class, "assert py_class(0).fd_b == 0");
-        pyo3::py_run!(py, py_class, "assert py_class(0).fd_c == 0");
+        pyo3::py_run!(py, py_class, "assert py_class(1, fd_b=0).fd_a == 1");
+        pyo3::py_run!(py, py_class, "assert py_class(0, fd_b=1).fd_b == 1");
+        pyo3::py_run!(py, py_class, "assert py_class(0, fd_b=1).fd_c == 0");
 
         pyo3::py_run!(
             py,
@@ -454,9 +714,9 @@ fn test_pyderive_kw_only_trailing_new_field() {
     #[derive(Default)]
     struct PyClass {
         fd_a: i64,
-        #[pyderive(kw_only)]
         #[pyderive(new = false)]
         fd_b: i64,
+        #[pyderive(kw_only)]
         fd_c: i64,
     }
 
@@ -507,3 +767,400 @@ assert a.field.field == 1
         );
     });
 }
+
+#[test]
+fn test_pyderive_positional_only() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(positional_only)]
+        a: i64,
+        #[pyderive(positional_only, default = 2)]
+        b: i64,
+        #[pyderive(default = 3)]
+        c: i64,
+        #[pyderive(kw_only, default = 4)]
+        d: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+actual = py_class(1)
+assert (actual.a, actual.b, actual.c, actual.d) == (1, 2, 3, 4)
+
+actual = py_class(10, 20, c=30, d=40)
+assert (actual.a, actual.b, actual.c, actual.d) == (10, 20, 30, 40)
+
+actual = py_class(10, 20, 30)
+assert (actual.a, actual.b, actual.c, actual.d) == (10, 20, 30, 4)
+
+try:
+    py_class(a=1)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+
+try:
+    py_class(1, b=2)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_default_py() {
+    #[pyclass(get_all)]
+    #[derive(Default)]
+    struct Child {
+        value: i64,
+    }
+
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(default = Py::new(py, Child::default())?)]
+        child: Py<Child>,
+        #[pyderive(default = Some(Py::new(py, Child { value: 1 })?))]
+        optional: Option<Py<Child>>,
+        #[pyderive(new = false, default = Py::new(py, Child { value: 2 })?)]
+        fixed: Py<Child>,
+        #[pyderive(default = 3)]
+        plain: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class()
+assert a.child.value == 0
+assert a.optional.value == 1
+assert a.fixed.value == 2
+assert a.plain == 3
+
+# evaluated on every call
+b = py_class()
+assert a.child is not b.child
+assert a.fixed is not b.fixed
+
+c = a.child
+b = py_class(c, plain=4)
+assert b.child is c
+assert b.plain == 4
+
+# None passed explicitly is not the missing argument
+d = py_class(optional=None)
+assert d.optional is None
+
+try:
+    py_class(child=None)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_default_py_vec() {
+    #[pyclass(get_all, set_all)]
+    struct Child {
+        value: i64,
+    }
+
+    #[derive(PyNew, PyDataclassFields)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(default = vec![Py::new(py, Child { value: 0 })?], dataclass_field = false)]
+        children: Vec<Py<Child>>,
+        #[pyderive(default = Vec::new(), default_factory = true)]
+        empty: Vec<Py<Child>>,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+from dataclasses import fields
+
+a = py_class()
+b = py_class()
+
+# the children are built on every call, not shared across the instances
+assert a.children[0] is not b.children[0]
+a.children[0].value = 1
+assert a.children[0].value == 1
+assert b.children[0].value == 0
+assert a.empty == [] and b.empty == []
+
+# the factory returns a fresh list on every call, like test_default_factory
+field = next(f for f in fields(a) if f.name == "empty")
+x = field.default_factory()
+y = field.default_factory()
+x.append(a.children[0])
+assert y == []
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_compute() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        a: i64,
+        #[pyderive(compute = |this| this.a + this.b)]
+        sum: i64,
+        b: i64,
+        #[pyderive(compute = |this| format!("{}+{}={}", this.a, this.b, this.sum))]
+        text: String,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(1, 2)
+assert a.sum == 3
+assert a.text == "1+2=3"
+
+try:
+    py_class(1, 2, 3)
+    raise AssertionError
+except TypeError:
+    pass
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_signature() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    #[pyderive(signature = (b, /, a, *, c = 10, d))]
+    struct PyClass {
+        #[pyderive(default = 1)]
+        a: i64,
+        b: String,
+        #[pyderive(default = 2)]
+        c: i64,
+        #[pyderive(default = Vec::new())]
+        d: Vec<i64>,
+        #[pyderive(new = false, default = 5)]
+        e: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class("x")
+assert a.b == "x"
+assert a.a == 1
+assert a.c == 10
+assert a.d == []
+assert a.e == 5
+
+a = py_class("y", 3, c=4, d=[5])
+assert (a.b, a.a, a.c, a.d) == ("y", 3, 4, [5])
+
+for args, kwargs in [((), {"b": "z"}), (("z", 1, 2), {})]:
+    try:
+        py_class(*args, **kwargs)
+        raise AssertionError
+    except TypeError:
+        pass
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_default_python() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(default_py = "datetime.datetime.now(datetime.timezone.utc)")]
+        created: Py<PyAny>,
+        #[pyderive(default_py = "os.path.join('a', 'b')")]
+        path: String,
+        #[pyderive(default_py = "len([1, 2, 3])")]
+        builtin: i64,
+        #[pyderive(new = false, default_py = "uuid.uuid4().hex")]
+        id: String,
+        #[pyderive(default_py = "xml.dom.minidom.parseString('<a/>').documentElement.tagName")]
+        submodule: String,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+import datetime, os
+
+a = py_class()
+assert isinstance(a.created, datetime.datetime)
+assert a.created.tzinfo is datetime.timezone.utc
+assert a.path == os.path.join('a', 'b')
+assert a.builtin == 3
+assert len(a.id) == 32
+assert a.submodule == "a"
+
+# evaluated on every call
+b = py_class(path="c", builtin=4)
+assert a.id != b.id
+assert a.created is not b.created
+assert (b.path, b.builtin) == ("c", 4)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_default_python_import_error() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(default_py = "pyderive_no_such_module.f()")]
+        module: i64,
+        #[pyderive(default_py = "os.pyderive_no_such_submodule.f()")]
+        submodule: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+try:
+    py_class(submodule=0)
+except ModuleNotFoundError as e:
+    assert e.name == "pyderive_no_such_module"
+else:
+    raise AssertionError
+
+try:
+    py_class(module=0)
+except ModuleNotFoundError as e:
+    assert e.name == "os.pyderive_no_such_submodule"
+else:
+    raise AssertionError
+
+assert py_class(module=1, submodule=2).module == 1
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_strip_underscore() {
+    #[derive(PyNew, PyRepr, PyDataclassFields)]
+    #[pyclass]
+    #[pyderive(strip_underscore)]
+    struct PyClass {
+        #[pyderive(repr = true)]
+        type_: String,
+        #[pyo3(get, name = "class")]
+        class_: i64,
+        #[pyo3(get)]
+        #[pyderive(default = 0)]
+        _private: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+a = py_class(type="a", **{"class": 1})
+assert getattr(a, "class") == 1
+assert repr(a) == "PyClass(type='a', class=1, _private=0)"
+assert list(a.__dataclass_fields__) == ["type", "class", "_private"]
+"#
+        );
+    });
+}
+
+#[test]
+fn test_pyderive_flatten() {
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    #[derive(Clone)]
+    struct Child {
+        x: i64,
+        #[pyderive(default = 0)]
+        y: i64,
+    }
+
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        name: String,
+        #[pyderive(flatten)]
+        child: Child,
+    }
+
+    #[derive(PyNew)]
+    #[pyclass(get_all)]
+    struct PyClassPy {
+        #[pyderive(flatten)]
+        child: Py<Child>,
+        #[pyderive(default = 1)]
+        z: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_py = py.get_type::<PyClassPy>();
+        pyo3::py_run!(
+            py,
+            py_class py_class_py,
+            r#"
+a = py_class("a", x=1, y=2)
+assert (a.name, a.child.x, a.child.y) == ("a", 1, 2)
+
+a = py_class(name="a", x=1)
+assert (a.name, a.child.x, a.child.y) == ("a", 1, 0)
+
+b = py_class_py(x=3)
+assert (b.child.x, b.child.y, b.z) == (3, 0, 1)
+
+b = py_class_py(2, x=3)
+assert (b.child.x, b.z) == (3, 2)
+
+for args, kwargs in [(("a",), {}), (("a",), {"x": 1, "unknown": 2}), (("a", 1), {})]:
+    try:
+        py_class(*args, **kwargs)
+    except TypeError:
+        pass
+    else:
+        raise AssertionError
+"#
+        );
+    });
+}
@@ -93,8 +93,12 @@ assert (obj + other).field == 8
 assert (obj - other).field == 2
 assert (obj * other).field == 15
 assert (obj / other).field == 1
+assert (obj // other).field == 1
 assert (obj % other).field == 2
 
+# // calls Div, which truncates toward zero unlike Python
+assert (py_class(-7) // py_class(2)).field == -3
+
 a, b = divmod(obj, other) 
 assert a.field == 1
 assert b.field == 2
@@ -115,6 +119,10 @@ obj = py_class(5)
 obj /= other
 assert obj.field == 1
 
+obj = py_class(5)
+obj //= other
+assert obj.field == 1
+
 obj = py_class(5)
 obj %= other
 assert obj.field == 2
@@ -122,3 +130,111 @@ assert obj.field == 2
         );
     });
 }
+
+#[test]
+fn test_reflected() {
+    #[derive(PyReflectedNumeric)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        field: i64,
+    }
+
+    impl_new!(PyClass);
+    impl_binary!(PyClass, Add::add);
+    impl_binary!(PyClass, Sub::sub);
+    impl_binary!(PyClass, Mul::mul);
+    impl_binary!(PyClass, Div::div);
+    impl_binary!(PyClass, Rem::rem);
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+obj = py_class(5)
+other = py_class(3)
+
+# the class has the reflected methods only,
+# thus the operator dispatches to the reflected method of the right operand
+assert (obj + other).field == 8
+assert (obj - other).field == 2
+assert (obj * other).field == 15
+assert (obj / other).field == 1
+assert (obj // other).field == 1
+assert (obj % other).field == 2
+
+a, b = divmod(obj, other)
+assert a.field == 1
+assert b.field == 2
+
+assert other.__radd__(obj).field == 8
+assert other.__rsub__(obj).field == 2
+assert other.__rmul__(obj).field == 15
+assert other.__rtruediv__(obj).field == 1
+assert other.__rfloordiv__(obj).field == 1
+assert other.__rmod__(obj).field == 2
+
+a, b = other.__rdivmod__(obj)
+assert a.field == 1
+assert b.field == 2
+
+# the left operand must be an instance of the class
+import operator
+for op in [operator.add, operator.sub, operator.mul, operator.truediv, operator.floordiv, operator.mod, divmod]:
+    try:
+        op(2, obj)
+    except TypeError:
+        pass
+    else:
+        raise AssertionError(op)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_with_reflected() {
+    // PyNumeric and PyReflectedNumeric complement each other without a duplicated method
+    #[derive(PyNumeric, PyReflectedNumeric)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        field: i64,
+    }
+
+    impl_new!(PyClass);
+    impl_unary!(PyClass, Neg::neg);
+    impl_binary!(PyClass, Add::add);
+    impl_binary!(PyClass, Sub::sub);
+    impl_binary!(PyClass, Mul::mul);
+    impl_binary!(PyClass, Div::div);
+    impl_binary!(PyClass, Rem::rem);
+    impl_binary_assign!(PyClass, AddAssign::add_assign);
+    impl_binary_assign!(PyClass, SubAssign::sub_assign);
+    impl_binary_assign!(PyClass, MulAssign::mul_assign);
+    impl_binary_assign!(PyClass, DivAssign::div_assign);
+    impl_binary_assign!(PyClass, RemAssign::rem_assign);
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+obj = py_class(5)
+other = py_class(3)
+
+assert (obj // other).field == 1
+assert obj.__floordiv__(other).field == 1
+assert other.__rfloordiv__(obj).field == 1
+
+a, b = divmod(obj, other)
+assert a.field == 1
+assert b.field == 2
+a, b = other.__rdivmod__(obj)
+assert a.field == 1
+assert b.field == 2
+"#
+        );
+    });
+}
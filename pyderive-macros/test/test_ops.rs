@@ -948,3 +948,260 @@ assert actual.field == 4
         });
     }
 }
+
+#[cfg(test)]
+mod rhs {
+    use super::*;
+
+    #[derive(PyAdd, PyMul, PyReflectedAdd)]
+    #[pyclass(get_all)]
+    #[pyderive(rhs(Self, i64, f64))]
+    struct Money {
+        amount: f64,
+        // the last rhs that was used
+        rhs: &'static str,
+    }
+
+    #[pymethods]
+    impl Money {
+        #[new]
+        fn new(amount: f64) -> Self {
+            Self { amount, rhs: "" }
+        }
+    }
+
+    macro_rules! impl_money {
+        ($trait:ident::$method:ident) => {
+            impl $trait for &Money {
+                type Output = Money;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Money {
+                        amount: $trait::$method(self.amount, rhs.amount),
+                        rhs: "Money",
+                    }
+                }
+            }
+
+            impl $trait<i64> for &Money {
+                type Output = Money;
+
+                fn $method(self, rhs: i64) -> Self::Output {
+                    Money {
+                        amount: $trait::$method(self.amount, rhs as f64),
+                        rhs: "int",
+                    }
+                }
+            }
+
+            impl $trait<f64> for &Money {
+                type Output = Money;
+
+                fn $method(self, rhs: f64) -> Self::Output {
+                    Money {
+                        amount: $trait::$method(self.amount, rhs),
+                        rhs: "float",
+                    }
+                }
+            }
+
+            impl $trait<&Money> for i64 {
+                type Output = Money;
+
+                fn $method(self, rhs: &Money) -> Self::Output {
+                    Money {
+                        amount: $trait::$method(self as f64, rhs.amount),
+                        rhs: "int",
+                    }
+                }
+            }
+
+            impl $trait<&Money> for f64 {
+                type Output = Money;
+
+                fn $method(self, rhs: &Money) -> Self::Output {
+                    Money {
+                        amount: $trait::$method(self, rhs.amount),
+                        rhs: "float",
+                    }
+                }
+            }
+        };
+    }
+
+    impl_money!(Add::add);
+    impl_money!(Mul::mul);
+
+    #[test]
+    fn add() {
+        Python::attach(|py| {
+            let py_class = py.get_type::<Money>();
+            pyo3::py_run!(
+                py,
+                py_class,
+                r#"
+actual = py_class(1.5) + 2
+assert actual.amount == 3.5
+assert actual.rhs == "int"
+
+actual = py_class(1.5) + 0.25
+assert actual.amount == 1.75
+assert actual.rhs == "float"
+
+actual = py_class(1.5) + py_class(1.0)
+assert actual.amount == 2.5
+assert actual.rhs == "Money"
+
+try:
+    py_class(1.5) + "1"
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+            );
+        });
+    }
+
+    #[test]
+    fn mul() {
+        Python::attach(|py| {
+            let py_class = py.get_type::<Money>();
+            pyo3::py_run!(
+                py,
+                py_class,
+                r#"
+actual = py_class(1.5) * 2
+assert actual.amount == 3.0
+assert actual.rhs == "int"
+
+actual = py_class(1.5) * 0.5
+assert actual.amount == 0.75
+assert actual.rhs == "float"
+
+actual = py_class(1.5) * py_class(3.0)
+assert actual.amount == 4.5
+assert actual.rhs == "Money"
+
+try:
+    py_class(1.5) * None
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+            );
+        });
+    }
+
+    #[test]
+    fn reflected() {
+        Python::attach(|py| {
+            let py_class = py.get_type::<Money>();
+            pyo3::py_run!(
+                py,
+                py_class,
+                r#"
+actual = 2 + py_class(1.5)
+assert actual.amount == 3.5
+assert actual.rhs == "int"
+
+actual = 0.25 + py_class(1.5)
+assert actual.amount == 1.75
+assert actual.rhs == "float"
+
+try:
+    "1" + py_class(1.5)
+except TypeError:
+    pass
+else:
+    raise AssertionError
+"#
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod by_value {
+    use super::*;
+
+    // by-value impls only, no impl for &Vector
+    #[derive(PyAdd, PyReflectedAdd, PyNeg, PyAddAssign, Clone)]
+    #[pyclass(get_all)]
+    #[pyderive(by_value)]
+    struct Vector {
+        x: i64,
+        y: i64,
+    }
+
+    #[pymethods]
+    impl Vector {
+        #[new]
+        fn new(x: i64, y: i64) -> Self {
+            Self { x, y }
+        }
+    }
+
+    impl Add for Vector {
+        type Output = Vector;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Vector {
+                x: self.x + rhs.x,
+                y: self.y + rhs.y,
+            }
+        }
+    }
+
+    impl Neg for Vector {
+        type Output = Vector;
+
+        fn neg(self) -> Self::Output {
+            Vector {
+                x: -self.x,
+                y: -self.y,
+            }
+        }
+    }
+
+    impl AddAssign for Vector {
+        fn add_assign(&mut self, rhs: Self) {
+            self.x += rhs.x;
+            self.y += rhs.y;
+        }
+    }
+
+    #[test]
+    fn by_value() {
+        Python::attach(|py| {
+            let py_class = py.get_type::<Vector>();
+            pyo3::py_run!(
+                py,
+                py_class,
+                r#"
+a = py_class(1, 2)
+b = py_class(10, 20)
+
+actual = a + b
+assert (actual.x, actual.y) == (11, 22)
+assert actual is not a and actual is not b
+
+actual = b.__radd__(a)
+assert (actual.x, actual.y) == (11, 22)
+
+actual = -a
+assert (actual.x, actual.y) == (-1, -2)
+
+# operands are intact
+assert (a.x, a.y) == (1, 2)
+assert (b.x, b.y) == (10, 20)
+
+c = a
+c += b
+assert (c.x, c.y) == (11, 22)
+"#
+            );
+        });
+    }
+}
@@ -154,3 +154,131 @@ assert not a >= b
         );
     });
 }
+
+#[test]
+fn test_order_key() {
+    #[derive(PyOrd)]
+    #[pyclass]
+    #[pyderive(order_key = key)]
+    #[allow(dead_code)]
+    struct PyClass {
+        fa: i64,
+        fb: String,
+    }
+
+    fn key(this: &PyClass) -> i64 {
+        this.fa
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "b".to_string(),
+            },
+        )
+        .unwrap();
+        let data2 = Py::new(
+            py,
+            PyClass {
+                fa: 2,
+                fb: "a".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data1 data2,  "assert data1 < data2");
+        py_run!(py, data1 data2,  "assert data1 <= data2");
+        py_run!(py, data1 data2,  "assert not data1 > data2");
+        py_run!(py, data1 data2,  "assert not data1 >= data2");
+        py_run!(py, data1 data2,  "assert sorted([data2, data1]) == [data1, data2]");
+    });
+}
+
+#[test]
+fn test_order_reverse() {
+    #[derive(PyOrd)]
+    #[pyclass(get_all)]
+    #[pyderive(order_reverse)]
+    #[derive(PartialOrd, PartialEq)]
+    struct PyClass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(py, PyClass { field: 1 }).unwrap();
+        let data2 = Py::new(py, PyClass { field: 2 }).unwrap();
+        let data3 = Py::new(py, PyClass { field: 3 }).unwrap();
+        py_run!(py, data1 data2 data3, "assert data1 > data2");
+        py_run!(py, data1 data2 data3, "assert data1 >= data2");
+        py_run!(py, data1 data2 data3, "assert data1 >= data1");
+        py_run!(py, data1 data2 data3, "assert not data1 < data2");
+        py_run!(py, data1 data2 data3, "assert not data1 <= data2");
+        py_run!(py, data1 data2 data3, "assert data1 <= data1");
+        py_run!(py, data1 data2 data3, "assert [d.field for d in sorted([data2, data1, data3])] == [3, 2, 1]");
+    });
+}
+
+#[test]
+fn test_nan_inf() {
+    #[derive(PyNew, PyOrd)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import operator
+
+values = [float("nan"), float("inf"), float("-inf"), 0.0]
+for op in [operator.lt, operator.le, operator.gt, operator.ge]:
+    for x in values:
+        for y in values:
+            assert op(py_class(x), py_class(y)) is op(x, y), (op, x, y)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_order_nan_raise() {
+    #[derive(PyNew, PyOrd)]
+    #[pyclass(get_all)]
+    #[pyderive(order_nan = "raise")]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import math
+import operator
+
+values = [float("nan"), float("inf"), float("-inf"), 0.0]
+for op in [operator.lt, operator.le, operator.gt, operator.ge]:
+    for x in values:
+        for y in values:
+            if math.isnan(x) or math.isnan(y):
+                try:
+                    op(py_class(x), py_class(y))
+                except ValueError:
+                    pass
+                else:
+                    raise AssertionError((op, x, y))
+            else:
+                assert op(py_class(x), py_class(y)) is op(x, y), (op, x, y)
+"#
+        );
+    });
+}
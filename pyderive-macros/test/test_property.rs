@@ -0,0 +1,44 @@
+use pyderive_macros::*;
+use pyo3::prelude::*;
+
+#[test]
+fn test_property() {
+    #[derive(PyNew)]
+    #[pyclass]
+    struct PyClass {
+        width: f64,
+        height: f64,
+    }
+
+    #[pyderive_macros::property]
+    impl PyClass {
+        fn area(&self) -> f64 {
+            self.width * self.height
+        }
+
+        fn label(&self, py: Python<'_>) -> PyResult<String> {
+            let name = py.get_type::<Self>().name()?;
+            Ok(format!("{name}({}x{})", self.width, self.height))
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        pyo3::py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(2.0, 3.0)
+assert data.area == 6.0
+assert data.label == "PyClass(2x3)"
+
+try:
+    data.area = 1.0
+except AttributeError:
+    pass
+else:
+    raise AssertionError
+"#
+        );
+    });
+}
@@ -86,6 +86,7 @@ fn test_variation() {
 
                 fd_name_pystr_abspath,
             ))]
+        #[allow(clippy::too_many_arguments)]
         fn new(
             fd_name_bool: bool,
             fd_name_str: String,
@@ -233,6 +234,56 @@ assert repr(a) == "PyClassA(field=PyClassB(field=1))"
     });
 }
 
+#[test]
+#[allow(deprecated)]
+fn test_py_any() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fd_name_any: Py<PyAny>,
+        fd_name_object: PyObject,
+        fd_name_opt_any: Option<Py<PyAny>>,
+        fd_name_vec_any: Vec<Py<PyAny>>,
+    }
+
+    #[pymethods]
+    impl PyClass {
+        #[new]
+        fn new(
+            fd_name_any: Py<PyAny>,
+            fd_name_object: PyObject,
+            fd_name_opt_any: Option<Py<PyAny>>,
+            fd_name_vec_any: Vec<Py<PyAny>>,
+        ) -> Self {
+            Self {
+                fd_name_any,
+                fd_name_object,
+                fd_name_opt_any,
+                fd_name_vec_any,
+            }
+        }
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+class Opaque:
+    def __repr__(self):
+        return "<opaque>"
+
+d = {'a': [1, 'b'], 'c': None}
+data = py_class(d, Opaque(), Opaque(), [d, Opaque()])
+expected = f"PyClass(fd_name_any={d!r}, fd_name_object=<opaque>, fd_name_opt_any=<opaque>, fd_name_vec_any=[{d!r}, <opaque>])"
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+"#
+        );
+    });
+}
+
 #[test]
 fn test_no_get_set() {
     #[derive(PyRepr)]
@@ -292,6 +343,71 @@ fn test_get_all() {
     });
 }
 
+#[test]
+fn test_pyo3_pyclass_path() {
+    #[derive(PyRepr)]
+    #[pyo3::pyclass(get_all)]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"assert repr(data) == "PyClass(fd_name_a=0, fd_name_b=0.0)""#
+        )
+    });
+}
+
+#[test]
+fn test_pyo3_prelude_pyclass_path() {
+    #[derive(PyRepr)]
+    #[pyo3::prelude::pyclass(get_all)]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(py, data, r#"assert repr(data) == "PyClass(fd_name_a=0)""#)
+    });
+}
+
+#[test]
+fn test_str_fields() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fd_name_string: String,
+        fd_name_cow: std::borrow::Cow<'static, str>,
+        fd_name_static: &'static str,
+    }
+
+    Python::attach(|py| {
+        let large = "a'b\"c\n".repeat(10_000);
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_string: large.clone(),
+                fd_name_cow: std::borrow::Cow::Owned(large.clone()),
+                fd_name_static: "static",
+            },
+        )
+        .unwrap();
+        let large = pyo3::types::PyString::new(py, &large);
+        py_run!(
+            py,
+            data large,
+            r#"assert repr(data) == f"PyClass(fd_name_string={large!r}, fd_name_cow={large!r}, fd_name_static='static')""#
+        )
+    });
+}
+
 #[test]
 fn test_set_all() {
     #[derive(PyRepr)]
@@ -333,6 +449,21 @@ fn test_name_rename_all() {
     });
 }
 
+#[test]
+fn test_name_rename_all_kebab_case() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all, rename_all = "kebab-case")]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(py, data, r#"assert repr(data) == "PyClass(fd-name-a=0)""#)
+    });
+}
+
 #[test]
 fn test_pyderive_true() {
     #[derive(PyRepr)]
@@ -349,6 +480,50 @@ fn test_pyderive_true() {
     });
 }
 
+#[test]
+fn test_pyderive_true_private() {
+    #[derive(PyRepr)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        fd_name_a: i64,
+        #[pyderive(repr)]
+        fd_name_b: Vec<String>,
+        #[pyderive(repr)]
+        fd_name_c: String,
+    }
+
+    #[pymethods]
+    impl PyClass {
+        // the repr does not call the getter
+        #[getter]
+        fn fd_name_c(&self) -> &'static str {
+            "getter"
+        }
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1,
+                fd_name_b: vec!["b".to_string()],
+                fd_name_c: "c".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert not hasattr(data, "fd_name_b")
+assert data.fd_name_c == "getter"
+assert repr(data) == "PyClass(fd_name_a=1, fd_name_b=['b'], fd_name_c='c')"
+"#
+        )
+    });
+}
+
 #[test]
 fn test_pyderive_false() {
     #[derive(PyRepr)]
@@ -365,3 +540,862 @@ fn test_pyderive_false() {
         py_run!(py, data, r#"assert repr(data) == "PyClass()""#)
     });
 }
+
+#[test]
+fn test_repr_name() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all, name = "Renamed")]
+    #[pyderive(repr_name = "Overridden")]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert type(data).__qualname__ == "Renamed"
+assert repr(data) == "Overridden(fd_name_a=0)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_repr_float() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(repr_float = ".3f")]
+        fd_name_a: f64,
+        #[pyderive(repr_float = ".2e")]
+        fd_name_b: f32,
+        fd_name_c: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1.0,
+                fd_name_b: 1234.5,
+                fd_name_c: 1.0,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(fd_name_a=1.000, fd_name_b=1.23e+03, fd_name_c=1.0)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_nan_inf() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        fd_name_a: f64,
+        fd_name_b: f64,
+        fd_name_c: f32,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: f64::NAN,
+                fd_name_b: f64::INFINITY,
+                fd_name_c: f32::NEG_INFINITY,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(fd_name_a=nan, fd_name_b=inf, fd_name_c=-inf)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_frozen() {
+    #[derive(PyRepr)]
+    #[pyclass(frozen)]
+    #[pyderive(frozen_get)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    struct PyClass {
+        fd_name_a: i64,
+        #[pyderive(repr = false)]
+        fd_name_b: String,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(py, data, r#"assert repr(data) == "PyClass(fd_name_a=0)""#)
+    });
+}
+
+#[test]
+fn test_frozen_without_frozen_get() {
+    #[derive(Default)]
+    struct Opaque;
+
+    #[derive(PyRepr)]
+    #[pyclass(frozen)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    struct PyClass {
+        #[pyo3(get)]
+        fd_name_a: i64,
+        fd_name_b: Opaque,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(py, data, r#"assert repr(data) == "PyClass(fd_name_a=0)""#)
+    });
+}
+
+#[test]
+fn test_repr_order_sorted() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    #[pyderive(repr_order = "sorted")]
+    struct PyClass {
+        zeta: i64,
+        #[pyo3(name = "beta")]
+        alpha: i64,
+        gamma: i64,
+        #[pyderive(repr = false, str = false)]
+        delta: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                zeta: 1,
+                alpha: 2,
+                gamma: 3,
+                delta: 4,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(beta=2, gamma=3, zeta=1)"
+assert str(data) == "PyClass(beta=2, gamma=3, zeta=1)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_path() {
+    use std::borrow::Cow;
+    use std::path::{Path, PathBuf};
+
+    #[derive(PyRepr, PyStr)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        path_buf: PathBuf,
+        // Box<Path> is not IntoPyObject
+        #[pyderive(repr = true, str = true)]
+        boxed: Box<Path>,
+        #[pyo3(get)]
+        cow: Cow<'static, Path>,
+        #[pyo3(get)]
+        reference: &'static Path,
+        #[pyo3(get)]
+        #[pyderive(path_str = false)]
+        opt_out: PathBuf,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                path_buf: PathBuf::from("/tmp/a.txt"),
+                boxed: Path::new("b").into(),
+                cow: Cow::Borrowed(Path::new("c's")),
+                reference: Path::new("d"),
+                opt_out: PathBuf::from("e"),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+import pathlib
+
+expected = "PyClass(path_buf='/tmp/a.txt', boxed='b', cow=\"c's\", reference='d', opt_out={})".format(repr(pathlib.Path("e")))
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+"#
+        )
+    });
+}
+
+#[test]
+fn test_escape() {
+    use std::borrow::Cow;
+
+    #[derive(PyRepr, PyStr)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        string: String,
+        // forced without get, rendered by the &str path
+        #[pyderive(repr = true, str = true)]
+        private: String,
+        #[pyderive(repr = true, str = true)]
+        cow: Cow<'static, str>,
+        #[pyderive(repr = true, str = true)]
+        reference: &'static str,
+        #[pyderive(repr = true, str = true)]
+        option: Option<String>,
+    }
+
+    const S: &str = "\n\t'\"\\\x00\u{7f}\u{e9}\u{1f600}";
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                string: S.to_string(),
+                private: S.to_string(),
+                cow: Cow::Borrowed(S),
+                reference: S,
+                option: Some(S.to_string()),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+s = "\n\t'\"\\\x00\x7fé\U0001f600"
+r = repr(s)
+expected = "PyClass(string={0}, private={0}, cow={0}, reference={0}, option={0})".format(r)
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+"#
+        )
+    });
+}
+
+#[test]
+fn test_time() {
+    use std::time::{Duration, SystemTime};
+
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        duration: Duration,
+        time: SystemTime,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                duration: Duration::from_millis(1500),
+                time: SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+expected = "PyClass(duration=datetime.timedelta(seconds=1, microseconds=500000), time='1970-01-01T00:00:01+00:00')"
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_tuple() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    struct Child {
+        field: String,
+    }
+
+    #[derive(PyRepr, PyStr)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        five: (String, i64, f64, Py<Child>, (Option<i64>, &'static str)),
+        #[pyderive(repr = true, str = true)]
+        one: (String,),
+        #[pyderive(repr = true, str = true)]
+        #[allow(clippy::type_complexity)]
+        thirteen: (
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+        ),
+        #[pyo3(get)]
+        unit: (),
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                five: (
+                    "s".to_string(),
+                    1,
+                    1.5,
+                    Py::new(
+                        py,
+                        Child {
+                            field: "'c'".to_string(),
+                        },
+                    )
+                    .unwrap(),
+                    (None, "t"),
+                ),
+                one: ("o".to_string(),),
+                thirteen: (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12),
+                unit: (),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+child = data.five[3]
+expected = "PyClass(five={}, one={}, thirteen={}, unit=())".format(
+    repr(("s", 1, 1.5, child, (None, "t"))),
+    repr(("o",)),
+    repr(tuple(range(13))),
+)
+assert repr(child) == """Child(field="'c'")"""
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_repr_prefix_suffix() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass]
+    #[pyderive(repr_prefix = "Decimal(", repr_suffix = ")")]
+    struct Decimal {
+        #[pyo3(get)]
+        value: String,
+        #[allow(dead_code)]
+        scale: i64,
+    }
+
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    #[pyderive(repr_prefix = "<", repr_suffix = ">")]
+    struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    #[derive(PyRepr)]
+    #[pyclass]
+    #[pyderive(repr_prefix = "Color.RED", repr_suffix = "")]
+    struct Red {}
+
+    Python::attach(|py| {
+        let decimal = Py::new(
+            py,
+            Decimal {
+                value: "1.5".to_string(),
+                scale: 1,
+            },
+        )
+        .unwrap();
+        let point = Py::new(py, Point { x: 1, y: 2 }).unwrap();
+        let red = Py::new(py, Red {}).unwrap();
+        py_run!(
+            py,
+            decimal point red,
+            r#"
+assert repr(decimal) == "Decimal('1.5')"
+assert str(decimal) == "Decimal('1.5')"
+assert repr(point) == "<1, 2>"
+assert repr(red) == "Color.RED"
+"#
+        );
+    });
+}
+
+#[test]
+fn test_smart_pointer() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    struct Child {
+        field: i64,
+    }
+
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(unsendable)]
+    struct PyClass {
+        #[pyderive(repr, str)]
+        arc: Arc<String>,
+        #[pyderive(repr, str)]
+        boxed: Box<(i64, Arc<str>)>,
+        #[pyderive(repr, str)]
+        rc: Rc<Option<Py<Child>>>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                arc: Arc::new("a'b".to_string()),
+                boxed: Box::new((1, Arc::from("s"))),
+                rc: Rc::new(Some(Py::new(py, Child { field: 2 }).unwrap())),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+expected = "PyClass(arc=\"a'b\", boxed=(1, 's'), rc=Child(field=2))"
+assert repr(data) == expected, repr(data)
+assert str(data) == expected, str(data)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_repr_depth() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    #[pyderive(repr_depth = 3)]
+    struct Node {
+        value: i64,
+        child: Option<Py<Node>>,
+    }
+
+    Python::attach(|py| {
+        let mut node = None;
+        for value in (0..5).rev() {
+            node = Some(Py::new(py, Node { value, child: node }).unwrap());
+        }
+        let node = node.unwrap();
+        let child = node.borrow(py).child.as_ref().unwrap().clone_ref(py);
+        py_run!(
+            py,
+            node child,
+            r#"
+expected = "Node(value=0, child=Node(value=1, child=Node(value=2, child=...)))"
+assert repr(node) == expected, repr(node)
+
+# the depth counts from the outermost repr() call
+expected = "Node(value=1, child=Node(value=2, child=Node(value=3, child=...)))"
+assert repr(child) == expected, repr(child)
+assert repr([node]) == "[Node(value=0, child=Node(value=1, child=Node(value=2, child=...)))]"
+"#
+        );
+    });
+}
+
+#[test]
+fn test_repr_all() {
+    #[derive(PyRepr, PyStr, PyIter, PyLen)]
+    #[pyclass]
+    #[pyderive(repr_all, str_all, iter_all, len_all)]
+    struct PyClass {
+        a: i64,
+        b: String,
+        #[pyderive(repr = false, str = false, iter = false, len = false)]
+        #[allow(dead_code)]
+        c: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                a: 1,
+                b: "s".to_string(),
+                c: 2,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert not hasattr(data, "a")
+assert repr(data) == "PyClass(a=1, b='s')", repr(data)
+assert str(data) == "PyClass(a=1, b='s')", str(data)
+assert list(data) == [1, "s"]
+assert len(data) == 2
+"#
+        );
+    });
+}
+
+#[test]
+fn test_repr_debug() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum State {
+        Idle,
+        Running { pid: u32 },
+    }
+
+    #[derive(PyRepr, PyStr)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        field: i64,
+        #[pyderive(repr = "debug")]
+        state: State,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                field: 1,
+                state: State::Running { pid: 42 },
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(field=1, state=Running { pid: 42 })", repr(data)
+assert str(data) == "PyClass(field=1)", str(data)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_repr_callable() {
+    #[derive(PyRepr)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        callback: Py<PyAny>,
+        #[pyo3(get)]
+        #[pyderive(repr = "name")]
+        named: Py<PyAny>,
+        #[pyo3(get)]
+        #[pyderive(repr = "name")]
+        fallback: Option<Py<PyAny>>,
+    }
+
+    Python::attach(|py| {
+        let len = py
+            .import("builtins")
+            .unwrap()
+            .getattr("len")
+            .unwrap()
+            .unbind();
+        let data = Py::new(
+            py,
+            PyClass {
+                callback: len.clone_ref(py),
+                named: len,
+                fallback: None,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(callback=<built-in function len>, named=len, fallback=None)", repr(data)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_locale_independent() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        float: f64,
+        single: f32,
+        int: i64,
+        tuple: (f64, i64),
+        #[pyderive(repr_float = ",.2f")]
+        spec: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                float: 1234.5,
+                single: 0.25,
+                int: 1234567,
+                tuple: (0.5, -1),
+                spec: 1234.5,
+            },
+        )
+        .unwrap();
+        // a comma decimal separator locale if the platform has one
+        py_run!(
+            py,
+            data,
+            r#"
+import locale
+
+expected = "PyClass(float=1234.5, single=0.25, int=1234567, tuple=(0.5, -1), spec=1,234.50)"
+
+saved = locale.setlocale(locale.LC_ALL)
+try:
+    for name in ("de_DE.UTF-8", "de_DE.utf8", "fr_FR.UTF-8", "fr_FR.utf8", "German_Germany.1252"):
+        try:
+            locale.setlocale(locale.LC_ALL, name)
+            break
+        except locale.Error:
+            pass
+
+    assert repr(data) == expected, repr(data)
+    assert str(data) == expected, str(data)
+finally:
+    locale.setlocale(locale.LC_ALL, saved)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_cache_repr() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::OnceLock;
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted(i64);
+
+    impl std::fmt::Debug for Counted {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            COUNT.fetch_add(1, Ordering::SeqCst);
+            write!(f, "{}", self.0)
+        }
+    }
+
+    #[derive(PyNew, PyRepr)]
+    #[pyclass(frozen)]
+    struct PyClass {
+        #[pyderive(repr = "debug", new = false, default = Counted(1))]
+        field: Counted,
+        #[pyo3(get)]
+        other: i64,
+        #[pyderive(cache_repr)]
+        cache: OnceLock<String>,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+data = py_class(2)
+assert repr(data) == "PyClass(field=1, other=2)", repr(data)
+assert repr(data) == "PyClass(field=1, other=2)"
+"#
+        );
+        assert_eq!(COUNT.load(Ordering::SeqCst), 1);
+    });
+}
+
+#[test]
+fn test_pyclass_name() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all, name = "Other")]
+    struct PyClass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass { field: 1 }).unwrap();
+        py_run!(py, data, "assert repr(data) == 'Other(field=1)'")
+    });
+}
+
+#[test]
+fn test_result() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(frozen)]
+    #[pyderive(frozen_get)]
+    struct PyClass {
+        ok: Result<i64, String>,
+        err: Result<i64, String>,
+        nested: Box<Result<(i64, String), Vec<i64>>>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                ok: Ok(1),
+                err: Err("e".to_string()),
+                nested: Box::new(Err(vec![1, 2])),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(ok=Ok(1), err=Err('e'), nested=Err([1, 2]))"
+assert str(data) == "PyClass(ok=Ok(1), err=Err('e'), nested=Err([1, 2]))"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_repr_fields() {
+    #[derive(PyRepr)]
+    #[pyclass(get_all)]
+    #[allow(dead_code)]
+    struct PyClass {
+        a: i64,
+        #[pyo3(name = "renamed")]
+        b: i64,
+        #[pyderive(repr = false)]
+        c: i64,
+    }
+
+    #[derive(PyRepr)]
+    #[pyclass]
+    #[pyderive(repr_order = "sorted")]
+    #[allow(dead_code)]
+    struct PyClassSorted {
+        #[pyderive(repr = true)]
+        z: i64,
+        #[pyderive(repr = true)]
+        y: i64,
+        x: i64,
+    }
+
+    #[derive(PyRepr)]
+    #[pyclass]
+    #[allow(dead_code)]
+    struct PyClassEmpty {
+        x: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_sorted = py.get_type::<PyClassSorted>();
+        let py_class_empty = py.get_type::<PyClassEmpty>();
+        py_run!(
+            py,
+            py_class py_class_sorted py_class_empty,
+            r#"
+assert py_class.__pyderive_repr_fields__ == ("a", "renamed")
+assert py_class_sorted.__pyderive_repr_fields__ == ("y", "z")
+assert py_class_empty.__pyderive_repr_fields__ == ()
+"#
+        )
+    });
+}
+
+#[test]
+fn test_flatten() {
+    #[derive(PyNew, PyRepr)]
+    #[pyclass(get_all)]
+    #[pyderive(repr_flatten)]
+    #[derive(Clone)]
+    struct Child {
+        a: i64,
+        b: String,
+    }
+
+    #[derive(PyNew, PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        name: String,
+        #[pyderive(flatten)]
+        child: Child,
+    }
+
+    #[derive(PyNew, PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    #[pyderive(str_children = "str")]
+    struct PyClassStr {
+        name: String,
+        #[pyderive(flatten)]
+        child: Child,
+    }
+
+    #[derive(PyNew, PyRepr)]
+    #[pyclass(get_all)]
+    #[pyderive(repr_prefix = "<", repr_suffix = ">")]
+    struct PyClassPy {
+        #[pyderive(flatten)]
+        child: Py<Child>,
+        #[pyderive(default = 1)]
+        z: i64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        let py_class_str = py.get_type::<PyClassStr>();
+        let py_class_py = py.get_type::<PyClassPy>();
+        py_run!(
+            py,
+            py_class py_class_str py_class_py,
+            r#"
+a = py_class("x", a=1, b="y")
+assert repr(a) == "PyClass(name='x', a=1, b='y')"
+assert str(a) == "PyClass(name='x', a=1, b='y')"
+assert py_class.__pyderive_repr_fields__ == ("name", "a", "b")
+
+a = py_class_str("x", a=1, b="y")
+assert repr(a) == "PyClassStr(name='x', a=1, b='y')"
+assert str(a) == "PyClassStr(name=x, a=1, b=y)"
+
+b = py_class_py(a=2, b="z")
+assert repr(b) == "<2, 'z', 1>"
+assert py_class_py.__pyderive_repr_fields__ == ("a", "b", "z")
+"#
+        )
+    });
+}
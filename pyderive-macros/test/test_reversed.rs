@@ -174,3 +174,84 @@ assert tuple(reversed(a)) == (1, py_class_b(1))
         );
     });
 }
+
+#[test]
+fn test_iter_snapshot() {
+    #[derive(PyReversed)]
+    #[pyclass(get_all)]
+    #[pyderive(iter_snapshot)]
+    struct PyClass {
+        fd_name_a: i64,
+        fd_name_b: String,
+        fd_name_c: Vec<i64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1,
+                fd_name_b: "b".to_string(),
+                fd_name_c: vec![3],
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert tuple(reversed(data)) == ([3], 'b', 1)
+
+it = reversed(data)
+assert iter(it) is it
+values = []
+for v in it:
+    values.append(v)
+assert len(values) == 3
+try:
+    next(it)
+except StopIteration:
+    pass
+else:
+    raise AssertionError
+"#
+        )
+    });
+}
+
+#[test]
+fn test_private_field() {
+    #[derive(PyReversed)]
+    #[pyclass]
+    struct PyClass {
+        #[pyo3(get)]
+        public: i64,
+        #[pyderive(iter = true)]
+        private: String,
+        #[allow(dead_code)]
+        hidden: i64,
+        #[pyderive(iter = true)]
+        boxed: Box<i64>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                public: 1,
+                private: "secret".to_string(),
+                hidden: 2,
+                boxed: Box::new(3),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert not hasattr(data, "private")
+assert tuple(reversed(data)) == (3, "secret", 1)
+"#
+        )
+    });
+}
@@ -154,3 +154,222 @@ assert not a >= b
         );
     });
 }
+
+#[test]
+fn test_order_key() {
+    #[derive(PyRichCmp)]
+    #[pyclass]
+    #[pyderive(order_key = key)]
+    #[allow(dead_code)]
+    struct PyClass {
+        fa: i64,
+        fb: String,
+    }
+
+    fn key(this: &PyClass) -> i64 {
+        this.fa
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "b".to_string(),
+            },
+        )
+        .unwrap();
+        let data2 = Py::new(
+            py,
+            PyClass {
+                fa: 2,
+                fb: "a".to_string(),
+            },
+        )
+        .unwrap();
+        let data3 = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "c".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data1 data2 data3, "assert data1 < data2");
+        py_run!(py, data1 data2 data3, "assert data1 <= data2");
+        py_run!(py, data1 data2 data3, "assert not data1 > data2");
+        py_run!(py, data1 data2 data3, "assert not data1 >= data2");
+        py_run!(py, data1 data2 data3, "assert data1 == data3");
+        py_run!(py, data1 data2 data3, "assert data1 != data2");
+    });
+}
+
+#[test]
+fn test_order_reverse() {
+    #[derive(PyRichCmp)]
+    #[pyclass(get_all)]
+    #[pyderive(order_reverse)]
+    #[derive(PartialOrd, PartialEq)]
+    struct PyClass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let data1 = Py::new(py, PyClass { field: 1 }).unwrap();
+        let data2 = Py::new(py, PyClass { field: 2 }).unwrap();
+        let data3 = Py::new(py, PyClass { field: 1 }).unwrap();
+        py_run!(py, data1 data2 data3, "assert data1 > data2");
+        py_run!(py, data1 data2 data3, "assert not data1 < data2");
+        py_run!(py, data1 data2 data3, "assert data1 == data3");
+        py_run!(py, data1 data2 data3, "assert data1 != data2");
+        py_run!(py, data1 data2 data3, "assert [d.field for d in sorted([data1, data2, data3])] == [2, 1, 1]");
+    });
+}
+
+#[test]
+fn test_richcmp_tuple() {
+    #[derive(PyRichCmp)]
+    #[pyclass(get_all)]
+    #[pyderive(richcmp_tuple)]
+    #[derive(PartialOrd, PartialEq)]
+    struct PyClass {
+        fa: i64,
+        fb: String,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fa: 1,
+                fb: "b".to_string(),
+            },
+        )
+        .unwrap();
+        py_run!(py, data, "assert data == (1, 'b')");
+        py_run!(py, data, "assert not data == (1, 'c')");
+        py_run!(py, data, "assert data != (1, 'c')");
+        py_run!(py, data, "assert not data != (1, 'b')");
+        py_run!(py, data, "assert data < (1, 'c')");
+        py_run!(py, data, "assert not data < (1, 'b')");
+        py_run!(py, data, "assert data <= (1, 'b')");
+        py_run!(py, data, "assert not data <= (0, 'b')");
+        py_run!(py, data, "assert data > (1, 'a')");
+        py_run!(py, data, "assert not data > (1, 'b')");
+        py_run!(py, data, "assert data >= (1, 'b')");
+        py_run!(py, data, "assert not data >= (2, 'b')");
+        py_run!(py, data, "assert (1, 'c') > data");
+        py_run!(py, data, "assert data == data");
+        py_run!(py, data, "assert data != (1, 'b', 0)");
+        py_run!(py, data, "assert data != ('a', 'b')");
+        py_run!(
+            py,
+            data,
+            "try: data < (1,)
+except TypeError: pass
+else: raise AssertionError"
+        );
+        py_run!(
+            py,
+            data,
+            "try: data < ('a', 'b')
+except TypeError: pass
+else: raise AssertionError"
+        );
+        py_run!(
+            py,
+            data,
+            "try: data < 1
+except TypeError: pass
+else: raise AssertionError"
+        );
+    });
+}
+
+#[test]
+fn test_nan_inf() {
+    #[derive(PyNew, PyRichCmp)]
+    #[pyclass(get_all)]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import operator
+
+values = [float("nan"), float("inf"), float("-inf"), 0.0]
+for op in [operator.eq, operator.ne, operator.lt, operator.le, operator.gt, operator.ge]:
+    for x in values:
+        for y in values:
+            assert op(py_class(x), py_class(y)) is op(x, y), (op, x, y)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_order_nan_raise() {
+    #[derive(PyNew, PyRichCmp)]
+    #[pyclass(get_all)]
+    #[pyderive(order_nan = "raise")]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+import math
+import operator
+
+values = [float("nan"), float("inf"), float("-inf"), 0.0]
+for op in [operator.lt, operator.le, operator.gt, operator.ge]:
+    for x in values:
+        for y in values:
+            if math.isnan(x) or math.isnan(y):
+                try:
+                    op(py_class(x), py_class(y))
+                except ValueError:
+                    pass
+                else:
+                    raise AssertionError((op, x, y))
+            else:
+                assert op(py_class(x), py_class(y)) is op(x, y), (op, x, y)
+"#
+        );
+    });
+}
+
+#[test]
+fn test_order_nan_raise_eq() {
+    #[derive(PyNew, PyRichCmp)]
+    #[pyclass(get_all)]
+    #[pyderive(order_nan = "raise")]
+    #[derive(PartialEq, PartialOrd)]
+    struct PyClass {
+        f: f64,
+    }
+
+    Python::attach(|py| {
+        let py_class = py.get_type::<PyClass>();
+        py_run!(
+            py,
+            py_class,
+            r#"
+nan = py_class(float("nan"))
+assert not nan == nan
+assert nan != nan
+"#
+        );
+    });
+}
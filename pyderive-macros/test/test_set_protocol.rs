@@ -0,0 +1,73 @@
+use std::collections::{BTreeSet, HashSet};
+
+use pyo3::prelude::*;
+
+use pyderive_macros::*;
+
+#[test]
+fn test_hash_set() {
+    #[derive(PySetProtocol)]
+    #[pyclass]
+    struct PyClass {
+        #[pyderive(set_field)]
+        items: HashSet<i64>,
+        #[allow(dead_code)]
+        other: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                items: HashSet::from([1, 2, 3]),
+                other: 10,
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert len(data) == 3
+assert sorted(data) == [1, 2, 3]
+assert set(data) == {1, 2, 3}
+
+assert 1 in data
+assert 1.0 in data
+assert 10 not in data
+assert "1" not in data
+assert [1] not in data
+"#
+        );
+    });
+}
+
+#[test]
+fn test_btree_set() {
+    #[derive(PySetProtocol)]
+    #[pyclass]
+    struct PyClass {
+        #[pyderive(set_field)]
+        items: BTreeSet<String>,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                items: BTreeSet::from(["b".to_string(), "a".to_string()]),
+            },
+        )
+        .unwrap();
+        pyo3::py_run!(
+            py,
+            data,
+            r#"
+assert len(data) == 2
+assert set(data) == {"a", "b"}
+assert "a" in data
+assert "c" not in data
+"#
+        );
+    });
+}
@@ -86,6 +86,7 @@ fn test_variation() {
 
                 fd_name_pystr_abspath,
             ))]
+        #[allow(clippy::too_many_arguments)]
         fn new(
             fd_name_bool: bool,
             fd_name_str: String,
@@ -366,3 +367,227 @@ fn test_pyderive_false() {
         py_run!(py, data, r#"assert str(data) == "PyClass()""#)
     });
 }
+
+#[test]
+fn test_str_with() {
+    #[derive(PyStr, PyRepr)]
+    #[pyclass(get_all)]
+    #[pyderive(str_with = to_string)]
+    #[derive(Default)]
+    struct PyClass {
+        field: i64,
+    }
+
+    fn to_string(this: &PyClass) -> PyResult<String> {
+        Ok(format!("custom {}", this.field))
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(py, data, r#"assert str(data) == "custom 0""#);
+        py_run!(py, data, r#"assert repr(data) == "PyClass(field=0)""#);
+    });
+}
+
+#[test]
+fn test_repr_name() {
+    #[derive(PyStr)]
+    #[pyclass(get_all, name = "Renamed")]
+    #[pyderive(repr_name = "Overridden")]
+    #[derive(Default)]
+    struct PyClass {
+        fd_name_a: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert type(data).__qualname__ == "Renamed"
+assert str(data) == "Overridden(fd_name_a=0)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_repr_float() {
+    #[derive(PyStr)]
+    #[pyclass(get_all)]
+    struct PyClass {
+        #[pyderive(repr_float = ".3f")]
+        fd_name_a: f64,
+        #[pyderive(repr_float = ".2e")]
+        fd_name_b: f32,
+        fd_name_c: f64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                fd_name_a: 1.0,
+                fd_name_b: 1234.5,
+                fd_name_c: 1.0,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert str(data) == "PyClass(fd_name_a=1.000, fd_name_b=1.23e+03, fd_name_c=1.0)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_str_repr() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    #[pyderive(str = "repr")]
+    #[derive(Default)]
+    struct PyClass {
+        #[pyderive(str = false)]
+        fd_name_a: i64,
+        #[pyderive(str = false)]
+        fd_name_b: String,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass::default()).unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert str(data) == repr(data)
+assert str(data) == "PyClass(fd_name_a=0, fd_name_b='')"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_repr_str_independent() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass]
+    #[allow(dead_code)]
+    struct PyClass {
+        #[pyo3(get)]
+        name: String,
+        // shown in repr only, e.g., a debugging identifier
+        #[pyderive(repr = true, str = false)]
+        id: u64,
+        // shown in str only
+        #[pyderive(repr = false, str = true)]
+        label: String,
+        #[pyo3(get)]
+        #[pyderive(str = false)]
+        hidden_in_str: i64,
+        #[pyo3(get)]
+        #[pyderive(repr = false)]
+        hidden_in_repr: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(
+            py,
+            PyClass {
+                name: "a".to_string(),
+                id: 42,
+                label: "b".to_string(),
+                hidden_in_str: 1,
+                hidden_in_repr: 2,
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data,
+            r#"
+assert repr(data) == "PyClass(name='a', id=42, hidden_in_str=1)"
+assert str(data) == "PyClass(name='a', label='b', hidden_in_repr=2)"
+"#
+        )
+    });
+}
+
+#[test]
+fn test_pyclass_name() {
+    #[derive(PyStr)]
+    #[pyclass(get_all, name = "Other")]
+    struct PyClass {
+        field: i64,
+    }
+
+    Python::attach(|py| {
+        let data = Py::new(py, PyClass { field: 1 }).unwrap();
+        py_run!(py, data, "assert str(data) == 'Other(field=1)'")
+    });
+}
+
+#[test]
+fn test_str_children() {
+    #[derive(PyRepr, PyStr)]
+    #[pyclass(get_all)]
+    #[pyderive(str_children = "str")]
+    struct Child {
+        name: String,
+    }
+
+    #[derive(PyStr)]
+    #[pyclass(get_all)]
+    struct PyClassRepr {
+        child: Py<Child>,
+        name: String,
+        pair: (String, i64),
+    }
+
+    #[derive(PyStr)]
+    #[pyclass(get_all)]
+    #[pyderive(str_children = "str")]
+    struct PyClassStr {
+        child: Py<Child>,
+        name: String,
+        pair: (String, i64),
+    }
+
+    Python::attach(|py| {
+        let child = Py::new(
+            py,
+            Child {
+                name: "c".to_string(),
+            },
+        )
+        .unwrap();
+        let data_repr = Py::new(
+            py,
+            PyClassRepr {
+                child: child.clone_ref(py),
+                name: "a".to_string(),
+                pair: ("b".to_string(), 1),
+            },
+        )
+        .unwrap();
+        let data_str = Py::new(
+            py,
+            PyClassStr {
+                child,
+                name: "a".to_string(),
+                pair: ("b".to_string(), 1),
+            },
+        )
+        .unwrap();
+        py_run!(
+            py,
+            data_repr data_str,
+            r#"
+assert str(data_repr) == "PyClassRepr(child=Child(name='c'), name='a', pair=('b', 1))"
+assert str(data_str) == "PyClassStr(child=Child(name=c), name=a, pair=('b', 1))"
+"#
+        )
+    });
+}
@@ -0,0 +1,51 @@
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_compile_error() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let output = Command::new(cargo)
+        .arg("check")
+        .arg("--quiet")
+        .arg("--message-format=short")
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("test/fixture/ui/Cargo.toml"))
+        .env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("fixture"),
+        )
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+
+    // <file>:<line>:<column> points at the token of the error
+    let expected = [
+        "src/lib.rs:7:29: error: duplicated repr",
+        "src/lib.rs:15:21: error: duplicated repr",
+        "src/lib.rs:23:29: error: kw_only=true conflicts with new=false",
+        "src/lib.rs:30:16: error: kw_only=true conflicts with new=false",
+        "src/lib.rs:38:16: error: default_factory=true requires default=<expr>",
+        "src/lib.rs:46:5: error: positional_only field must precede the other arguments of __new__()",
+        "src/lib.rs:52:12: error: #[pyclass] is not found, place #[derive(..)] before #[pyclass]",
+        "src/lib.rs:59:12: error: frozen_get requires #[pyclass(frozen)]",
+        "src/lib.rs:66:12: error: cache_hash conflicts with Py<T> field, the object may mutate after the hash is cached, exclude it by #[pyderive(compare=false)]",
+        "src/lib.rs:75:12: error: cache_repr conflicts with Py<T> field, the object may mutate after the repr is cached, exclude it by #[pyderive(repr=false)]",
+        "src/lib.rs:84:12: error: dataclass_flatten requires flatten",
+        "src/lib.rs:89:24: error: repr_name conflicts with repr_prefix, the prefix replaces \"<name>(\"",
+        "src/lib.rs:96:24: error: repr_name conflicts with repr_prefix, the prefix replaces \"<name>(\"",
+        // reported once, the rest of the message depends on the compiler
+        "src/lib.rs:101:10: error[E0277]: can't compare `OrderWithoutPartialOrd`",
+    ];
+    let actual = stderr
+        .lines()
+        .filter(|line| line.starts_with("src/"))
+        .collect::<Vec<_>>();
+    assert_eq!(actual.len(), expected.len(), "{stderr}");
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!(actual.starts_with(expected), "{stderr}");
+    }
+}
@@ -1,5 +1,55 @@
 //! Provides derive macros that implements conversion by built-in functions.
 
+/// Derive macro generating an impl of [`__bool__`][py] method that always returns `True`.
+///
+/// Python treats an instance as falsy if `__len__()` returns `0`,
+/// e.g. an instance of the class that derives [`PyLen`](crate::PyLen) without `get` fields.
+/// This makes the instance truthy regardless of `__len__()`.
+///
+/// # Expansion
+///
+/// This implements:
+///
+/// ```
+/// # use pyo3::prelude::*;
+/// # #[pyclass]
+/// # struct PyClass {}
+/// #[pymethods]
+/// impl PyClass {
+///     fn __bool__(&self) -> bool {
+///         true
+///     }
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+///
+/// use pyderive::{PyLen, PyNew};
+/// use pyderive::convert::PyAlwaysTrue;
+///
+/// #[derive(PyNew, PyLen, PyAlwaysTrue)]
+/// #[pyclass]
+/// struct PyClass {
+///     field: i64
+/// }
+///
+/// let test = "
+/// a = PyClass(0)
+/// assert len(a) == 0
+/// assert bool(a) is True
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [py]: https://docs.python.org/3/reference/datamodel.html#object.__bool__
+pub use pyderive_macros::PyAlwaysTrue;
 /// Derive macro generating an impl of [`__bool__`][py] method by [`Into<bool>`] trait.
 ///
 /// # Expansion
@@ -117,6 +167,71 @@ pub use pyderive_macros::PyBool;
 ///
 /// [py]: https://docs.python.org/3/reference/datamodel.html#object.__bytes__
 pub use pyderive_macros::PyBytes;
+/// Derive macro generating an impl of [`__bytes__`][py] method by `as_bytes()` method.
+///
+/// This is a variant of [`PyBytes`] for a type computing bytes lazily,
+/// which calls `fn as_bytes(&self)` instead of [`Into<Cow<[u8]>>`] trait.
+/// The method can be an inherent method or a trait method in scope,
+/// and its return type must implement [`Into<Cow<[u8]>>`], e.g., `Cow<[u8]>`, `&[u8]` or `Vec<u8>`.
+///
+/// # Expansion
+///
+/// This implements, for example:
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use pyo3::prelude::*;
+/// # #[pyclass]
+/// # struct PyClass {}
+/// # impl PyClass {
+/// #     fn as_bytes(&self) -> Cow<[u8]> {
+/// #         vec![].into()
+/// #     }
+/// # }
+/// #[pymethods]
+/// impl PyClass {
+///     fn __bytes__(&self) -> Cow<[u8]> {
+///         Into::into(self.as_bytes())
+///     }
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use pyo3::{prelude::*, py_run};
+///
+/// use pyderive::PyNew;
+/// use pyderive::convert::PyBytesFrom;
+///
+/// #[derive(PyNew, PyBytesFrom)]
+/// #[pyclass]
+/// struct PyClass {
+///     text: String,
+/// }
+///
+/// impl PyClass {
+///     fn as_bytes(&self) -> Cow<[u8]> {
+///         self.text.to_uppercase().into_bytes().into()
+///     }
+/// }
+///
+/// let test = r#"
+/// actual = bytes(PyClass("abc"))
+/// assert isinstance(actual, bytes)
+/// assert actual == b'ABC'
+/// "#;
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [py]: https://docs.python.org/3/reference/datamodel.html#object.__bytes__
+pub use pyderive_macros::PyBytesFrom;
 /// Derive macro generating an impl of [`__complex__`][py] method by [`Into<num_complex::Complex64>`] trait.
 ///
 /// # Expansion
@@ -289,6 +404,16 @@ pub use pyderive_macros::PyFloat;
 pub use pyderive_macros::PyIndex;
 /// Derive macro generating an impl of [`__int__`][py] method by [`Into<i64>`] trait.
 ///
+/// If `#[pyderive(int="trunc")]` or `#[pyderive(int="round")]` is given to the struct,
+/// the method converts the value by [`Into<f64>`] trait instead,
+/// and then truncates it like `int()` or rounds it like `round()` of Python float,
+/// which rounds half to even, e.g. `2.5` to `2` and `3.5` to `4`.
+/// It raises `ValueError` on NaN and `OverflowError` on infinity and a value out of the range of [`i64`].
+/// It is useful for a fixed-point number whose `__index__()` must be exact.
+///
+/// Notes, [`f64`] represents an integer exactly up to 2<sup>53</sup> in magnitude,
+/// thus a value beyond it loses precision before rounding.
+///
 /// # Expansion
 ///
 /// This implements, for example:
@@ -341,5 +466,38 @@ pub use pyderive_macros::PyIndex;
 /// });
 /// ```
 ///
+/// Rounding a fixed-point number:
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+///
+/// use pyderive::PyNew;
+/// use pyderive::convert::PyInt;
+///
+/// #[derive(PyNew, PyInt)]
+/// #[pyderive(int="round")]
+/// #[pyclass]
+/// struct Decimal {
+///     hundredths: i64
+/// }
+///
+/// impl From<&Decimal> for f64 {
+///     fn from(value: &Decimal) -> f64 {
+///         value.hundredths as f64 / 100.0
+///     }
+/// }
+///
+/// let test = "
+/// assert int(Decimal(275)) == 3
+/// assert int(Decimal(-275)) == -3
+/// assert int(Decimal(250)) == 2
+/// ";
+///
+/// Python::attach(|py| {
+///     let Decimal = py.get_type::<Decimal>();
+///     py_run!(py, Decimal, test)
+/// });
+/// ```
+///
 /// [py]: https://docs.python.org/3/reference/datamodel.html#object.__int__
 pub use pyderive_macros::PyInt;
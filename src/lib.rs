@@ -5,8 +5,16 @@
 //! The field attribute `#[pyderive(..)]` helps to customize implementations,
 //! like [`dataclasses.field()`][dataclasses-field] of Python.
 //!
-//! It requires to enable `multiple-pymethods` feature of PyO3
-//! because the derive macros that this library provides may implement multiple `#[pymethods]`.
+//! It requires to enable `multiple-pymethods` feature of pyderive
+//! because the derive macros that this library provides may implement multiple `#[pymethods]`,
+//! the feature enables the `multiple-pymethods` feature of PyO3 also.
+//! Without the feature, every derive macro that implements `#[pymethods]` reports
+//! a compile error that tells to enable the `multiple-pymethods` feature.
+//!
+//! ```toml
+//! [dependencies]
+//! pyderive = { version = "0.9", features = ["multiple-pymethods"] }
+//! ```
 //!
 //! [dataclasses-field]: https://docs.python.org/3/library/dataclasses.html#dataclasses.field
 //! [PyO3]: https://github.com/PyO3/pyo3
@@ -14,7 +22,7 @@
 //! # Example
 //!
 //! ```
-//! // Enable `multiple-pymethods` feature of PyO3
+//! // Enable `multiple-pymethods` feature of pyderive
 //! use pyo3::prelude::*;
 //! use pyderive::*;
 //!
@@ -57,6 +65,22 @@
 //!
 //! Some macros change implementations depend on `#[pyclass(..)]` and `#[pyo3(..)]` arguments,
 //! hence it should place `#[derive(PyNew)]` etc. before `#[pyclass(..)]` and `#[pyo3(..)]`.
+//! The order cannot be relaxed: `#[pyclass]` placed above `#[derive(..)]` expands first,
+//! and it removes itself and the `#[pyo3(..)]` field attributes before the derive macros run,
+//! so the arguments are lost rather than read in a different order.
+//! Thus the macros that read them emit a compile error instead of silently ignoring the arguments:
+//!
+//! ```compile_fail
+//! # use pyo3::prelude::*;
+//! # use pyderive::*;
+//! #[pyclass(get_all)]
+//! #[derive(PyRepr)]
+//! struct MyClass {
+//!     field: i64,
+//! }
+//! ```
+//!
+//! Any path to the attribute, e.g. `#[pyo3::pyclass(..)]`, is also recognized.
 //!
 //! We list the default implementations that the macros generate.
 //!
@@ -69,25 +93,49 @@
 //! | [`PyIter`]            | `__iter__()` returns an iterator of `get` fields     |
 //! | [`PyReversed`]        | `__reversed__()` returns an iterator of `get` fields |
 //! | [`PyLen`]             | `__len__()` returns number of `get` fields           |
+//! | [`PyGetItem`]         | `__getitem__()` by index or slice of `get` fields    |
+//! | [`PyMapping`]         | `__getitem__()` etc. by name of `get`/`set` fields   |
+//! | [`PyContainsKey`]     | `__contains__()` by name of `get` fields             |
+//! | [`PySetProtocol`]     | `__iter__()` etc. over the `set_field` field         |
+//! | [`PyName`]            | `name` property of the `is_name` field               |
+//! | [`PyFormat`]          | `__format__()` of the `format_field` field           |
+//! | [`PyFromDict`]        | `from_dict()` class method by `__new__()`            |
+//! | [`PyIntEnum`]         | `IntEnum`-like methods of a fieldless enum           |
+//! | [`PyCopyReplace`]     | `__replace__()` for `copy.replace()` (Python 3.13)   |
 //! | [`PyDataclassFields`] | `__dataclass_fields__` class attr. with all fields   |
+//! | [`PyClassVar`]        | class attr. of the `classvar` fields                 |
+//! | [`PyDoc`]             | `__doc__` class attr. with the `doc` fields          |
 //!
-//! Notes, methods implemented by [`PyRepr`] and [`PyStr`] are recursively calls `repr()` or `str()` like a Python `dataclass`.
+//! Notes, methods implemented by [`PyRepr`] and [`PyStr`] are recursively calls `repr()` of the fields like a Python `dataclass`
+//! (see `#[pyderive(str_children="str")]` to call `str()` instead in [`PyStr`]).
+//! The trait [`FieldRepr`] provides the default field rendering, Python `repr()`, for hand-written `#[pymethods]`.
+//! The rendering does not depend on the locale,
+//! numbers are rendered by Python `repr()` and Rust formatting, which ignore it,
+//! e.g. `1234.5` is always `1234.5` even if the decimal separator of the locale is a comma.
 //!
 //! We call the field is *`get` (or `set`) field*
 //! if the field has a `#[pyclass/pyo3(get)]` (or `#[pyclass/pyo3(set)]`) attribute or
 //! its struct has a `#[pyclass/pyo3(get_all)]` (or `#[pyclass/pyo3(set_all)]`) attribute.
+//! If the struct has a `#[pyclass(frozen)]` attribute and `#[pyderive(frozen_get)]`,
+//! [`PyRepr`], [`PyStr`], [`PyIter`], [`PyReversed`] and [`PyLen`] treat all fields as `get` fields
+//! (the fields are read-only and commonly exposed),
+//! use `#[pyderive(repr=false)]` etc. to exclude the field.
 //!
 //! The following derive macros depend on traits.
 //!
-//! | Derive Macro    | Derives                                                                                            |
-//! | --------------- | -------------------------------------------------------------------------------------------------- |
-//! | [`PyEq`]        | `__eq__()` and `__ne__()`, depends on [`PartialEq`]                                                |
-//! | [`PyOrd`]       | `__lt__()`, `__le__()`, `__gt__()` and `__ge__()`, depend on [`PartialOrd`]                        |
-//! | [`PyRichCmp`]   | `==`, `!=`, `>`, `>=`, `<` and `<=` by `__richcmp__()`, depend on [`PartialEq`] and [`PartialOrd`] |
-//! | [`PyNumeric`]   | Numeric op traits (`__add__()` etc.)                                                               |
-//! | [`PyBitwise`]   | Bitwise op traits (`__and__()` etc.)                                                               |
+//! | Derive Macro     | Derives                                                                                                         |
+//! | ---------------- | --------------------------------------------------------------------------------------------------------------- |
+//! | [`PyEq`]         | `__eq__()` and `__ne__()`, depends on [`PartialEq`]                                                             |
+//! | [`PyOrd`]        | `__lt__()`, `__le__()`, `__gt__()` and `__ge__()`, depend on [`PartialOrd`]                                     |
+//! | [`PyRichCmp`]    | `==`, `!=`, `>`, `>=`, `<` and `<=` by `__richcmp__()`, depend on [`PartialEq`] and [`PartialOrd`]              |
+//! | [`PyFieldHash`]  | `__hash__()` of `compare` fields, depends on [`Hash`] of the fields                                             |
+//! | [`PyComparable`] | `__eq__()`, `__ne__()` and `__hash__()` of `compare` fields, depend on [`PartialEq`] and [`Hash`] of the fields |
+//! | [`PyFromStr`]    | `from_str()` static method, depends on [`FromStr`][std::str::FromStr]                                           |
+//! | [`PyNumeric`]    | Numeric op traits (`__add__()` etc.)                                                                            |
+//! | [`PyBitwise`]    | Bitwise op traits (`__and__()` etc.)                                                                            |
 //!
-//! Notes, implementation of [`PyEq`] and [`PyOrd`] does not use `__richcmp__()`.
+//! Notes, PyO3 merges `__eq__()` and `__lt__()` etc. into one `__richcmp__()` slot,
+//! thus [`PyEq`] and [`PyOrd`] cannot be combined, derive [`PyRichCmp`] alone for both equality and ordering.
 //!
 //! Module [`pyderive::ops`](mod@ops) and [`pyderive::convert`](mod@convert) provides
 //! derive macros that implement individual method that enumerating numeric type (`__add__()` etc.) and
@@ -139,21 +187,84 @@
 //! and it evaluates to the right-hand as `true`
 //! except `default` , for example,
 //! `#[pyderive(repr)]` is equivalent to `#[pyderive(repr=true)]`.
+//! Each attribute is allowed at most once per field,
+//! and the compile error points at the duplicated one:
+//!
+//! ```compile_fail
+//! # use pyo3::prelude::*;
+//! use pyderive::*;
+//!
+//! #[derive(PyRepr)]
+//! #[pyclass]
+//! struct MyClass {
+//!     #[pyderive(repr=true, repr=false)]
+//!     field: i64,
+//! }
+//! ```
 //!
 //! - `#[pyderive(repr=<bool>)]`
 //!
 //!   If `repr=true`,
 //!   the field is included in the string that the `__repr__()` method returns;
 //!   if `repr=false`, it isn't.
+//!   The method reads the struct field directly (not through a Python getter),
+//!   thus `repr=true` works on a field without `get`.
+//!   The string is still rendered by Python `repr()`,
+//!   so the escaping of quotes and control characters is identical to Python.
 //!
 //!   The derive macro [`PyDataclassFields`] reads this attribute also,
 //!   see [`PyDataclassFields`] for detail.
 //!
+//! - `#[pyderive(repr="debug")]`
+//!
+//!   It works as `repr=true` does,
+//!   but the field is rendered by Rust [`Debug`] (`format!("{:?}", field)`) inserted verbatim
+//!   instead of Python `repr()`, e.g., an opaque enum without [`IntoPyObject`][pyo3_IntoPyObject].
+//!   [`PyStr`] also renders the field in this way if it includes the field.
+//!
+//! - `#[pyderive(repr="name")]`
+//!
+//!   It works as `repr=true` does,
+//!   but the field is rendered by its `__name__`, e.g. `callback=on_click`
+//!   instead of `callback=<function on_click at 0x...>`, for a callable field such as `Py<PyAny>`.
+//!   The object without `__name__`, e.g. `None`, is rendered by `repr()`.
+//!   [`PyStr`] also renders the field in this way if it includes the field.
+//!
 //! - `#[pyderive(str=<bool>)]`
 //!
 //!   If `str=true`,
 //!   the field is included in the string that the `__str__()` method returns;
 //!   if `str=false`, it isn't.
+//!   It is independent of `repr=<bool>`,
+//!   e.g., `#[pyderive(repr=true, str=false)]` shows the field in `repr()` only.
+//!
+//! - `#[pyderive(repr_float="<spec>")]`
+//!
+//!   The `__repr__()` and `__str__()` methods render the field by `format(field, "<spec>")`
+//!   with the Python [format spec][format_spec], e.g. `1.0` renders as `1.000` under `".3f"`,
+//!   instead of `repr(field)`.
+//!   Note, the `"n"` presentation type of the spec is the only locale-dependent format.
+//!   It supports `f64` and `f32` fields only, and it is a compile error on other fields:
+//!
+//!   ```compile_fail
+//!   # use pyderive::*;
+//!   # use pyo3::prelude::*;
+//!   #
+//!   #[derive(PyRepr)]
+//!   #[pyclass(get_all)]
+//!   struct PyClass {
+//!     #[pyderive(repr_float=".3f")]
+//!     field: i64,
+//!   }
+//!   ```
+//!
+//! - `#[pyderive(path_str=<bool>)]`
+//!
+//!   The `__repr__()` and `__str__()` methods render a path field,
+//!   `PathBuf`, `Box<Path>`, `Cow<Path>` or `&Path`, as a quoted string, e.g. `'/tmp/a.txt'`,
+//!   instead of `repr(pathlib.Path(..))`, e.g. `PosixPath('/tmp/a.txt')`.
+//!   Use `path_str=false` to opt out,
+//!   and `path_str=true` on the other fields is a compile error.
 //!
 //! - `#[pyderive(new=<bool>)]`
 //!
@@ -183,6 +294,31 @@
 //!
 //!   We note that this internally produces `#[pyo3(signature = ..)]` attribute.
 //!
+//!   The `__new__()` method by [`PyNew`] binds `py: Python<'_>` (unless a field is named `py`)
+//!   and returns `PyResult<Self>`, so `<expr>` may use `py` and `?`,
+//!   e.g., `#[pyderive(default = Py::new(py, Child::default())?)]` on a `Py<Child>` field.
+//!   Since PyO3 evaluates the default of the signature without the bindings,
+//!   such `<expr>` is evaluated in the body of `__new__()` instead,
+//!   that is, the default in the signature is `None`, but passing `None` explicitly is not the default,
+//!   e.g. it is `None` of an `Option<T>` field (and `TypeError` of the other fields).
+//!   Notes, the other derive macros that read `<expr>`, e.g., [`PyDataclassFields`],
+//!   do not guarantee the bindings.
+//!
+//!   The `<expr>` is typed by the field (or the `try_from` type),
+//!   so that a mismatched default is a compile error pointing at the `<expr>`:
+//!
+//!   ```compile_fail
+//!   # use pyderive::*;
+//!   # use pyo3::prelude::*;
+//!   #
+//!   #[derive(PyNew)]
+//!   #[pyclass]
+//!   struct PyClass {
+//!     #[pyderive(default = "x")]
+//!     field: i64,
+//!   }
+//!   ```
+//!
 //!     1. No `#[pyderive(..)]` (for example, just `field: i64`)
 //!
 //!        Pseudocode:
@@ -241,24 +377,164 @@
 //!   The derive macro [`PyDataclassFields`] and [`PyNamedTupleFieldDefaults`] read this attribute also,
 //!   see [`PyDataclassFields`] and [`PyNamedTupleFieldDefaults`] for detail.
 //!
+//! - `#[pyderive(new_default=<bool>)]`
+//!
+//!   If `new_default=true`,
+//!   it is a shorthand of `#[pyderive(default=<Ty as Default>::default())]`
+//!   where `Ty` is the type of the field,
+//!   that is, the field is included to the arguments with [`Default::default()`] as its default value.
+//!   It is a compile error to mark the field by both `new_default=true` and `default=<expr>`.
+//!   Notes, `new_default=false` has no effect.
+//!
+//! - `#[pyderive(default_once=<expr>)]`
+//!
+//!   It works as `#[pyderive(default=<expr>)]` does,
+//!   but `<expr>` is evaluated only once, at the first `__new__()` call without the argument,
+//!   by a [`OnceLock`][std::sync::OnceLock], and the subsequent calls get its [`Clone::clone()`].
+//!   It fits an expensive default value, and the field type should implement [`Clone`] (and [`Send`] + [`Sync`]).
+//!   In contrast, `default=<expr>` evaluates `<expr>` on every `__new__()` call,
+//!   and `default_factory=true` evaluates it on every `Field.default_factory` call of [`PyDataclassFields`].
+//!   It is a compile error to mark the field by both `default_once=<expr>` and `default=<expr>` (or `new_default=true`).
+//!
+//! - `#[pyderive(default_py="<expr>")]`
+//!
+//!   It works as `#[pyderive(default=<expr>)]` does,
+//!   but `<expr>` is a Python expression evaluated on every `__new__()` call without the argument,
+//!   like `field(default_factory=..)` of a dataclass, e.g., `#[pyderive(default_py="datetime.datetime.now()")]`.
+//!   The leading name of `<expr>` is imported unless it is a builtin, e.g. `len`,
+//!   and so are the submodules of the leading dotted name that the parent does not import, e.g. `xml.dom.minidom`;
+//!   a failed import raises `ModuleNotFoundError` on the evaluation.
+//!   and the result is extracted to the type of the field by [`FromPyObject`][pyo3_FromPyObject].
+//!   The derive macros [`PyDataclassFields`] and [`PyNamedTupleFieldDefaults`] evaluate it once when the class attribute is created.
+//!   It is a compile error to mark the field by both `default_py="<expr>"` and `default=<expr>`
+//!   (or `new_default=true`, `default_once=<expr>` and `default_factory=true`).
+//!
+//! - `#[pyderive(compute=<closure>)]`
+//!
+//!   The field is computed from the other fields by `<closure>` of `Fn(&Self) -> Ty`,
+//!   like a derived attribute, e.g., `#[pyderive(compute = |this| this.a + this.b)]`.
+//!   It implies `new=false`, and it is a compile error to mark the field by `new=true` also.
+//!   The `__new__()` method by [`PyNew`] sets the field to [`Default::default()`]
+//!   (or `<expr>` of `#[pyderive(default=<expr>)]`) first,
+//!   and then calls `<closure>` after the other fields are set, in the order of declaration.
+//!
 //! - `#[pyderive(default_factory=true)]`
 //!
 //!   If `default_factory=true`,
 //!   let the `default_factory` attribute of `Field`obj be `lambda: <expr>`,
 //!   and let the `default` attribute be [`dataclasses.MISSING`][MISSING],
 //!   where `<expr>` is given by `#[pyderive(default=<expr>)]`.
-//!   Notes, `default_factory=false` has no effect,
-//!   If the field is not marked by `#[pyderive(default=<expr>)]`, this ignores.
+//!   The `<expr>` is typed by the field as [`PyNew`] does, e.g. `default=Vec::new()` works as is.
+//!   Notes, `default_factory=false` has no effect.
+//!   It is a compile error if the field is not marked by `#[pyderive(default=<expr>)]` (or `new_default`, `default_once`),
+//!   and the error points at the `default_factory` token:
+//!
+//!   ```compile_fail
+//!   # use pyderive::*;
+//!   # use pyo3::prelude::*;
+//!   #
+//!   #[derive(PyDataclassFields)]
+//!   #[pyclass]
+//!   struct PyClass {
+//!     #[pyderive(default_factory=true)]
+//!     field: i64,
+//!   }
+//!   ```
 //!    
 //!   See [`PyDataclassFields`] for detail.
 //!
+//! - `#[pyderive(try_from=<type>)]`
+//!
+//!   The `__new__()` method by [`PyNew`] takes the argument of `<type>`,
+//!   and converts it into the field by [`TryFrom<type>`][TryFrom] of the field type,
+//!   e.g. `#[pyderive(try_from=u32)]` on a [`NonZeroU32`][std::num::NonZeroU32] field.
+//!   It raises `ValueError` with the [`Display`][std::fmt::Display] of the error if the conversion fails
+//!   instead of panicking.
+//!   Notes, `default=<expr>` of the field should be a value of `<type>`.
+//!
+//! - `#[pyderive(init_var=<bool>)]`
+//!
+//!   If `init_var=true`, the field is an init-only variable like [`InitVar`][InitVar] of `dataclass`.
+//!   The field should be `PhantomData<T>` so that nothing is stored,
+//!   the `__new__()` method by [`PyNew`] takes the argument of `T`
+//!   and passes it to `fn __post_init__(&mut self, ..) -> PyResult<()>` which we should implement,
+//!   after the other fields are initialized.
+//!   The arguments of `__post_init__()` are the `init_var` fields in the order of declaration.
+//!   It is a compile error to mark non-`PhantomData` field or `new=false` field.
+//!   Notes, `#[pyclass(get_all)]` does not work with `PhantomData` fields, use `#[pyo3(get)]` on each field instead.
+//!
+//! - `#[pyderive(flatten=<bool>)]`
+//!
+//!   If `flatten=true`, like `#[serde(flatten)]`,
+//!   the `__new__()` method by [`PyNew`] takes the arguments of the `__new__()` of the field class
+//!   by `**kwargs` instead of the field itself, and constructs the field by them,
+//!   e.g. `Parent(name, x=1)` for a `Child` field that takes `x`.
+//!   The field should be a `#[pyclass]` struct with `__new__()`, e.g. by [`PyNew`],
+//!   and be a `Py<Child>` or a `Child` that implements [`Clone`].
+//!   The arguments are passed by keyword only, thus a positional-only argument of the child is not supported,
+//!   and an unknown keyword argument raises `TypeError` of the `__new__()` of the child.
+//!   At most one field can be marked,
+//!   and it conflicts with `new`, `default`, `try_from`, `init_var`, `compute` and `#[pyderive(signature=..)]`.
+//!
+//!   The derive macros [`PyRepr`] and [`PyStr`] render the fields of the field inline,
+//!   e.g. `Parent(name='a', x=1)` instead of `Parent(name='a', child=Child(x=1))`,
+//!   as [`PyRepr`] of the class of the field renders them (by `str()` in [`PyStr`] of `str_children="str"`),
+//!   thus the class requires [`PyRepr`] and `#[pyderive(repr_flatten)]`.
+//!
+//!   The derive macro [`PyDataclassFields`] treats the field as a single field as usual,
+//!   e.g. `dataclasses.asdict()` returns `{'name': 'a', 'child': {'x': 1}}`.
+//!
+//! - `#[pyderive(dataclass_flatten=<bool>)]`
+//!
+//!   If `dataclass_flatten=true` on a `flatten` field,
+//!   the derive macro [`PyDataclassFields`] places the `__dataclass_fields__` of the class of the field
+//!   in place of the field, thus the class requires [`PyDataclassFields`],
+//!   and defines `__getattr__()` forwarding the names of them to the field,
+//!   so that `dataclasses.asdict()` returns the inlined keys, e.g. `{'name': 'a', 'x': 1}`.
+//!   A `Child` field requires [`Clone`] and [`IntoPyObject`][pyo3_IntoPyObject] (e.g. by `#[pyclass]`),
+//!   since `__getattr__()` converts a clone of it to read the attribute;
+//!   a `Py<Child>` field does not.
+//!   It is a compile error to mark non-`flatten` field,
+//!   and it conflicts with the other `__getattr__()` of the struct.
+//!   Notes, `dataclass_flatten=false` has no effect.
+//!
+//!   The derive macro [`PyCopyReplace`] passes the current values of the `__new__()` arguments
+//!   of the field class by the keyword arguments, thus the class requires [`PyCopyReplace`].
+//!
+//! - `#[pyderive(positional_only=<bool>)]`
+//!
+//!   If `positional_only=true`,
+//!   the field is a positional-only argument of `__new__()`,
+//!   that is, the derive macro [`PyNew`] places `/` after the fields in the signature,
+//!   and passing it by keyword raises `TypeError`.
+//!   The fields must precede the other arguments,
+//!   and it conflicts with `new=false` and `kw_only=true`.
+//!
+//!   ```compile_fail
+//!   # use pyo3::prelude::*;
+//!   # use pyderive::*;
+//!   #[derive(PyNew)]
+//!   #[pyclass]
+//!   struct PyClass {
+//!       a: i64,
+//!       // positional_only field after a non-positional_only one
+//!       #[pyderive(positional_only)]
+//!       b: i64,
+//!   }
+//!   ```
+//!
 //! - `#[pyderive(kw_only=true)]`
 //!
 //!   If `kw_only=true`,
 //!   the following fields are keyword only arguments in the `__new__()` method,
 //!   like [`*`][keyword-only-arguments] and [`dataclasses.KW_ONLY`][KW_ONLY].
 //!   Note, `kw_only=false` has no effect.
+//!   The field marks the position of `*` rather than a single keyword only argument,
+//!   thus the signature is always valid and the arguments keep the order of declaration,
+//!   e.g. all arguments are keyword only if the first field is marked.
+//!   It is a compile error to mark a `new=false` field, which is not an argument.
 //!
+//!   The derive macro [`PyMatchArgs`] excludes the keyword only fields from `__match_args__` as default.
 //!   The derive macro [`PyDataclassFields`] reads this attribute also,
 //!   see [`PyDataclassFields`] for detail.
 //!
@@ -267,6 +543,8 @@
 //!   If `match_args=true`,
 //!   the field is included in the `__match_args__` class attribute;
 //!   if `match_args=false`, it isn't.
+//!   As default, [`PyMatchArgs`] includes the `get` fields that are positional arguments of `__new__()`,
+//!   and it is a compile error to mark a `new=false` field by `match_args=true`.
 //!
 //!   We note that, as far as I know,
 //!   the field must be accessible on the pattern matching.
@@ -279,6 +557,22 @@
 //!   If `iter=true`,
 //!   the field is included in the iterator that `__iter__()` and `__reversed__()` return;
 //!   if `iter=false`, it isn't.
+//!   The field is read directly, thus `iter=true` includes a private field without a getter.
+//!
+//! - `#[pyderive(iter_order=<usize>)]`
+//!
+//!   The iterator that `__iter__()` returns yields the fields marked by `iter_order`
+//!   first in ascending order of `<usize>`,
+//!   and then yields the others in the order of declaration
+//!   (`__reversed__()` yields in reverse).
+//!
+//! - `#[pyderive(children=<bool>)]`
+//!
+//!   If `children=true`,
+//!   the `__iter__()` of [`PyIter`] iterates over the elements of the field
+//!   instead of the field values, e.g., `for child in node` of a tree node
+//!   with a `Vec<Py<Self>>` field.
+//!   At most one field can be marked, and it conflicts with `#[pyderive(iter="..")]`.
 //!
 //! - `#[pyderive(len=<bool>)]`
 //!
@@ -286,6 +580,33 @@
 //!   the field is counted by the `__len__()`;
 //!   if `len=false`, it isn't.
 //!
+//! - `#[pyderive(len_field=<bool>)]`
+//!
+//!   If `len_field=true`, the `__len__()` returns the Python `len()` of the field
+//!   instead of the number of fields, see [`PyLen`] for detail.
+//!
+//! - `#[pyderive(format_field=<bool>)]`
+//!
+//!   The derive macro [`PyFormat`] forwards the format spec to the `__format__()` of the `format_field=true` field,
+//!   see [`PyFormat`] for detail.
+//!
+//! - `#[pyderive(set=false)]`
+//!
+//!   If `set=false`, the derive macros treat the field as a not `set` field,
+//!   e.g. `obj["field"] = ..` by [`PyMapping`] raises `KeyError`,
+//!   and [`PyRepr`] etc. include it only if it is a `get` field.
+//!   Notes, `set=true` has no effect.
+//!
+//!   PyO3 has no way to exclude a field from `#[pyclass(set_all)]`,
+//!   thus the setter of `#[pyclass(set_all)]` remains and `obj.field = ..` still works.
+//!   For a read-only attribute, use `#[pyclass(get_all)]` and mark the other fields by `#[pyo3(set)]` instead,
+//!   the derive macros read `#[pyo3(get)]` and `#[pyo3(set)]` of each field.
+//!
+//! - `#[pyderive(set_field=<bool>)]`
+//!
+//!   The derive macro [`PySetProtocol`] implements the methods over the `set_field=true` field,
+//!   see [`PySetProtocol`] for detail.
+//!
 //! - `#[pyderive(dataclass_field=false)]`
 //!
 //!   If `dataclass_field=false`,
@@ -294,40 +615,362 @@
 //!
 //!   See [`PyDataclassFields`] for detail.
 //!
+//! - `#[pyderive(compare=<bool>)]`
+//!
+//!   If `compare=false`,
+//!   the field is excluded from the hash that the `__hash__()` method by [`PyFieldHash`] (and [`PyDataclass`]) returns
+//!   (and from the comparison by [`PyEq`] of `#[pyderive(eq="fields")]`);
+//!   if `compare=true`, it isn't.
+//!
+//! - `#[pyderive(cache_hash=<bool>)]`
+//!
+//!   If `cache_hash=true`, the `OnceLock<u64>` field stores the hash computed by [`PyFieldHash`] (and [`PyDataclass`]),
+//!   it requires `#[pyclass(frozen)]` and no `Py<T>` field in the hash.
+//!   The field is excluded from the other derive macros as default, see [`PyFieldHash`] for detail.
+//!
+//! - `#[pyderive(cache_repr=<bool>)]`
+//!
+//!   If `cache_repr=true`, the `OnceLock<String>` field stores the string computed by [`PyRepr`],
+//!   it requires `#[pyclass(frozen)]` and no `Py<T>` field in the string.
+//!   The field is excluded from the other derive macros as default, see [`PyRepr`] for detail.
+//!
+//! - `#[pyderive(classvar=<expr>)]`
+//!
+//!   The derive macro [`PyClassVar`] implements the class attribute of the value `<expr>`,
+//!   like a `ClassVar` of a dataclass.
+//!   The field is initialized by `<expr>` and excluded from the other derive macros as default
+//!   except [`PyDataclassFields`], see [`PyClassVar`] for detail.
+//!
+//! - `#[pyderive(is_name=<bool>)]`
+//!
+//!   If `is_name=true`,
+//!   the `name` property by [`PyName`] returns the field.
+//!   Exactly one field should be marked.
+//!
 //! - `#[pyderive(annotation=<str>)]`
 //!
 //!   The derive macro [`PyDataclassFields`] reads this attribute,
 //!   see [`PyDataclassFields`] for detail.
 //!
+//! - `#[pyderive(doc="<doc>")]`
+//!
+//!   The derive macro [`PyDoc`] lists the field with `<doc>` in the class `__doc__`,
+//!   and [`PyDataclassFields`] stores `<doc>` in the `metadata` of the field under the `doc` key.
+//!
+//! # Customize Implementation by Struct Attributes
+//!
+//! The struct attribute `#[pyderive(..)]` is used to customize implementations
+//! for the whole struct.
+//!
+//! ```
+//! # use pyo3::prelude::*;
+//! use pyderive::*;
+//!
+//! #[derive(PyOrd)]
+//! #[pyclass]
+//! #[pyderive(order_key=key)]
+//! struct MyClass {
+//!     string: String,
+//!     integer: i64,
+//! }
+//!
+//! fn key(this: &MyClass) -> i64 {
+//!     this.integer
+//! }
+//! ```
+//!
+//! - `#[pyderive(by_value=<bool>)]`
+//!
+//!   If `by_value=true`, the operator derive macros in [`ops`], e.g. [`PyAdd`](ops::PyAdd),
+//!   call the traits implemented for `Self` by cloning the operands, instead of for `&Self`,
+//!   see [`ops`] for detail.
+//!   Notes, `by_value=false` has no effect.
+//!
+//! - `#[pyderive(eq="fields")]`
+//!
+//!   The derive macro [`PyEq`] compares the `compare` fields one by one instead of [`PartialEq`] of the struct,
+//!   where `Py<T>` and `Option<Py<T>>` fields are compared by Python `==`,
+//!   see [`PyEq`] for detail.
+//!
+//! - `#[pyderive(eq_subclass=<bool>)]`
+//!
+//!   If `eq_subclass=true`,
+//!   the derive macros [`PyEq`] and [`PyDataclass`] compare the instance with an instance of a Python subclass also
+//!   (as far as it is extractable to the struct).
+//!   As default, it returns `NotImplemented` unless `type(other) is type(self)` like `dataclass`.
+//!   Notes, `eq_subclass=false` has no effect.
+//!
+//! - `#[pyderive(eq_identity=<bool>)]`
+//!
+//!   If `eq_identity=true`,
+//!   the derive macro [`PyEq`] returns `True` for the same object before comparing the fields,
+//!   see [`PyEq`] for detail.
+//!   Notes, `eq_identity=false` has no effect.
+//!
+//! - `#[pyderive(frozen_get=<bool>)]`
+//!
+//!   If `frozen_get=true`,
+//!   the derive macros [`PyRepr`], [`PyStr`], [`PyIter`], [`PyReversed`] and [`PyLen`]
+//!   treat all fields of the `#[pyclass(frozen)]` struct as `get` fields,
+//!   except the fields not a part of the instance in Python, e.g. `cache_hash` fields.
+//!   The field attribute, e.g. `#[pyderive(repr=false)]`, still excludes the field.
+//!   It requires `#[pyclass(frozen)]`.
+//!   Notes, `frozen_get=false` has no effect.
+//!
+//! - `#[pyderive(repr_flatten=<bool>)]`
+//!
+//!   If `repr_flatten=true`,
+//!   the derive macro [`PyRepr`] implements the hidden helper that renders the fields,
+//!   so that [`PyRepr`] and [`PyStr`] of another class render them inline for a `flatten` field of the class,
+//!   see `#[pyderive(flatten)]` for detail.
+//!   Notes, `repr_flatten=false` has no effect.
+//!
+//! - `#[pyderive(hash=<bool>)]`
+//!
+//!   The derive macro [`PyDataclass`] derives `__hash__()` if `hash=true`
+//!   and leaves `__hash__` to the user if `hash=false`,
+//!   instead of deciding it by `#[pyclass(frozen)]`, see [`PyDataclass`] for detail.
+//!
+//! - `#[pyderive(int="trunc"|"round")]`
+//!
+//!   The derive macro [`convert::PyInt`] truncates or rounds the [`Into<f64>`] value
+//!   instead of [`Into<i64>`] trait, see [`convert::PyInt`] for detail.
+//!
+//! - `#[pyderive(iter="values"|"items"|"keys")]`
+//!
+//!   It selects what the derive macros [`PyIter`] and [`PyReversed`] yield for each field;
+//!   `"values"` (default) yields the values,
+//!   `"items"` yields the tuples of the Python name and the value, like `dict.items()`,
+//!   and `"keys"` yields the Python names, that is, `list(obj) == obj.keys()` with [`PyMapping`].
+//!   The fields are selected by the field attribute `#[pyderive(iter=<bool>)]` regardless of the mode,
+//!   e.g., an `iter=false` field is yielded neither as a value, an item nor a key.
+//!
+//! - `#[pyderive(iter_all=<bool>)]`, `#[pyderive(len_all=<bool>)]`, `#[pyderive(repr_all=<bool>)]` and `#[pyderive(str_all=<bool>)]`
+//!
+//!   If `repr_all=true`, the derive macro [`PyRepr`] includes all fields as default regardless of `get` and `set`,
+//!   that is, it changes the default of the field attribute `repr` to `true`;
+//!   `iter_all`, `len_all` and `str_all` change the default of `iter`, `len` and `str` likewise.
+//!   It fits a class that does not expose getters,
+//!   and the field attribute, e.g. `#[pyderive(repr=false)]`, still excludes the field.
+//!   Notes, `*_all=false` has no effect.
+//!
+//! - `#[pyderive(iter_snapshot=<bool>)]`
+//!
+//!   If `iter_snapshot=true`,
+//!   the derive macros [`PyIter`] and [`PyReversed`] return the builtin iterator of a tuple
+//!   that snapshots the fields, instead of the iterator that takes a lock on every `__next__()`.
+//!   Notes, `iter_snapshot=false` has no effect.
+//!
+//! - `#[pyderive(order=<bool>)]`
+//!
+//!   If `order=true`,
+//!   the derive macro [`PyDataclass`] implements the ordering by [`PyRichCmp`],
+//!   like `order` of [`@dataclass`][dataclass].
+//!   Notes, `order=false` has no effect.
+//!
+//! - `#[pyderive(order_key=<path>)]`
+//!
+//!   The derive macros [`PyOrd`] and [`PyRichCmp`] compare `<path>(self)` with `<path>(other)`
+//!   instead of `self` with `other`,
+//!   where `<path>` is a fn of `fn(&Self) -> K` and `K` implements [`PartialOrd`].
+//!   The derive macro [`PyDataclass`] with `order=true` uses it for the ordering only,
+//!   `==` and `!=` compare by [`PartialEq`] of the struct.
+//!
+//!   See [`PyOrd`] and [`PyRichCmp`] for detail.
+//!
+//! - `#[pyderive(order_reverse=<bool>)]`
+//!
+//!   If `order_reverse=true`,
+//!   the derive macros [`PyOrd`] and [`PyRichCmp`] flip `<` and `>` (and `<=` and `>=`),
+//!   that is, `sorted()` results in descending order.
+//!   Notes, `order_reverse=false` has no effect, and `==` and `!=` are unaffected.
+//!
+//! - `#[pyderive(order_nan="false"|"raise")]`
+//!
+//!   If `order_nan="raise"`,
+//!   `<`, `<=`, `>` and `>=` of the derive macros [`PyOrd`] and [`PyRichCmp`]
+//!   raise `ValueError` when [`PartialOrd::partial_cmp()`] returns `None`, e.g. by a `NaN` field.
+//!   As default (`order_nan="false"`), they return `False` like Python `float`.
+//!   `==` and `!=` are unaffected.
+//!
+//! - `#[pyderive(repr_depth=<usize>)]`
+//!
+//!   The derive macro [`PyRepr`] renders the nested instances of the struct deeper than `<usize>` as `...`,
+//!   e.g., `Node(value=0, child=Node(value=1, child=...))` of `repr_depth=2`,
+//!   which guards a deep (but acyclic) tree against the recursion limit.
+//!   The depth counts the nesting of the instances of the same struct from the outermost `repr()` call in the thread.
+//!
+//! - `#[pyderive(repr_name="<name>")]`
+//!
+//!   The derive macros [`PyRepr`] and [`PyStr`] use `<name>` as the leading class name
+//!   instead of the `__qualname__` of the class.
+//!   It is a compile error to use it together with `repr_prefix="<prefix>"`, which replaces the name.
+//!
+//! - `#[pyderive(repr_order="sorted")]`
+//!
+//!   The derive macros [`PyRepr`] and [`PyStr`] list the fields alphabetically by the Python name
+//!   instead of the declaration order,
+//!   so that the output stays the same when the struct fields are reordered.
+//!
+//! - `#[pyderive(repr_prefix="<prefix>")]` and `#[pyderive(repr_suffix="<suffix>")]`
+//!
+//!   The derive macros [`PyRepr`] and [`PyStr`] use `<prefix>` instead of `<qualname>(`
+//!   and `<suffix>` instead of `)` to wrap the fields,
+//!   and render the fields positionally, that is, the values without `<name>=`.
+//!   With a single field (e.g., the others are `repr=false`), it reproduces a repr like `Decimal('1.5')`,
+//!   and with no fields, like `Color.RED`.
+//!   If either is omitted, it is the default one.
+//!
+//! - `#[pyderive(rhs(<type>, ...))]`
+//!
+//!   The binary operator derive macros in [`ops`], e.g. [`PyAdd`](ops::PyAdd),
+//!   accept the listed types as the other operand instead of `&Self` only,
+//!   see [`ops`] for detail.
+//!
+//! - `#[pyderive(richcmp_tuple=<bool>)]`
+//!
+//!   If `richcmp_tuple=true`,
+//!   the derive macro [`PyRichCmp`] also compares the instance with a tuple of `get` fields,
+//!   see [`PyRichCmp`] for detail.
+//!   Notes, `richcmp_tuple=false` has no effect.
+//!
+//! - `#[pyderive(signature=(<arg>, ...))]`
+//!
+//!   The derive macro [`PyNew`] uses the given signature for `__new__()`
+//!   instead of the one made from the field attributes,
+//!   where `<arg>` is `<name>`, `<name>=<expr>`, `/` or `*` like [`#[pyo3(signature=..)]`][pyo3-signature]
+//!   (`*args` and `**kwargs` are not supported).
+//!   `<name>` is the Python name of a field, and every argument of `__new__()` must be listed.
+//!   An argument without `=<expr>` takes `#[pyderive(default=<expr>)]` of the field if any,
+//!   thus the signature only needs to override the order and the defaults as it needs.
+//!   It is a compile error to use it together with the field attribute `kw_only` or `positional_only`.
+//!
+//! - `#[pyderive(str="repr")]`
+//!
+//!   The derive macro [`PyStr`] implements `__str__()` by calling `repr(self)`
+//!   instead of the field listing, like the default `__str__()` of Python.
+//!   It is a compile error to use it together with `str_with=<path>`.
+//!
+//! - `#[pyderive(str_children="repr"|"str")]`
+//!
+//!   The derive macro [`PyStr`] renders the fields by `str()` if `str_children="str"`,
+//!   e.g. `PyClass(name=a)` instead of `PyClass(name='a')`,
+//!   where the elements of a tuple field are still rendered by `repr()` like `str()` of a tuple does.
+//!   The field attributes that choose the rendering, e.g. `repr="debug"`, take precedence.
+//!   `str_children="repr"` is the default, like `dataclass`.
+//!
+//! - `#[pyderive(str_with=<path>)]`
+//!
+//!   The derive macro [`PyStr`] implements `__str__()` by `<path>(self)`
+//!   instead of the field listing,
+//!   where `<path>` is a fn of `fn(&Self) -> PyResult<String>`.
+//!
+//! - `#[pyderive(strip_underscore=<bool>)]`
+//!
+//!   The derive macros use the field name without a trailing underscore as the Python name,
+//!   e.g. a field `type_` (`type` is a Rust keyword) is `type` in `__new__()`, `repr()`, `__dataclass_fields__` etc.
+//!   A field whose name is given by `#[pyo3(name = "..")]` is not renamed.
+//!   PyO3 names the getter and the setter by itself,
+//!   thus it is a compile error if a `get`/`set` field is renamed by it,
+//!   add `#[pyo3(name = "type")]` to the field instead.
+//!
+//!   ```compile_fail
+//!   # use pyo3::prelude::*;
+//!   # use pyderive::*;
+//!   #[derive(PyNew)]
+//!   #[pyclass(get_all)]
+//!   #[pyderive(strip_underscore)]
+//!   struct PyClass {
+//!       type_: String,
+//!   }
+//!   ```
+//!
+//! [dataclass]: https://docs.python.org/3/library/dataclasses.html#dataclasses.dataclass
+//! [format_spec]: https://docs.python.org/3/library/string.html#format-specification-mini-language
+//! [InitVar]: https://docs.python.org/3/library/dataclasses.html#init-only-variables
 //! [keyword-only-arguments]: https://docs.python.org/3/tutorial/controlflow.html#keyword-only-arguments
 //! [KW_ONLY]: https://docs.python.org/3/library/dataclasses.html#dataclasses.KW_ONLY
 //! [MISSING]: https://docs.python.org/3/library/dataclasses.html#dataclasses.MISSING
+//! [pyo3_FromPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.FromPyObject.html
+//! [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+//! [pyo3-signature]: https://pyo3.rs/latest/function/signature.html
 
 pub mod convert;
 pub mod ops;
+mod repr;
 
-/// Derive macro generating a `__dataclass_fields__` fn/Python class attribute.
+pub use repr::FieldRepr;
+
+/// Derive macro generating [`__aiter__()`][__aiter__] and [`__anext__()`][__anext__] Python methods.
+///
+/// The `__aiter__()` returns the instance itself,
+/// and the awaitable that `__anext__()` returns delegates to the user-defined
+/// `async fn next_item(slf: Py<Self>) -> Option<T>`,
+/// it raises `StopAsyncIteration` when `next_item()` resolves to `None`.
+/// The future holds `Py<Self>` rather than a borrow of the instance,
+/// thus `next_item()` borrows the instance by itself, e.g. by `slf.borrow_mut(py)`,
+/// and the borrow should not be held across `.await`.
+///
+/// - It requires `experimental-async` feature of this crate (and PyO3).
+/// - The future of `next_item()` should be [`Send`] and `T` should implement [`IntoPyObject`][pyo3_IntoPyObject] trait.
+/// - The awaitable is a coroutine of the internal class, PyO3 does not support `async fn __anext__()`.
+///
+/// [__aiter__]: https://docs.python.org/reference/datamodel.html#object.__aiter__
+/// [__anext__]: https://docs.python.org/reference/datamodel.html#object.__anext__
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
 ///
-/// It returns a [`dataclasses.Field`][Field] dict that helper functions of the [dataclasses] module read.
-/// It supports [`is_dataclass()`][is_dataclass], [`fields()`][fields],
-/// [`asdict()`][asdict] (include nest), [`astuple()`][astuple] (include nest)
-/// and [`replace()`][replace] of the dataclasses module.
+/// # Example
 ///
-/// The resulting dict contains all fields as default.
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
 ///
-/// If the filed is marked by `#[pyderive(dataclass_field=false)]` attribute,
-/// the field is excluded from the dict that `__dataclass_fields__` returns.
-/// Notes, `dataclass_field=true` has no effect.
+/// #[derive(PyAIter)]
+/// #[pyclass]
+/// struct PyClass {
+///     items: Vec<i64>,
+/// }
 ///
-/// - It should place `#[derive(PyDataclassField)]` before `#[pyclass]`.
-/// - All fields in the arguments of the `__new__()` method should be `get` field, like `dataclass` does.
-/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
+/// impl PyClass {
+///     async fn next_item(slf: Py<Self>) -> Option<i64> {
+///         Python::attach(|py| slf.borrow_mut(py).items.pop())
+///     }
+/// }
 ///
-/// This does not generate other fn/method,
-/// use [`PyNew`] etc. to implement `__new__()` etc.
+/// let test = "
+/// import asyncio
+///
+/// async def main(a):
+///     return [v async for v in a]
+///
+/// assert asyncio.run(main(a)) == [3, 2, 1]
+/// ";
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass { items: vec![1, 2, 3] })?;
+///     py_run!(py, a, test);
+///     Ok(())
+/// });
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental-async")))]
+#[cfg(feature = "experimental-async")]
+pub use pyderive_macros::PyAIter;
+/// Derive macro generating [`__aenter__()`][__aenter__] and [`__aexit__()`][__aexit__] Python methods.
+///
+/// The awaitables delegate to the user-defined async methods;
+///
+/// - `async fn aenter(&mut self) -> PyResult<T>`, the value of `async with ... as value`,
+/// - `async fn aexit(&mut self, exc_type: Option<Py<PyAny>>, exc_value: Option<Py<PyAny>>, traceback: Option<Py<PyAny>>) -> PyResult<bool>`.
 ///
+/// The exception raised in the `async with` block propagates when `aexit()` resolves to `false`,
+/// and it is suppressed when `true`, as Python does.
+///
+/// - It requires `experimental-async` feature of this crate (and PyO3).
+/// - The futures should be [`Send`] and `T` should implement [`IntoPyObject`][pyo3_IntoPyObject] trait.
+///
+/// [__aenter__]: https://docs.python.org/reference/datamodel.html#object.__aenter__
+/// [__aexit__]: https://docs.python.org/reference/datamodel.html#object.__aexit__
 /// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
-/// [pyo3_pyclass]: https://docs.rs/pyo3/latest/pyo3/attr.pyclass.html
 ///
 /// # Example
 ///
@@ -335,77 +978,478 @@ pub mod ops;
 /// use pyo3::{prelude::*, py_run};
 /// use pyderive::*;
 ///
-/// // Place before `#[pyclass]`
-/// #[derive(PyNew, PyDataclassFields)]
+/// #[derive(PyAsyncContextManager)]
 /// #[pyclass(get_all)]
 /// struct PyClass {
-///     string: String,
-///     integer: i64,
-///     float: f64,
-///     tuple: (String, i64, f64),
-///     option: Option<String>,
-///     #[pyderive(dataclass_field=false)]
-///     excluded: String,
+///     opened: bool,
 /// }
 ///
-/// Python::attach(|py| -> PyResult<()> {
-///     let a = Py::new(py, PyClass {
-///         string: "s".to_string(),
-///         integer: 1,
-///         float: 1.0,
-///         tuple: ("s".to_string(), 1, 1.0),
-///         option: None,
-///         excluded: "s".to_string(),
-///     })?;
+/// impl PyClass {
+///     async fn aenter(&mut self) -> PyResult<i64> {
+///         self.opened = true;
+///         Ok(1)
+///     }
 ///
-///     let test = "
-/// from dataclasses import is_dataclass, asdict, astuple
+///     async fn aexit(
+///         &mut self,
+///         _exc_type: Option<Py<PyAny>>,
+///         _exc_value: Option<Py<PyAny>>,
+///         _traceback: Option<Py<PyAny>>,
+///     ) -> PyResult<bool> {
+///         self.opened = false;
+///         Ok(false)
+///     }
+/// }
 ///
-/// assert is_dataclass(a) is True
-/// assert asdict(a) == {'string': 's', 'integer': 1, 'float': 1.0, 'tuple': ('s', 1, 1.0), 'option': None}
-/// assert astuple(a) == ('s', 1, 1.0, ('s', 1, 1.0), None)
+/// let test = "
+/// import asyncio
+///
+/// async def main(a):
+///     async with a as value:
+///         assert value == 1
+///         assert a.opened
+///     assert not a.opened
+///
+/// asyncio.run(main(a))
 /// ";
-///     py_run!(py, a, test);
 ///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass { opened: false })?;
+///     py_run!(py, a, test);
 ///     Ok(())
 /// });
 /// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "experimental-async")))]
+#[cfg(feature = "experimental-async")]
+pub use pyderive_macros::PyAsyncContextManager;
+/// Derive macro generating a [`__class_getitem__()`][__class_getitem__] Python class method.
 ///
-/// # Implementation Notes
+/// It returns [`types.GenericAlias(cls, item)`][GenericAlias],
+/// this lets a subscription `MyClass[int]` evaluate, e.g., in annotations.
 ///
-/// | `dataclasses.Field` Attribute | Compatibility                      |
-/// | ----------------------------- | ---------------------------------- |
-/// | `name`                        | ✅                                 |
-/// | `type`                        | ❌ (✅ if `annotation` given)      |
-/// | `default`                     | ✅ (`<expr>` or `MISSING`)         |
-/// | `default_factory`             | ✅ (`lambda: <expr>` or `MISSING`) |
-/// | `new`                         | ✅                                 |
-/// | `repr`                        | ✅                                 |
-/// | `hash`                        | ❌ (`None` for pyderive)           |
-/// | `compare`                     | ❌ (`None` for pyderive)           |
-/// | `metadata`                    | ✅ (empty for pyderive)            |
-/// | `kw_only`                     | ✅                                 |
+/// # Expansion
 ///
-/// 1. The `type` attribute of `Field` is `None` as default.
-///    If the field is marked by `#[pyderive(annotation=<type>)]`,
-///    this uses the given `<type>` as `type` attribute.
-/// 2. If the field is marked by `#[pyderive(default_factory=true)]`,
-///    the `default` attribute of the resulting `Field` obj is [`MISSING`][MISSING]
-///    and the `default_factory` is `lambda: <expr>`.
-///    Notes, it evaluates `<expr>` on every `Field.default_factory` call.
+/// This implements:
 ///
-///    | Rust Field Attribute                | Python `default` Attribute | Python `default_factory` Attribute |
-///    | ----------------------------------- | -------------------------- | ---------------------------------- |
-///    | `#[pyderive(default_factory=true)]` | `MISSING`                  | `lambda: <expr>`                   |
-///    | Other                               | `<expr>`                   | `MISSING`                          |
-/// 3. Attributes `hash` and `compare` are `None`.
-/// 4. This marks `new=false` field as a [`ClassVar` field][dataclass_ClassVar].
+/// ```
+/// # use pyo3::prelude::*;
+/// # use pyo3::types::PyType;
+/// # #[pyclass]
+/// # struct PyClass {}
+/// #[pymethods]
+/// impl PyClass {
+///     #[classmethod]
+///     pub fn __class_getitem__<'py>(
+///         cls: &Bound<'py, PyType>,
+///         item: &Bound<'py, PyAny>,
+///     ) -> PyResult<Bound<'py, PyAny>> {
+///         let py = cls.py();
+///         py.import("types")?.getattr("GenericAlias")?.call1((cls, item))
+///     }
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyClassGetItem)]
+/// #[pyclass]
+/// struct PyClass {}
+///
+/// let test = "
+/// import types
+///
+/// assert isinstance(PyClass[int], types.GenericAlias)
+/// assert PyClass[int].__origin__ is PyClass
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [__class_getitem__]: https://docs.python.org/3/reference/datamodel.html#object.__class_getitem__
+/// [GenericAlias]: https://docs.python.org/3/library/types.html#types.GenericAlias
+pub use pyderive_macros::PyClassGetItem;
+/// Derive macro generating class attributes by [`#[classattr]`][pyo3_classattr].
+///
+/// The field marked by `#[pyderive(classvar=<expr>)]` becomes a Python class attribute of the value `<expr>`,
+/// that is, it is readable without instantiating, like a `ClassVar` of a dataclass.
+///
+/// The field is not a `__new__()` argument of [`PyNew`], and the instance holds `<expr>` also.
+/// It is excluded from the derive macros as default, e.g., [`PyRepr`],
+/// but [`PyDataclassFields`] lists it as a [`ClassVar` pseudo-field][dataclass_ClassVar].
+///
+/// - It should place `#[derive(PyClassVar)]` before `#[pyclass]`.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for the field.
+/// - The field should not be marked by `#[pyo3(get)]` (and `#[pyclass(get_all)]`),
+///   the getter shadows the class attribute.
+/// - It is a compile error to mark the field by both `classvar=<expr>` and `default=<expr>` (or `new=true`).
+///
+/// [dataclass_ClassVar]: https://docs.python.org/3/library/dataclasses.html#class-variables
+/// [pyo3_classattr]: https://pyo3.rs/latest/class.html#class-attributes
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PyClassVar, PyNew, PyRepr)]
+/// #[pyclass]
+/// struct PyClass {
+///     #[pyo3(get)]
+///     field: i64,
+///     #[pyderive(classvar = 42)]
+///     answer: i64,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let PyClass = py.get_type::<PyClass>();
+///
+///     py_run!(py, PyClass, "assert PyClass.answer == 42");
+///     py_run!(py, PyClass, "assert PyClass(1).answer == 42");
+///     py_run!(py, PyClass, "assert repr(PyClass(1)) == 'PyClass(field=1)'");
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PyClassVar;
+/// Derive macro generating [`__eq__()`][__eq__], [`__ne__()`][__ne__] and [`__hash__()`][__hash__] fn/Python methods of fields at once.
+///
+/// This is equivalent to [`PyEq`] with `#[pyderive(eq="fields")]` and [`PyFieldHash`],
+/// both read the same `compare` fields, thus equal objects always have the same hash value.
+/// Use this instead of deriving [`PyEq`] and [`PyFieldHash`] separately,
+/// whose `__eq__()` depends on [`PartialEq`] of the struct, which may not agree with the hash.
+///
+/// If the filed is marked by `#[pyderive(compare=false)]` attribute,
+/// the field is excluded from both the comparison and the hash.
+/// The struct attributes `#[pyderive(eq_subclass)]` and `#[pyderive(eq_identity)]` work as [`PyEq`].
+///
+/// - It requires [`PartialEq`] and [`Hash`] traits for non-`Py` fields.
+/// - It calls Python `==` and `hash()` on `Py` fields.
+/// - It conflicts with [`PyEq`], [`PyOrd`], [`PyRichCmp`] and [`PyDataclass`].
+/// - Python assumes the hash of an object never changes,
+///   mark the struct by `#[pyclass(frozen)]` unless the fields are never mutated while hashed.
+///
+/// [__eq__]: https://docs.python.org/reference/datamodel.html#object.__eq__
+/// [__ne__]: https://docs.python.org/reference/datamodel.html#object.__ne__
+/// [__hash__]: https://docs.python.org/reference/datamodel.html#object.__hash__
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyComparable)]
+/// #[pyclass(frozen)]
+/// struct PyClass {
+///     field: i64,
+///     #[pyderive(compare=false)]
+///     excluded: String,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass { field: 0, excluded: "a".to_string() })?;
+///     let b = Py::new(py, PyClass { field: 0, excluded: "b".to_string() })?;
+///     let c = Py::new(py, PyClass { field: 1, excluded: "a".to_string() })?;
+///
+///     py_run!(py, a b, "assert a == b and hash(a) == hash(b)");
+///     py_run!(py, a c, "assert a != c");
+///     py_run!(py, a, "assert {a: 1}[a] == 1");
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PyComparable;
+/// Derive macro generating a [`__contains__()`][__contains__] fn/Python method
+/// that tests the membership of the key, like a mapping does.
+///
+/// `key in obj` returns `True` if the `key` is a `str`
+/// and matches the Python name of a `get` field, that is,
+/// the keys are the same as [`PyMapping`]; it returns `False` otherwise.
+/// Notes, it does not test the membership of the field values.
+///
+/// - It should place `#[derive(PyContainsKey)]` before `#[pyclass]`.
+///
+/// [__contains__]: https://docs.python.org/3/reference/datamodel.html#object.__contains__
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PyMapping, PyContainsKey)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     field_a: i64,
+///     #[pyo3(name = "renamed")]
+///     field_b: String,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass {
+///         field_a: 1,
+///         field_b: "s".to_string(),
+///     })?;
+///
+///     py_run!(py, a, "assert 'field_a' in a");
+///     py_run!(py, a, "assert 'renamed' in a");
+///     py_run!(py, a, "assert 'nope' not in a");
+///     py_run!(py, a, "assert 1 not in a");
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PyContainsKey;
+/// Derive macro generating a [`__replace__()`][__replace__] fn/Python method.
+///
+/// It creates a new instance by calling the class with the current values
+/// of the `__new__()` arguments updated by the keyword arguments,
+/// that is, [`copy.replace(obj, field=x)`][copy_replace] works on Python 3.13 or later
+/// (`obj.__replace__(field=x)` works on any version).
+/// It works with [`PyNew`] or a hand-written `#[new]` that takes the fields by their names.
+///
+/// - The arguments are the fields that `#[pyderive(new=true)]` (default),
+///   an unknown name and a field of `#[pyderive(new=false)]` raise `TypeError` as `__new__()` does.
+/// - The `#[pyderive(init_var)]` fields have no value to copy,
+///   so that they must be given like `dataclasses.replace()`.
+/// - The `#[pyderive(positional_only)]` fields are passed by position,
+///   they can be replaced by the keyword arguments though.
+/// - It calls the type of the instance, a subclass instance produces the subclass instance.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for the `&` reference of the fields.
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyNew, PyCopyReplace)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     name: String,
+///     value: i64,
+/// }
+///
+/// let test = "
+/// a = PyClass('a', 1)
+/// b = a.__replace__(value=2)
+/// assert (b.name, b.value) == ('a', 2)
+///
+/// import sys
+/// if sys.version_info >= (3, 13):
+///     import copy
+///     c = copy.replace(a, name='c')
+///     assert (c.name, c.value) == ('c', 1)
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [__replace__]: https://docs.python.org/3/library/copy.html#object.__replace__
+/// [copy_replace]: https://docs.python.org/3/library/copy.html#copy.replace
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+pub use pyderive_macros::PyCopyReplace;
+/// Derive macro generating the methods of [`@dataclass`][dataclass] at once.
+///
+/// This is equivalent to `#[derive(PyNew, PyRepr, PyEq, PyMatchArgs)]`,
+/// and, if the struct is marked by `#[pyderive(order=true)]`, [`PyRichCmp`] instead of [`PyEq`]
+/// (PyO3 does not allow to combine [`PyEq`] and [`PyOrd`]).
+/// The field attributes `#[pyderive(..)]` customize them as the respective derive macro does.
+///
+/// Python drops `__hash__()` of the class that defines `__eq__()`, thus, like `@dataclass` does,
+/// it derives `__hash__()` if the struct is marked by `#[pyclass(frozen)]`,
+/// otherwise it sets `__hash__` to `None` explicitly (the instance is unhashable).
+/// The `__hash__()` combines Python `hash()` of the `compare` fields like `@dataclass`,
+/// so that it does not require [`Hash`] of the fields, e.g. an `f64` field.
+/// The struct attribute `#[pyderive(hash=<bool>)]` overrides it like `unsafe_hash` of `@dataclass`;
+/// `hash=true` derives `__hash__()` regardless of `frozen`,
+/// and `hash=false` derives nothing about `__hash__`, e.g. for [`PyFieldHash`] or a hand-written `__hash__()`.
+///
+/// - It should place `#[derive(PyDataclass)]` before `#[pyclass]`.
+/// - It requires [`PartialEq`] impl, and [`PartialOrd`] impl if `order=true`
+///   (except the struct is marked by `#[pyderive(order_key=<path>)]`).
+/// - `==` and `!=` compare by [`PartialEq`] even if the struct is marked by `#[pyderive(order_key=<path>)]`,
+///   which affects `<`, `<=`, `>` and `>=` only, unlike [`PyRichCmp`].
+///   They return `NotImplemented` unless `type(other) is type(self)` as [`PyEq`] does, regardless of `order`,
+///   and the struct attribute `#[pyderive(eq_subclass)]` opts out it.
+/// - The instance supports [`weakref`][weakref] by `#[pyclass(weakref)]`, like `weakref_slot` of `@dataclass`,
+///   it is a flag of PyO3 and there is no derive macro for it.
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyDataclass)]
+/// #[pyclass(get_all)]
+/// #[pyderive(order)]
+/// #[derive(PartialEq, PartialOrd)]
+/// struct PyClass {
+///     string: String,
+///     integer: i64,
+/// }
+///
+/// let test = "
+/// a = PyClass('a', 1)
+/// assert repr(a) == \"PyClass(string='a', integer=1)\"
+/// assert a == PyClass('a', 1)
+/// assert a < PyClass('b', 0)
+///
+/// match a:
+///     case PyClass(s, i):
+///         assert s == 'a'
+///         assert i == 1
+///     case _:
+///         raise AssertionError
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// The struct without [`PartialOrd`] fails to compile
+/// with the note `required by a bound in pyderive_order_requires_derive_PartialOrd`:
+///
+/// ```compile_fail
+/// # use pyo3::prelude::*;
+/// # use pyderive::*;
+/// #[derive(PyDataclass)]
+/// #[pyclass(get_all)]
+/// #[pyderive(order)]
+/// #[derive(PartialEq)]
+/// struct PyClass {
+///     integer: i64,
+/// }
+/// ```
 ///
-///    | Field Attribute        | Result                                 |
-///    | ---------------------- | -------------------------------------- |
-///    |`new=true` (default)    | Dataclass field                        |
-///    |`new=false`             | [`ClassVar` field][dataclass_ClassVar] |
-///    |`dataclass_field=false` | Exclude from `__dataclass_fields__`    |
+/// [dataclass]: https://docs.python.org/3/library/dataclasses.html#dataclasses.dataclass
+/// [weakref]: https://docs.python.org/3/library/weakref.html
+pub use pyderive_macros::PyDataclass;
+/// Derive macro generating a `__dataclass_fields__` fn/Python class attribute.
+///
+/// It returns a [`dataclasses.Field`][Field] dict that helper functions of the [dataclasses] module read.
+/// It supports [`is_dataclass()`][is_dataclass], [`fields()`][fields],
+/// [`asdict()`][asdict] (include nest), [`astuple()`][astuple] (include nest)
+/// and [`replace()`][replace] of the dataclasses module.
+///
+/// The resulting dict contains all fields as default.
+///
+/// If the filed is marked by `#[pyderive(dataclass_field=false)]` attribute,
+/// the field is excluded from the dict that `__dataclass_fields__` returns.
+/// Notes, `dataclass_field=true` has no effect.
+///
+/// - It should place `#[derive(PyDataclassField)]` before `#[pyclass]`.
+/// - All fields in the arguments of the `__new__()` method should be `get` field, like `dataclass` does.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
+///
+/// This does not generate other fn/method,
+/// use [`PyNew`] etc. to implement `__new__()` etc.,
+/// except `__getattr__()` of a field marked by `#[pyderive(flatten, dataclass_flatten)]`,
+/// which requires [`Clone`] and [`IntoPyObject`][pyo3_IntoPyObject] for a non-`Py` field,
+/// see the field attribute `#[pyderive(dataclass_flatten=<bool>)]` for detail.
+///
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+/// [pyo3_pyclass]: https://docs.rs/pyo3/latest/pyo3/attr.pyclass.html
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PyNew, PyDataclassFields)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     string: String,
+///     integer: i64,
+///     float: f64,
+///     tuple: (String, i64, f64),
+///     option: Option<String>,
+///     #[pyderive(dataclass_field=false)]
+///     excluded: String,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass {
+///         string: "s".to_string(),
+///         integer: 1,
+///         float: 1.0,
+///         tuple: ("s".to_string(), 1, 1.0),
+///         option: None,
+///         excluded: "s".to_string(),
+///     })?;
+///
+///     let test = "
+/// from dataclasses import is_dataclass, asdict, astuple
+///
+/// assert is_dataclass(a) is True
+/// assert asdict(a) == {'string': 's', 'integer': 1, 'float': 1.0, 'tuple': ('s', 1, 1.0), 'option': None}
+/// assert astuple(a) == ('s', 1, 1.0, ('s', 1, 1.0), None)
+/// ";
+///     py_run!(py, a, test);
+///
+///     Ok(())
+/// });
+/// ```
+///
+/// # Implementation Notes
+///
+/// | `dataclasses.Field` Attribute | Compatibility                      |
+/// | ----------------------------- | ---------------------------------- |
+/// | `name`                        | ✅                                 |
+/// | `type`                        | ❌ (✅ if `annotation` given)      |
+/// | `default`                     | ✅ (`<expr>` or `MISSING`)         |
+/// | `default_factory`             | ✅ (`lambda: <expr>` or `MISSING`) |
+/// | `new`                         | ✅                                 |
+/// | `repr`                        | ✅                                 |
+/// | `hash`                        | ❌ (`None` for pyderive)           |
+/// | `compare`                     | ❌ (`None` for pyderive)           |
+/// | `metadata`                    | ✅ (`doc` key only for pyderive)   |
+/// | `kw_only`                     | ✅                                 |
+///
+/// 1. The `type` attribute of `Field` is `None` as default.
+///    If the field is marked by `#[pyderive(annotation=<type>)]`,
+///    this uses the given `<type>` as `type` attribute.
+/// 2. If the field is marked by `#[pyderive(default_factory=true)]`,
+///    the `default` attribute of the resulting `Field` obj is [`MISSING`][MISSING]
+///    and the `default_factory` is `lambda: <expr>`.
+///    Notes, it evaluates `<expr>` on every `Field.default_factory` call.
+///
+///    | Rust Field Attribute                | Python `default` Attribute | Python `default_factory` Attribute |
+///    | ----------------------------------- | -------------------------- | ---------------------------------- |
+///    | `#[pyderive(default_factory=true)]` | `MISSING`                  | `lambda: <expr>`                   |
+///    | Other                               | `<expr>`                   | `MISSING`                          |
+/// 3. Attributes `hash` and `compare` are `None`.
+/// 4. This marks `classvar=<expr>` field as a [`ClassVar` field][dataclass_ClassVar] of the default `<expr>`,
+///    and `init_var=true` field as an [`InitVar` field][dataclass_InitVar],
+///    they are not listed by [`fields()`][fields].
+///    The `new=false` field is a dataclass field of `init=False`, like `field(init=False)`.
+///
+///    | Field Attribute        | Result                                  |
+///    | ---------------------- | --------------------------------------- |
+///    |`new=true` (default)    | Dataclass field                         |
+///    |`new=false`             | Dataclass field of `init=False`         |
+///    |`classvar=<expr>`       | [`ClassVar` field][dataclass_ClassVar]  |
+///    |`init_var=true`         | [`InitVar` field][dataclass_InitVar]    |
+///    |`dataclass_field=false` | Exclude from `__dataclass_fields__`     |
 /// 5. The [PEP 487][PEP487] ([`__set_name__()`][set_name] hook) is not supported
 ///    (The default value of `__dataclass_fields__` is a different object
 ///    from `__new__()`'s one, that is, they have different object IDs.
@@ -422,40 +1466,534 @@ pub mod ops;
 /// [is_dataclass]: https://docs.python.org/3/library/dataclasses.html#dataclasses.is_dataclass
 /// [ClassVar]: https://docs.python.org/3/library/typing.html#typing.ClassVar
 /// [dataclass_ClassVar]: https://docs.python.org/3/library/dataclasses.html#class-variables
+/// [dataclass_InitVar]: https://docs.python.org/3/library/dataclasses.html#init-only-variables
 /// [MISSING]: https://docs.python.org/3/library/dataclasses.html#dataclasses.MISSING
 /// [PEP487]: https://peps.python.org/pep-0487/
 /// [set_name]: https://docs.python.org/3/reference/datamodel.html#object.__set_name__
 pub use pyderive_macros::PyDataclassFields;
+/// Derive macro generating a [`__new__()`][__new__] Python method by [`Default`] trait.
+///
+/// It makes the class constructible with no arguments, `PyClass()`,
+/// that is simpler than [`PyNew`] when a single [`Default`] impl defines all the fields.
+///
+/// - It conflicts with [`PyNew`] because both implement `__new__()`.
+///
+/// [__new__]: https://docs.python.org/3/reference/datamodel.html#object.__new__
+///
+/// # Expansion
+///
+/// This implements:
+///
+/// ```
+/// # use pyo3::prelude::*;
+/// # #[pyclass]
+/// # #[derive(Default)]
+/// # struct PyClass {}
+/// #[pymethods]
+/// impl PyClass {
+///     #[new]
+///     fn __new__() -> Self {
+///         Default::default()
+///     }
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyDefaultNew, PyRepr)]
+/// #[pyclass(get_all)]
+/// #[derive(Default)]
+/// struct PyClass {
+///     field: i64,
+///     items: Vec<String>,
+/// }
+///
+/// let test = "
+/// a = PyClass()
+/// assert a.field == 0
+/// assert repr(a) == 'PyClass(field=0, items=[])'
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+pub use pyderive_macros::PyDefaultNew;
+/// Derive macro generating a [`__doc__`][__doc__] const/Python class attribute.
+///
+/// The `__doc__` is the doc comment of the struct followed by an `Attributes:` section
+/// (like Google style docstrings) that lists the fields marked by `#[pyderive(doc="<doc>")]` attribute
+/// with their Python names, which helps documentation tools and generated stubs.
+/// The same `<doc>` is stored in the `metadata` of [`PyDataclassFields`].
+///
+/// - It should place `#[derive(PyDoc)]` before `#[pyclass]`.
+/// - It overrides the `__doc__` that PyO3 sets from the doc comment.
+///
+/// [__doc__]: https://docs.python.org/3/reference/datamodel.html#type.__doc__
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// /// A point.
+/// // Place before `#[pyclass]`
+/// #[derive(PyDoc)]
+/// #[pyclass(get_all)]
+/// struct Point {
+///     #[pyderive(doc="the x coordinate")]
+///     x: f64,
+///     #[pyderive(doc="the y coordinate")]
+///     y: f64,
+/// }
+///
+/// Python::attach(|py| {
+///     let Point = py.get_type::<Point>();
+///     py_run!(py, Point, r#"
+/// assert Point.__doc__ == "A point.\n\nAttributes:\n    x: the x coordinate\n    y: the y coordinate"
+/// "#)
+/// });
+/// ```
+pub use pyderive_macros::PyDoc;
 /// Derive macro generating a [`__eq__()`][__eq__] and [`__ne__()`][__ne__] fn/Python methods.
 ///
 /// The implementation requires [`PartialEq`] impl.
+/// It follows the impl, e.g. the derived [`PartialEq`] makes the instance with a `NaN` field
+/// not equal to itself like Python `float` (`==` returns `False` and `!=` returns `True`).
 ///
 /// *Note that implementing `__eq__()` and `__ne__()` methods will cause
 /// Python not to generate a default `__hash__()` implementation,
 /// so consider also implementing `__hash__()`.*
 ///
+/// It returns `NotImplemented` unless `type(other) is type(self)` like `dataclass`,
+/// that is, an instance of a Python subclass is not equal to the instance of the class.
+/// The struct attribute `#[pyderive(eq_subclass)]` opts out it.
+///
+/// It conflicts with [`PyOrd`], [`PyRichCmp`] and [`PyDataclass`], see [`PyRichCmp`] for detail.
+///
+/// # Expansion
+///
+/// This implements, for example;
+///
+/// ```
+/// # use pyo3::prelude::*;
+/// # use pyo3::types::PyBool;
+/// # #[pyclass]
+/// # #[derive(PartialEq)]
+/// # struct PyClass {}
+/// #[pymethods]
+/// impl PyClass {
+///     pub fn __eq__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+///         let py = slf.py();
+///         if !other.get_type().is(&slf.get_type()) {
+///             return Ok(py.NotImplemented());
+///         }
+///         let other = other.cast::<Self>()?.try_borrow()?;
+///         let r = slf.try_borrow()?.eq(&other);
+///         Ok(PyBool::new(py, r).to_owned().into_any().unbind())
+///     }
+///     // __ne__() as well
+/// }
+/// ```
+///
+/// and, if the struct is marked by `#[pyderive(eq_subclass)]`,
+///
+/// ```
+/// # use pyo3::prelude::*;
+/// # #[pyclass]
+/// # #[derive(PartialEq)]
+/// # struct PyClass {}
+/// #[pymethods]
+/// impl PyClass {
+///     pub fn __eq__(&self, other: &Self) -> bool {
+///         self.eq(other)
+///     }
+///     pub fn __ne__(&self, other: &Self) -> bool {
+///         self.ne(other)
+///     }
+/// }
+/// ```
+///
+/// If the struct is marked by `#[pyderive(eq="fields")]`,
+/// it compares the fields one by one instead, and it does not require [`PartialEq`] of the struct.
+/// The `Py<T>` and `Option<Py<T>>` fields are compared by Python `==`,
+/// e.g., two distinct child objects with equal fields compare equal,
+/// and the other fields are compared by [`PartialEq`].
+/// The fields marked by `#[pyderive(compare=false)]` are skipped.
+///
+/// If the struct is marked by `#[pyderive(eq_identity)]`,
+/// `a == a` of the same object returns `True` (and `a != a` returns `False`)
+/// before comparing the fields, which skips the comparison of a large struct.
+/// It assumes that the equality is reflexive, e.g. a `NaN` field compares equal to itself under it.
+/// Note, Python containers already check the identity first, e.g. `a in [a]` does not call `__eq__()`,
+/// thus it saves the cost of an explicit self-comparison only.
+///
+/// [__eq__]: https://docs.python.org/reference/datamodel.html#object.__eq__
+/// [__ne__]: https://docs.python.org/reference/datamodel.html#object.__ne__
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyEq)]
+/// #[pyclass]
+/// #[derive(PartialEq)]
+/// struct PyClass {
+///     field: f64,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass { field: 0.0 })?;
+///     let b = Py::new(py, PyClass { field: 1.0 })?;
+///     let c = Py::new(py, PyClass { field: f64::NAN })?;
+///
+///     py_run!(py, a b, "assert a == a");
+///     py_run!(py, a b, "assert a != b");
+///     py_run!(py, c, "assert c != c");
+///     py_run!(py, a, "assert a != 1");
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PyEq;
+/// Derive macro generating a [`__hash__()`][__hash__] fn/Python method of fields.
+///
+/// It hashes all fields as default, in the order of declaration.
+///
+/// If the filed is marked by `#[pyderive(compare=false)]` attribute,
+/// the field is excluded from the hash, like `compare` of [`dataclasses.field()`][dataclasses-field].
+/// Notes, `compare=true` has no effect.
+///
+/// - It requires [`Hash`] trait for non-`Py` fields.
+/// - It calls Python `hash()` on `Py` fields.
+///
+/// Unlike `#[pyclass(hash)]` hashes whole struct by [`Hash`] impl of the struct,
+/// this hashes `compare` fields only.
+/// It should be consistent with the `__eq__()` implementation,
+/// that is, it should hold that equal objects have the same hash value.
+///
+/// If the struct is marked by `#[pyclass(frozen)]`,
+/// the hash can be memoized in a [`OnceLock<u64>`][std::sync::OnceLock] field marked by `#[pyderive(cache_hash)]`;
+/// the hash is computed by the first `hash()` call only.
+/// The field is not a `__new__()` argument and is excluded from the hash, `repr()` etc. as default,
+/// but it should not be marked by `#[pyo3(get)]` (and `#[pyclass(get_all)]`).
+///
+/// Note that `#[pyclass(frozen)]` freezes the fields but not the objects they refer to,
+/// e.g. a `Py<PyList>` field is appendable and a `Py<T>` field of a non-frozen pyclass `T` is assignable
+/// after the hash is cached, thus `cache_hash` rejects a `Py<T>` (and `PyObject`) field in the hash,
+/// including `Option<Py<T>>`, `Vec<Py<T>>` etc.;
+/// exclude it by `#[pyderive(compare=false)]`.
+/// The interior mutability of a Rust field, e.g. [`Mutex`][std::sync::Mutex] and atomics, is not detected,
+/// such a field also leaves the cached hash stale.
+///
+/// [__hash__]: https://docs.python.org/reference/datamodel.html#object.__hash__
+/// [dataclasses-field]: https://docs.python.org/3/library/dataclasses.html#dataclasses.field
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyFieldHash)]
+/// #[pyclass]
+/// struct PyClass {
+///     field: i64,
+///     #[pyderive(compare=false)]
+///     excluded: String,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass { field: 0, excluded: "a".to_string() })?;
+///     let b = Py::new(py, PyClass { field: 0, excluded: "b".to_string() })?;
+///
+///     py_run!(py, a b, "assert hash(a) == hash(b)");
+///
+///     Ok(())
+/// });
+/// ```
+///
+/// Memoize the hash:
+///
+/// ```
+/// use std::sync::OnceLock;
+///
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyNew, PyFieldHash)]
+/// #[pyclass(frozen)]
+/// struct PyClass {
+///     field: Vec<String>,
+///     #[pyderive(cache_hash)]
+///     cache: OnceLock<u64>,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let PyClass = py.get_type::<PyClass>();
+///
+///     py_run!(py, PyClass, "a = PyClass(['a', 'b']); assert hash(a) == hash(a)");
+///
+///     Ok(())
+/// });
+/// ```
+///
+/// A `Py<T>` field may mutate after the hash is cached, it fails to compile:
+///
+/// ```compile_fail
+/// use std::sync::OnceLock;
+///
+/// use pyo3::{prelude::*, types::PyList};
+/// use pyderive::*;
+///
+/// #[derive(PyFieldHash)]
+/// #[pyclass(frozen)]
+/// struct PyClass {
+///     // `obj.field.append(1)` would change the hash
+///     #[pyo3(get)]
+///     field: Py<PyList>,
+///     #[pyderive(cache_hash)]
+///     cache: OnceLock<u64>,
+/// }
+/// ```
+pub use pyderive_macros::PyFieldHash;
+
+/// Attribute macro turning methods of an `impl` block into computed read-only Python properties.
+///
+/// Since a derive macro cannot see `impl` blocks, this is an attribute macro on an inherent `impl` block.
+/// It marks all methods of the block by `#[getter]` and makes the block `#[pymethods]`.
+///
+/// - The methods should take `&self` (and optionally `py: Python`) only.
+/// - The block should contain methods only.
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyNew)]
+/// #[pyclass]
+/// struct PyClass {
+///     width: f64,
+///     height: f64,
+/// }
+///
+/// #[pyderive::property]
+/// impl PyClass {
+///     fn area(&self) -> f64 {
+///         self.width * self.height
+///     }
+/// }
+///
+/// let test = "
+/// a = PyClass(2.0, 3.0)
+/// assert a.area == 6.0
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// The method taking `&mut self` fails to compile:
+///
+/// ```compile_fail
+/// # use pyo3::prelude::*;
+/// # #[pyclass]
+/// # struct PyClass {
+/// #     field: i64,
+/// # }
+/// #[pyderive::property]
+/// impl PyClass {
+///     fn field(&mut self) -> i64 {
+///         self.field
+///     }
+/// }
+/// ```
+pub use pyderive_macros::property;
+/// Derive macro generating a [`__format__()`][__format__] Python method.
+///
+/// The `__format__()` forwards the format spec to the `__format__()` of the field
+/// marked by `#[pyderive(format_field)]` attribute,
+/// e.g., `f"{obj:.1%}"` of a single-value wrapper formats the inner value.
+/// Exactly one field must be marked.
+///
+/// - It should place `#[derive(PyFormat)]` before `#[pyclass]`.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for the field.
+///
+/// [__format__]: https://docs.python.org/reference/datamodel.html#object.__format__
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PyFormat)]
+/// #[pyclass]
+/// struct Percentage {
+///     #[pyderive(format_field)]
+///     value: f64,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, Percentage { value: 0.125 })?;
+///
+///     py_run!(py, a, r#"
+/// assert f"{a:.1%}" == "12.5%"
+/// assert format(a, ".2f") == "0.12"
+/// "#);
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PyFormat;
+/// Derive macro generating a `from_dict()` Python class method.
+///
+/// The `from_dict(mapping)` constructs the instance by `cls(**mapping)`,
+/// thus it takes the Python names of the arguments of `__new__()` as the keys,
+/// e.g. [`PyNew`] or a hand-written `#[new]`,
+/// and it raises `TypeError` on a missing key or an extra key as `__new__()` does.
+/// The arguments with a default may be missing.
+/// It makes the round trip `PyClass.from_dict(obj._asdict())` with [`PyNamedTupleAsdict`].
+///
+/// The positional only arguments (see `#[pyderive(positional_only)]`
+/// and `#[pyderive(signature=(..))]`) are passed positionally.
+/// The given dict is not modified.
+///
+/// - It should place `#[derive(PyFromDict)]` before `#[pyclass]`.
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyNew, PyFromDict, PyNamedTupleAsdict, PyRepr)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     name: String,
+///     #[pyderive(default = 0)]
+///     count: i64,
+/// }
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///
+///     py_run!(py, PyClass, r#"
+/// a = PyClass.from_dict({"name": "a"})
+/// assert repr(a) == "PyClass(name='a', count=0)"
+/// assert repr(PyClass.from_dict(a._asdict())) == repr(a)
+///
+/// try:
+///     PyClass.from_dict({"name": "a", "size": 1})
+/// except TypeError:
+///     pass
+/// else:
+///     raise AssertionError
+/// "#);
+/// });
+/// ```
+pub use pyderive_macros::PyFromDict;
+/// Derive macro generating a `from_str()` Python static method by [`FromStr`] trait.
+///
+/// The `from_str(s)` parses the string by [`FromStr::from_str()`],
+/// and it raises `ValueError` with the message of [`FromStr::Err`]
+/// (by [`ToString`]) if it fails.
+/// It makes the round trip `PyClass.from_str(str(obj))` with [`PyStr`] or a hand-written `__str__()`.
+///
+/// [`FromStr`]: std::str::FromStr
+/// [`FromStr::from_str()`]: std::str::FromStr::from_str
+/// [`FromStr::Err`]: std::str::FromStr::Err
+///
 /// # Expansion
 ///
-/// This implements, for example;
+/// This implements:
 ///
 /// ```
+/// # use std::str::FromStr;
 /// # use pyo3::prelude::*;
 /// # #[pyclass]
-/// # #[derive(PartialEq)]
 /// # struct PyClass {}
+/// # impl FromStr for PyClass {
+/// #     type Err = std::num::ParseIntError;
+/// #     fn from_str(s: &str) -> Result<Self, Self::Err> { unimplemented!() }
+/// # }
 /// #[pymethods]
 /// impl PyClass {
-///     pub fn __eq__(&self, other: &Self) -> bool {
-///         self.eq(other)
+///     #[staticmethod]
+///     fn from_str(s: &str) -> PyResult<Self> {
+///         <Self as FromStr>::from_str(s)
+///             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
 ///     }
-///     pub fn __ne__(&self, other: &Self) -> bool {
-///         self.ne(other)
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyFromStr)]
+/// #[pyclass]
+/// struct Port {
+///     number: u16,
+/// }
+///
+/// impl FromStr for Port {
+///     type Err = std::num::ParseIntError;
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         Ok(Self { number: s.parse()? })
 ///     }
 /// }
+///
+/// Python::attach(|py| {
+///     let Port = py.get_type::<Port>();
+///
+///     py_run!(py, Port, r#"
+/// assert Port.from_str("8080") is not None
+/// try:
+///     Port.from_str("http")
+/// except ValueError as e:
+///     assert str(e) == "invalid digit found in string"
+/// else:
+///     raise AssertionError
+/// "#);
+/// });
 /// ```
+pub use pyderive_macros::PyFromStr;
+/// Derive macro generating a [`__getitem__()`][__getitem__] fn/Python method.
 ///
-/// [__eq__]: https://docs.python.org/reference/datamodel.html#object.__eq__
-/// [__ne__]: https://docs.python.org/reference/datamodel.html#object.__ne__
+/// That accesses `get` fields by position as a sequence does,
+/// the fields and their order are the same as [`PyIter`]
+/// (use `#[pyderive(iter=<bool>)]` and `#[pyderive(iter_order=<int>)]` to customize them).
+///
+/// - An integer index (or an object with `__index__()`) returns the value of the field,
+///   a negative index counts from the end, and out of range raises `IndexError`.
+/// - A `slice` returns a `list` of the selected field values,
+///   the indices are computed by `slice.indices()`.
+///
+/// - It should place `#[derive(PyGetItem)]` before `#[pyclass]`.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
+///
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+/// [__getitem__]: https://docs.python.org/reference/datamodel.html#object.__getitem__
 ///
 /// # Example
 ///
@@ -463,28 +2001,95 @@ pub use pyderive_macros::PyDataclassFields;
 /// use pyo3::{prelude::*, py_run};
 /// use pyderive::*;
 ///
-/// #[derive(PyEq)]
-/// #[pyclass]
-/// #[derive(PartialEq)]
+/// // Place before `#[pyclass]`
+/// #[derive(PyGetItem)]
+/// #[pyclass(get_all)]
 /// struct PyClass {
-///     field: f64,
+///     string: String,
+///     integer: i64,
+///     float: f64,
+///     #[pyderive(iter=false)]
+///     excluded: String,
 /// }
 ///
 /// Python::attach(|py| -> PyResult<()> {
-///     let a = Py::new(py, PyClass { field: 0.0 })?;
-///     let b = Py::new(py, PyClass { field: 1.0 })?;
-///     let c = Py::new(py, PyClass { field: f64::NAN })?;
+///     let a = Py::new(py, PyClass {
+///         string: "s".to_string(),
+///         integer: 1,
+///         float: 1.0,
+///         excluded: "excluded".to_string(),
+///     })?;
 ///
-///     py_run!(py, a b, "assert a == a");
-///     py_run!(py, a b, "assert a != b");
-///     py_run!(py, c, "assert c != c");
-///     py_run!(py, a, "assert a != 1");
+///     py_run!(py, a, "assert a[0] == 's'");
+///     py_run!(py, a, "assert a[-1] == 1.0");
+///     py_run!(py, a, "assert a[0:2] == ['s', 1]");
 ///
 ///     Ok(())
 /// });
 /// ```
-pub use pyderive_macros::PyEq;
-
+pub use pyderive_macros::PyGetItem;
+/// Derive macro generating methods of a fieldless enum that behave like [`IntEnum`][IntEnum] members.
+///
+/// `#[pyclass]` exposes each variant as a class attribute, e.g., `Color.Red`,
+/// and this implements;
+///
+/// | Python method                        | Behavior                                           |
+/// |--------------------------------------|----------------------------------------------------|
+/// | `name`                               | returns the variant name                           |
+/// | `value`                              | returns the discriminant                           |
+/// | `__int__()` and `__index__()`        | returns the discriminant                           |
+/// | `__repr__()`                         | returns `<Color.Red: 0>`                           |
+/// | `__eq__()` and `__ne__()`            | compares the discriminant with ints or the members |
+/// | `__hash__()`                         | returns the hash of the discriminant               |
+///
+/// The discriminant is `Variant as isize`, that is, it respects the implicit discriminants.
+///
+/// - It supports only an enum of unit variants.
+/// - It conflicts with `#[pyclass(eq, eq_int)]` because this implements `__eq__()`.
+///
+/// [IntEnum]: https://docs.python.org/3/library/enum.html#enum.IntEnum
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyIntEnum)]
+/// #[pyclass]
+/// enum Color {
+///     Red = 0,
+///     Green = 1,
+/// }
+///
+/// let test = "
+/// assert Color.Red == 0
+/// assert Color.Green != Color.Red
+/// assert int(Color.Green) == 1
+/// assert Color.Green.name == 'Green'
+/// assert repr(Color.Green) == '<Color.Green: 1>'
+/// assert {0: 'a'}[Color.Red] == 'a'
+/// ";
+///
+/// Python::attach(|py| {
+///     let Color = py.get_type::<Color>();
+///     py_run!(py, Color, test)
+/// });
+/// ```
+///
+/// It is a compile error to derive for a struct or an enum with fields:
+///
+/// ```compile_fail
+/// use pyo3::prelude::*;
+/// use pyderive::*;
+///
+/// #[derive(PyIntEnum)]
+/// #[pyclass]
+/// enum Shape {
+///     Circle { radius: f64 },
+/// }
+/// ```
+pub use pyderive_macros::PyIntEnum;
 /// Derive macro generating a [`__iter__()`][__iter__] fn/Python method.
 ///
 /// It returns an iterator of `get` fields as default,
@@ -494,9 +2099,21 @@ pub use pyderive_macros::PyEq;
 /// the field is included to the iterator that `__iter__()` returns;
 /// if `#[pyderive(iter=false)]`, it isn't.
 ///
+/// If the struct is marked by `#[pyderive(iter="items")]` attribute,
+/// the iterator yields the tuples of the Python name and the value of the fields,
+/// and by `#[pyderive(iter="keys")]`, it yields the Python names,
+/// like a mapping does (see [`PyMapping`]).
+/// `#[pyderive(iter="values")]` is the default.
+/// The field attribute `#[pyderive(iter=false)]` excludes the field in any mode.
+///
 /// - It should place `#[derive(PyIter)]` before `#[pyclass]`.
-/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields,
+///   or for `T` of a [`Box<T>`], [`Arc<T>`][std::sync::Arc] or [`Rc<T>`][std::rc::Rc] field.
+/// - Each `__iter__()` call returns a new iterator object with its own cursor,
+///   thus nested or concurrent iterations over the same instance are independent.
 /// - Calling `__next__()` is thread-safe, it raises `PyRuntimeError` when it fails to take a lock.
+///   If the struct is marked by `#[pyderive(iter_snapshot)]`,
+///   it returns an iterator of a tuple of the fields instead, which takes no lock.
 ///
 /// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
 /// [pyo3_pyclass]: https://docs.rs/pyo3/latest/pyo3/attr.pyclass.html
@@ -544,6 +2161,10 @@ pub use pyderive_macros::PyIter;
 /// If the filed is marked by `#[pyderive(len=true)]` attribute,
 /// the field is counted by the `__len__()`; if `#[pyderive(len=false)]`, it isn't.
 ///
+/// If the field is marked by `#[pyderive(len_field)]` attribute,
+/// the `__len__()` returns the Python `len()` of the field instead, e.g., a wrapper of a `Vec`.
+/// At most one field can be marked.
+///
 /// - It should place `#[derive(PyLen)]` before `#[pyclass]`.
 ///
 /// [__len__]: https://docs.python.org/reference/datamodel.html#object.__len__
@@ -582,19 +2203,128 @@ pub use pyderive_macros::PyIter;
 ///     Ok(())
 /// });
 /// ```
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyLen)]
+/// #[pyclass(get_all)]
+/// struct Wrapper {
+///     #[pyderive(len_field)]
+///     inner: Vec<i64>,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, Wrapper { inner: vec![1, 2, 3] })?;
+///
+///     py_run!(py, a, "assert len(a) == len(a.inner) == 3");
+///
+///     Ok(())
+/// });
+/// ```
 pub use pyderive_macros::PyLen;
+/// Derive macro generating mapping methods that access fields by the Python name.
+///
+/// This implements;
+///
+/// | Python method                  | Behavior                                            |
+/// |--------------------------------|-----------------------------------------------------|
+/// | [`__getitem__()`][__getitem__] | returns the value of the `get` field                |
+/// | [`__setitem__()`][__setitem__] | assigns the value to the `set` field                |
+/// | `keys()`                       | returns a list of the names of `get` fields         |
+///
+/// The key is the Python name of the field, that is,
+/// it respects `#[pyo3(name = "..")]` and `#[pyclass(rename_all = "..")]`.
+/// It raises `KeyError` if the key is not a name of `get` (`set` for `__setitem__()`) fields.
+///
+/// - It should place `#[derive(PyMapping)]` before `#[pyclass]`.
+/// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for `get` fields
+///   and [`FromPyObject`][pyo3_FromPyObject] trait for `set` fields.
+/// - It implements `__setitem__()` only if the struct has `set` fields and is not marked by `#[pyclass(frozen)]`.
+/// - It does not implement `__len__()`, use [`PyLen`] to make `len(obj)` the number of `get` fields.
+/// - It does not implement `__contains__()`, use [`PyContainsKey`] to make `key in obj` test the keys.
+///
+/// [__getitem__]: https://docs.python.org/reference/datamodel.html#object.__getitem__
+/// [__setitem__]: https://docs.python.org/reference/datamodel.html#object.__setitem__
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+/// [pyo3_FromPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.FromPyObject.html
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PyMapping)]
+/// #[pyclass(get_all, set_all)]
+/// struct PyClass {
+///     string: String,
+///     integer: i64,
+/// }
+///
+/// let test = "
+/// a['integer'] = 2
+/// assert a['integer'] == 2
+/// assert a.keys() == ['string', 'integer']
+/// assert dict(a) == {'string': 's', 'integer': 2}
+///
+/// try:
+///     a['unknown']
+/// except KeyError:
+///     pass
+/// else:
+///     raise AssertionError
+/// ";
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass {
+///         string: "s".to_string(),
+///         integer: 1,
+///     })?;
+///
+///     py_run!(py, a, test);
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PyMapping;
 /// Derive macro generating a [`__match_args__`][__match_args__] const/Python class attribute.
 ///
-/// It contains `get` fields as default,
-/// in the order of declaration.
+/// It contains `get` fields that are positional arguments of `__new__()` as default,
+/// in the order of the arguments,
+/// so that `case PyClass(a, b)` binds the same fields as `PyClass(a, b)` takes.
+/// Like [dataclass], the keyword only fields (see `#[pyderive(kw_only)]`)
+/// and the fields marked by `#[pyderive(new=false)]` are excluded as default,
+/// they are matched by a keyword pattern, e.g. `case PyClass(field=...)`.
+/// The order follows `#[pyderive(signature=(..))]` if given.
 ///
 /// If the filed is marked by `#[pyderive(match_args=true)]` attribute,
 /// the field is included to the `__match_args__`;
 /// if `#[pyderive(match_args=false)]`, it isn't.
+/// The field that is not an argument of `__new__()` cannot be marked by `match_args=true`:
+///
+/// ```compile_fail
+/// use pyo3::prelude::*;
+/// use pyderive::*;
+///
+/// #[derive(PyNew, PyMatchArgs)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     #[pyderive(new=false, match_args=true)]
+///     field: i64,
+/// }
+/// ```
+///
+/// The derive macro cannot see `#[getter]` methods in `#[pymethods]`,
+/// thus the field backed by a custom getter should be marked by `#[pyderive(match_args=true)]`.
+/// The pattern binds the value the getter returns, e.g. `None` if it returns `Option::None`.
 ///
 /// - It should place `#[derive(PyMatchArgs)]` before `#[pyclass]`.
 ///
 /// [__match_args__]: https://docs.python.org/reference/datamodel.html#object.__match_args__
+/// [dataclass]: https://docs.python.org/3/library/dataclasses.html#dataclasses.dataclass
 ///
 /// # Example
 ///
@@ -636,6 +2366,44 @@ pub use pyderive_macros::PyLen;
 /// });
 /// ```
 pub use pyderive_macros::PyMatchArgs;
+/// Derive macro generating a `name` Python property.
+///
+/// The property returns the field marked by `#[pyderive(is_name)]`,
+/// e.g. the name a factory gives to the instance.
+/// Notes, it does not change `__name__` and `__qualname__` of the class, which PyO3 sets.
+///
+/// - It should place `#[derive(PyName)]` before `#[pyclass]`.
+/// - It is a compile error unless exactly one field is marked by `#[pyderive(is_name)]`.
+/// - The field should implement [`IntoPyObject`][pyo3_IntoPyObject] trait by reference.
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PyNew, PyName)]
+/// #[pyclass]
+/// struct PyClass {
+///     #[pyderive(is_name)]
+///     label: String,
+///     value: i64,
+/// }
+///
+/// let test = "
+/// a = PyClass('a', 1)
+/// assert a.name == 'a'
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+pub use pyderive_macros::PyName;
 /// Derive macro generating a [`__new__()`][__new__] Python method.
 ///
 /// It has all fields as the arguments as default,
@@ -645,6 +2413,28 @@ pub use pyderive_macros::PyMatchArgs;
 /// the field is excluded from the arguments of the `__new__()` method.
 /// Notes, `new=true` has no effect.
 ///
+/// The struct attribute `#[pyderive(signature=(..))]` overrides the signature of `__new__()`,
+/// keeping the field defaults for the arguments without `=<expr>`.
+/// Since `#[pyo3(signature=..)]` is placed on the method, not on the struct,
+/// use this instead to customize the signature.
+/// Notes, a derive macro cannot see the other `impl` blocks,
+/// thus it cannot detect a hand-written `#[new]`; do not use it together with `PyNew`.
+///
+/// The argument names are the Python names of the fields,
+/// that is, they follow `#[pyo3(name = "..")]` and `#[pyclass(rename_all = "..")]` like the getters,
+/// e.g., `PyClass(fieldName=1)` with `rename_all = "camelCase"`, and a keyword name like `type` is allowed.
+/// It is a compile error if the Python name is not an identifier, e.g., by `rename_all = "kebab-case"`:
+///
+/// ```compile_fail
+/// # use pyo3::prelude::*;
+/// # use pyderive::*;
+/// #[derive(PyNew)]
+/// #[pyclass(rename_all = "kebab-case")]
+/// struct PyClass {
+///     field_name: i64,
+/// }
+/// ```
+///
 /// - It should place `#[derive(PyNew)]` before `#[pyclass]`.
 ///
 /// See the [Customize Implementation](crate) section of the crate doc for detail.
@@ -690,12 +2480,27 @@ pub use pyderive_macros::PyNew;
 /// Derive macro generating [`__lt__()`][__lt__], [`__le__()`][__le__], [`__gt__()`][__gt__] and [`__ge__()`][__ge__] fn/Python methods.
 ///
 /// The implementation requires [`PartialOrd`] impl.
+/// If [`PartialOrd::partial_cmp()`] returns `None`, e.g. the derived [`PartialOrd`] on a `NaN` field,
+/// all of `<`, `<=`, `>` and `>=` return `False` like Python `float`.
 ///
 /// <section class="warning">
 /// PyO3 supports <code>#[pyclass(ord)]</code> since 0.22.
 /// </section>
 ///
 /// The generated methods return `False` when [`PartialOrd::partial_cmp`] returns [`None`].
+/// If the struct is marked by `#[pyderive(order_nan="raise")]` attribute,
+/// they raise `ValueError` instead, e.g. for validation-heavy code (the default is `order_nan="false"`).
+///
+/// If the struct is marked by `#[pyderive(order_key=<path>)]` attribute,
+/// it compares `<path>(self)` with `<path>(other)` instead,
+/// where `<path>` is a fn of `fn(&Self) -> K` and `K` implements [`PartialOrd`].
+/// In this case, the struct does not require [`PartialOrd`] impl.
+///
+/// If the struct is marked by `#[pyderive(order_reverse)]` attribute,
+/// the generated methods flip the ordering,
+/// that is, `sorted()` results in descending order.
+///
+/// It conflicts with [`PyEq`], [`PyRichCmp`] and [`PyDataclass`], see [`PyRichCmp`] for detail.
 ///
 /// *Note that implementing `__lt__()`, `__le__()`, `__gt__()` and `__ge__()` methods
 /// will cause Python not to generate a default `__hash__()` implementation,
@@ -762,22 +2567,82 @@ pub use pyderive_macros::PyNew;
 ///     Ok(())
 /// });
 /// ```
+///
+/// Compare by a key:
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[derive(PyOrd)]
+/// #[pyclass]
+/// #[pyderive(order_key=key)]
+/// struct PyClass {
+///     name: String,
+///     field: i64,
+/// }
+///
+/// fn key(this: &PyClass) -> i64 {
+///     this.field
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass { name: "b".to_string(), field: 0 })?;
+///     let b = Py::new(py, PyClass { name: "a".to_string(), field: 1 })?;
+///
+///     py_run!(py, a b, "assert a < b");
+///     py_run!(py, a b, "assert sorted([b, a]) == [a, b]");
+///
+///     Ok(())
+/// });
+/// ```
 pub use pyderive_macros::PyOrd;
 /// Derive macro generating a [`__repr__()`][__repr__] fn/Python method.
 ///
 /// It returns the string that contains `get` and `set` fields as default,
 /// in the order of declaration.
+/// The string starts with the `__qualname__` of the class,
+/// thus it follows `#[pyclass(name="<name>")]` rather than the Rust ident.
 ///
 /// If the filed is marked by `#[pyderive(repr=true)]` attribute,
 /// the field is included in the string that `__str__()` returns;
 /// if `#[pyderive(repr=false)]`, it isn't.
 ///
+/// It also defines the class attribute `__pyderive_repr_fields__`,
+/// the tuple of the Python names of the fields in the order of the string,
+/// for introspection, e.g. `PyClass.__pyderive_repr_fields__ == ('a', 'b')`.
+///
+/// If the struct is marked by `#[pyclass(frozen)]`,
+/// the string can be memoized in a [`OnceLock<String>`][std::sync::OnceLock] field marked by `#[pyderive(cache_repr)]`;
+/// the string is computed by the first `repr()` call only, e.g. for a logging hot path.
+/// Note that `#[pyclass(frozen)]` freezes the fields but not the objects they refer to,
+/// e.g. a `Py<PyList>` field is appendable and a `Py<T>` field of a non-frozen pyclass `T` is assignable
+/// after the string is cached, thus `cache_repr` rejects a `Py<T>` (and `PyObject`) field in the string,
+/// including `Option<Py<T>>`, `Vec<Py<T>>` etc.;
+/// exclude it by `#[pyderive(repr=false)]`.
+/// The interior mutability of a Rust field, e.g. [`Mutex`][std::sync::Mutex] and atomics, is not detected,
+/// such a field also leaves the cached string stale.
+/// The field is not a `__new__()` argument and is excluded from `repr()` etc. as default,
+/// but it should not be marked by `#[pyo3(get)]` (and `#[pyclass(get_all)]`).
+/// It conflicts with `#[pyderive(repr_depth=<usize>)]`.
+///
 /// - It should place `#[derive(PyRepr)]` before `#[pyclass]`.
 /// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
 /// - This recursively calls `repr()` like a dataclass.
+///   A tuple field is rendered element by element, thus the elements require [`IntoPyObject`][pyo3_IntoPyObject]
+///   instead of the tuple, which has no arity limit.
+///   A [`Box<T>`], [`Arc<T>`][std::sync::Arc] or [`Rc<T>`][std::rc::Rc] field is rendered as `T`.
+///   A [`Result<T, E>`] field is rendered as `Ok(<T>)` or `Err(<E>)`.
+/// - It renders a [`Duration`][std::time::Duration] field as `datetime.timedelta(..)`
+///   and a [`SystemTime`][std::time::SystemTime] field as a quoted ISO 8601 string, e.g. `'1970-01-01T00:00:00+00:00'`.
+///   An [`Instant`][std::time::Instant] field has no Python counterpart, exclude it by `#[pyderive(repr=false)]`/`#[pyderive(str=false)]`.
+/// - Under the `chrono` feature of this crate (it enables `chrono` feature of PyO3),
+///   it renders a `DateTime<Tz>`, `NaiveDateTime`, `NaiveDate` or `NaiveTime` field of [chrono]
+///   as a quoted ISO 8601 string also, e.g. `'2024-01-02T03:04:05+00:00'`.
 ///
 /// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
 /// [pyo3_pyclass]: https://docs.rs/pyo3/latest/pyo3/attr.pyclass.html
+/// [chrono]: https://docs.rs/chrono
 /// [__repr__]: https://docs.python.org/reference/datamodel.html#object.__repr__
 /// [repr]: https://docs.python.org/library/functions.html#repr
 ///
@@ -815,6 +2680,25 @@ pub use pyderive_macros::PyOrd;
 ///     Ok(())
 /// });
 /// ```
+///
+/// A `Py<T>` field may mutate after the string is cached, it fails to compile:
+///
+/// ```compile_fail
+/// use std::sync::OnceLock;
+///
+/// use pyo3::{prelude::*, types::PyList};
+/// use pyderive::*;
+///
+/// #[derive(PyRepr)]
+/// #[pyclass(frozen)]
+/// struct PyClass {
+///     // `obj.field.append(1)` would change the string
+///     #[pyo3(get)]
+///     field: Py<PyList>,
+///     #[pyderive(cache_repr)]
+///     cache: OnceLock<String>,
+/// }
+/// ```
 pub use pyderive_macros::PyRepr;
 /// Derive macro generating a [`__reversed__()`][__reversed__] fn/Python method.
 ///
@@ -830,6 +2714,8 @@ pub use pyderive_macros::PyRepr;
 /// - It should place `#[derive(PyReversed)]` before `#[pyclass]`.
 /// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
 /// - Calling `__next__()` is thread-safe, it raises `PyRuntimeError` when it fails to take a lock.
+///   If the struct is marked by `#[pyderive(iter_snapshot)]`,
+///   it returns an iterator of a tuple of the fields instead, which takes no lock.
 ///
 /// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
 /// [pyo3_pyclass]: https://docs.rs/pyo3/latest/pyo3/attr.pyclass.html
@@ -873,12 +2759,49 @@ pub use pyderive_macros::PyReversed;
 /// Derive macro generating `__richcmp__` fn that provides Python comparison operations (`==`, `!=`, `<`, `<=`, `>`, and `>=`).
 ///
 /// The implementation requires [`PartialEq`] and [`PartialOrd`] impl.
+/// It handles `NaN` like Python `float` does, as [`PyEq`] and [`PyOrd`] do.
 ///
 /// <section class="warning">
 /// PyO3 supports <code>#[pyclass(ord)]</code> since 0.22, it is recommended to use it.
 /// </section>
 ///
 /// The generated methods return `False` when [`PartialOrd::partial_cmp`] returns [`None`].
+/// If the struct is marked by `#[pyderive(order_nan="raise")]` attribute,
+/// they raise `ValueError` instead, e.g. for validation-heavy code (the default is `order_nan="false"`).
+///
+/// If the struct is marked by `#[pyderive(order_key=<path>)]` attribute,
+/// it compares `<path>(self)` with `<path>(other)` instead (include `==` and `!=`),
+/// where `<path>` is a fn of `fn(&Self) -> K` and `K` implements [`PartialEq`] and [`PartialOrd`].
+/// In this case, the struct does not require [`PartialEq`] and [`PartialOrd`] impl.
+///
+/// If the struct is marked by `#[pyderive(order_reverse)]` attribute,
+/// the generated method flips the ordering (`<`, `<=`, `>` and `>=`),
+/// that is, `sorted()` results in descending order.
+/// It does not affect `==` and `!=`.
+///
+/// If the struct is marked by `#[pyderive(richcmp_tuple)]` attribute,
+/// it also compares the instance with a tuple as the tuple of `get` fields
+/// (in the order of declaration), e.g. `PyClass(1, 'a') < (1, 'b')`.
+/// It returns `NotImplemented` if the lengths are different or the elements are not comparable.
+/// This requires [`IntoPyObject`][pyo3_IntoPyObject] trait for `get` fields.
+///
+/// It subsumes [`PyEq`] and [`PyOrd`], derive it alone for both equality and ordering.
+/// PyO3 merges `__eq__()`, `__lt__()` etc. into one `__richcmp__()` slot,
+/// thus deriving two or more of [`PyEq`], [`PyOrd`], [`PyRichCmp`] and [`PyDataclass`] is a compile error,
+/// which says the duplicated definitions of `__pyderive_derive_only_one_of_PyEq_PyOrd_PyRichCmp_PyDataclass`
+/// (or names the conflicting derive macro if it is in a following `#[derive(..)]`):
+///
+/// ```compile_fail
+/// # use pyo3::prelude::*;
+/// # use pyderive::*;
+/// #[derive(PyEq, PyRichCmp, PartialEq, PartialOrd)]
+/// #[pyclass]
+/// struct PyClass {
+///     field: i64,
+/// }
+/// ```
+///
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
 ///
 /// *Note that implementing `__richcmp__` will cause Python not to generate
 /// a default `__hash__` implementation, so consider implementing `__hash__`
@@ -950,10 +2873,64 @@ pub use pyderive_macros::PyReversed;
 /// });
 /// ```
 pub use pyderive_macros::PyRichCmp;
+/// Derive macro generating set-like methods over a collection field.
+///
+/// The field marked by `#[pyderive(set_field)]` is the sole source of;
+///
+/// | Python method                    | Behavior                                           |
+/// |----------------------------------|----------------------------------------------------|
+/// | [`__iter__()`][__iter__]         | returns an iterator of the elements                |
+/// | [`__len__()`][__len__]           | returns the number of the elements                 |
+/// | [`__contains__()`][__contains__] | tests whether an element `==` the item             |
+///
+/// `__contains__()` compares the item with each element by Python equality,
+/// thus an unhashable item, e.g., a `list`, is just not contained (no `TypeError`).
+///
+/// - It should place `#[derive(PySetProtocol)]` before `#[pyclass]`.
+/// - It requires exactly one `set_field` field, e.g., [`HashSet`][std::collections::HashSet],
+///   whose `&` reference implements [`IntoPyObject`][pyo3_IntoPyObject] and which has `len()` method.
+/// - It conflicts with [`PyIter`] and [`PyLen`].
+///
+/// [__iter__]: https://docs.python.org/reference/datamodel.html#object.__iter__
+/// [__len__]: https://docs.python.org/reference/datamodel.html#object.__len__
+/// [__contains__]: https://docs.python.org/reference/datamodel.html#object.__contains__
+/// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// // Place before `#[pyclass]`
+/// #[derive(PySetProtocol)]
+/// #[pyclass]
+/// struct Tags {
+///     #[pyderive(set_field)]
+///     tags: HashSet<String>,
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, Tags {
+///         tags: HashSet::from(["x".to_string(), "y".to_string()]),
+///     })?;
+///
+///     py_run!(py, a, "assert len(a) == 2");
+///     py_run!(py, a, "assert 'x' in a");
+///     py_run!(py, a, "assert sorted(a) == ['x', 'y']");
+///
+///     Ok(())
+/// });
+/// ```
+pub use pyderive_macros::PySetProtocol;
 /// Derive macro generating a [`__str__()`][__str__] fn/Python method.
 ///
 /// It returns the string that contains `get` and `set` fields as default,
 /// in the order of declaration.
+/// The string starts with the `__qualname__` of the class,
+/// thus it follows `#[pyclass(name="<name>")]` rather than the Rust ident.
 ///
 /// If the filed is marked by `#[pyderive(str=true)]` attribute,
 /// the field is included in the string that `__str__()` returns;
@@ -961,10 +2938,24 @@ pub use pyderive_macros::PyRichCmp;
 ///
 /// - It should place `#[derive(PyStr)]` before `#[pyclass]`.
 /// - It requires [`IntoPyObject`][pyo3_IntoPyObject] trait for fields.
-/// - recursively calls `str()` like a dataclass.
+/// - It recursively calls `repr()` of the fields like a dataclass,
+///   or `str()` if the struct is marked by `#[pyderive(str_children="str")]`.
+///   A [`Box<T>`], [`Arc<T>`][std::sync::Arc] or [`Rc<T>`][std::rc::Rc] field is rendered as `T`.
+///   A [`Result<T, E>`] field is rendered as `Ok(<T>)` or `Err(<E>)`.
+/// - It renders a [`Duration`][std::time::Duration] field as `datetime.timedelta(..)`
+///   and a [`SystemTime`][std::time::SystemTime] field as a quoted ISO 8601 string, e.g. `'1970-01-01T00:00:00+00:00'`.
+///   An [`Instant`][std::time::Instant] field has no Python counterpart, exclude it by `#[pyderive(repr=false)]`/`#[pyderive(str=false)]`.
+/// - Under the `chrono` feature of this crate (it enables `chrono` feature of PyO3),
+///   it renders a `DateTime<Tz>`, `NaiveDateTime`, `NaiveDate` or `NaiveTime` field of [chrono]
+///   as a quoted ISO 8601 string also, e.g. `'2024-01-02T03:04:05+00:00'`.
+///
+/// If the struct is marked by `#[pyderive(str_with=<path>)]` attribute,
+/// `__str__()` returns `<path>(self)` instead of the string of the fields,
+/// where `<path>` is a fn of `fn(&Self) -> PyResult<String>`.
 ///
 /// [pyo3_IntoPyObject]: https://docs.rs/pyo3/latest/pyo3/conversion/trait.IntoPyObject.html
 /// [pyo3_pyclass]: https://docs.rs/pyo3/latest/pyo3/attr.pyclass.html
+/// [chrono]: https://docs.rs/chrono
 /// [__str__]: https://docs.python.org/reference/datamodel.html#object.__str__
 /// [str]: https://docs.python.org/library/functions.html#str
 ///
@@ -1254,23 +3245,34 @@ pub use pyderive_macros::PyNamedTupleReplace;
 pub use pyderive_macros::PyBitwise;
 /// Derive macro generating an impl of numeric op methods/fns base on [std::ops] traits.
 ///
+/// It derives the forward and in-place ops, see [`PyReflectedNumeric`](ops::PyReflectedNumeric)
+/// for the reflected ops, e.g. `__rfloordiv__()` and `__rdivmod__()`.
+///
 /// This derives;
 ///
-/// | Python method                    | Required Trait                          |
-/// |----------------------------------|-----------------------------------------|
-/// | [`__pos__()`][__pos__]           | --                                      |
-/// | [`__neg__()`][__neg__]           | `Neg<&Class> for &Class`                |
-/// | [`__add__()`][__add__]           | `Add<&Class> for &Class`                |
-/// | [`__sub__()`][__sub__]           | `Sub<&Class> for &Class`                |
-/// | [`__mul__()`][__mul__]           | `Mul<&Class> for &Class`                |
-/// | [`__truediv__()`][__truediv__]   | `Div<&Class> for &Class`                |
-/// | [`__mod__()`][__mod__]           | `Rem<&Class> for &Class`                |
-/// | [`__iadd__()`][__iadd__]         | `AddAssign<&Class> for &Class`          |
-/// | [`__isub__()`][__isub__]         | `SubAssign<&Class> for &Class`          |
-/// | [`__imul__()`][__imul__]         | `MulAssign<&Class> for &Class`          |
-/// | [`__itruediv__()`][__itruediv__] | `DivAssign<&Class> for &Class`          |
-/// | [`__imod__()`][__imod__]         | `RemAssign<&Class> for &Class`          |
-/// | [`__divmod__()`][__divmod__]     | Same as `__truediv__()` and `__mod__()` |
+/// | Python method                      | Required Trait                          |
+/// |------------------------------------|-----------------------------------------|
+/// | [`__pos__()`][__pos__]             | --                                      |
+/// | [`__neg__()`][__neg__]             | `Neg<&Class> for &Class`                |
+/// | [`__add__()`][__add__]             | `Add<&Class> for &Class`                |
+/// | [`__sub__()`][__sub__]             | `Sub<&Class> for &Class`                |
+/// | [`__mul__()`][__mul__]             | `Mul<&Class> for &Class`                |
+/// | [`__truediv__()`][__truediv__]     | `Div<&Class> for &Class`                |
+/// | [`__floordiv__()`][__floordiv__]   | `Div<&Class> for &Class`                |
+/// | [`__mod__()`][__mod__]             | `Rem<&Class> for &Class`                |
+/// | [`__iadd__()`][__iadd__]           | `AddAssign<&Class> for &Class`          |
+/// | [`__isub__()`][__isub__]           | `SubAssign<&Class> for &Class`          |
+/// | [`__imul__()`][__imul__]           | `MulAssign<&Class> for &Class`          |
+/// | [`__itruediv__()`][__itruediv__]   | `DivAssign<&Class> for &Class`          |
+/// | [`__ifloordiv__()`][__ifloordiv__] | `DivAssign<&Class> for &Class`          |
+/// | [`__imod__()`][__imod__]           | `RemAssign<&Class> for &Class`          |
+/// | [`__divmod__()`][__divmod__]       | Same as `__truediv__()` and `__mod__()` |
+///
+/// Notes, `__floordiv__()` and `__ifloordiv__()` call [`Div`][std::ops::Div] and [`DivAssign`][std::ops::DivAssign]
+/// as `__truediv__()` and `__itruediv__()` do, not a floor division, unlike Python,
+/// e.g. `7.0 // 2.0` is `3.5` for a `f64` field and `-7 // 2` is `-3` (truncated) for an `i64` field.
+/// Implement the trait with flooring, or derive the ops individually from [`pyderive::ops`](mod@ops)
+/// and implement `__floordiv__()` by hand.
 ///
 /// [__pos__]: https://docs.python.org/3/reference/datamodel.html#object.__pos__
 /// [__neg__]: https://docs.python.org/3/reference/datamodel.html#object.__neg__
@@ -1278,11 +3280,13 @@ pub use pyderive_macros::PyBitwise;
 /// [__sub__]: https://docs.python.org/3/reference/datamodel.html#object.__sub__
 /// [__mul__]: https://docs.python.org/3/reference/datamodel.html#object.__mul__
 /// [__truediv__]: https://docs.python.org/3/reference/datamodel.html#object.__truediv__
+/// [__floordiv__]: https://docs.python.org/3/reference/datamodel.html#object.__floordiv__
 /// [__mod__]: https://docs.python.org/3/reference/datamodel.html#object.__mod__
 /// [__iadd__]: https://docs.python.org/3/reference/datamodel.html#object.__iadd__
 /// [__isub__]: https://docs.python.org/3/reference/datamodel.html#object.__isub__
 /// [__imul__]: https://docs.python.org/3/reference/datamodel.html#object.__imul__
 /// [__itruediv__]: https://docs.python.org/3/reference/datamodel.html#object.__itruediv__
+/// [__ifloordiv__]: https://docs.python.org/3/reference/datamodel.html#object.__ifloordiv__
 /// [__imod__]: https://docs.python.org/3/reference/datamodel.html#object.__imod__
 /// [__divmod__]: https://docs.python.org/3/reference/datamodel.html#object.__divmod__
 pub use pyderive_macros::PyNumeric;
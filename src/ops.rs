@@ -1,4 +1,137 @@
 //! Provides derive macros that implements enumeration of numeric type.
+//!
+//! # Operand Types
+//!
+//! By default, the binary operator derive macros, e.g. [`PyAdd`] and [`PyReflectedAdd`],
+//! accept only an instance of the class itself as the other operand.
+//! The `#[pyderive(rhs(<type>, ...))]` struct attribute replaces it with the listed types;
+//! the generated method tries to extract the other operand as each type in order,
+//! calls the trait with the first one succeeded,
+//! and returns `NotImplemented` if none matches.
+//! `Self` in the list means an instance of the class, which is passed as `&Self`.
+//!
+//! The attribute applies to all binary operator derives on the struct,
+//! and it does not affect the assignment derives (e.g. [`PyAddAssign`])
+//! and the combined derives (e.g. [`PyNumeric`](crate::PyNumeric) and [`PyReflectedNumeric`]).
+//! Note that a Python `int` is also extracted as `f64`, so list `i64` before `f64`.
+//!
+//! ```
+//! use std::ops::{Add, Mul};
+//!
+//! use pyo3::{prelude::*, py_run};
+//!
+//! use pyderive::PyNew;
+//! use pyderive::ops::{PyAdd, PyMul, PyReflectedAdd};
+//!
+//! #[derive(PyNew, PyAdd, PyMul, PyReflectedAdd)]
+//! #[pyclass(get_all)]
+//! #[pyderive(rhs(Self, i64, f64))]
+//! struct Money {
+//!     amount: f64
+//! }
+//!
+//! // implements Add<&Money>, Add<i64>, Add<f64> for &Money,
+//! // Add<&Money> for i64 and f64 (PyReflectedAdd), and Mul likewise
+//! macro_rules! impl_op {
+//!     ($trait:ident::$method:ident) => {
+//!         impl $trait for &Money {
+//!             type Output = Money;
+//!             fn $method(self, rhs: Self) -> Money {
+//!                 Money { amount: self.amount.$method(rhs.amount) }
+//!             }
+//!         }
+//!         impl $trait<i64> for &Money {
+//!             type Output = Money;
+//!             fn $method(self, rhs: i64) -> Money {
+//!                 Money { amount: self.amount.$method(rhs as f64) }
+//!             }
+//!         }
+//!         impl $trait<f64> for &Money {
+//!             type Output = Money;
+//!             fn $method(self, rhs: f64) -> Money {
+//!                 Money { amount: self.amount.$method(rhs) }
+//!             }
+//!         }
+//!         impl $trait<&Money> for i64 {
+//!             type Output = Money;
+//!             fn $method(self, rhs: &Money) -> Money {
+//!                 Money { amount: (self as f64).$method(rhs.amount) }
+//!             }
+//!         }
+//!         impl $trait<&Money> for f64 {
+//!             type Output = Money;
+//!             fn $method(self, rhs: &Money) -> Money {
+//!                 Money { amount: self.$method(rhs.amount) }
+//!             }
+//!         }
+//!     };
+//! }
+//!
+//! impl_op!(Add::add);
+//! impl_op!(Mul::mul);
+//!
+//! let test = "
+//! assert (Money(1.5) + Money(1.0)).amount == 2.5
+//! assert (Money(1.5) + 2).amount == 3.5
+//! assert (2 + Money(1.5)).amount == 3.5
+//! assert (Money(1.5) * 0.5).amount == 0.75
+//!
+//! try:
+//!     Money(1.5) + '1'
+//! except TypeError:
+//!     pass
+//! else:
+//!     raise AssertionError
+//! ";
+//!
+//! Python::attach(|py| {
+//!     let Money = py.get_type::<Money>();
+//!     py_run!(py, Money, test)
+//! });
+//! ```
+//!
+//! # By-Value Operands
+//!
+//! By default, the operator derive macros call the traits implemented for `&Self`, e.g. `Add for &PyClass`.
+//! The `#[pyderive(by_value)]` struct attribute makes them call the traits implemented for `Self` instead,
+//! e.g. `Add for PyClass`, by cloning the operands, thus the struct requires [`Clone`].
+//! It applies to the unary, binary, reflected binary and assignment derives,
+//! and to `Self` of `#[pyderive(rhs(..))]`, but not to the combined derives.
+//!
+//! ```
+//! use std::ops::Add;
+//!
+//! use pyo3::{prelude::*, py_run};
+//!
+//! use pyderive::PyNew;
+//! use pyderive::ops::PyAdd;
+//!
+//! #[derive(PyNew, PyAdd, Clone)]
+//! #[pyclass(get_all)]
+//! #[pyderive(by_value)]
+//! struct Vector {
+//!     x: i64,
+//!     y: i64,
+//! }
+//!
+//! impl Add for Vector {
+//!     type Output = Vector;
+//!
+//!     fn add(self, rhs: Self) -> Vector {
+//!         Vector { x: self.x + rhs.x, y: self.y + rhs.y }
+//!     }
+//! }
+//!
+//! let test = "
+//! v = Vector(1, 2) + Vector(10, 20)
+//! assert (v.x, v.y) == (11, 22)
+//! ";
+//!
+//! Python::attach(|py| {
+//!     let Vector = py.get_type::<Vector>();
+//!     py_run!(py, Vector, test)
+//! });
+//! ```
 
 /// Derive macro generating an impl of [`__add__`][py] method by [`Add`][std::ops::Add] trait.
 ///
@@ -319,6 +452,9 @@ pub use pyderive_macros::PyAndAssign;
 pub use pyderive_macros::PyDivMod;
 /// Derive macro generating an impl of [`__floordiv__`][py] method by [`Div`][std::ops::Div] trait.
 ///
+/// Notes, it calls [`Div`][std::ops::Div], not a floor division, unlike Python,
+/// e.g. `-7 // 2` is `-3` (truncated) for an `i64` field, implement the trait with flooring if needed.
+///
 /// # Expansion
 ///
 /// This implements:
@@ -380,6 +516,9 @@ pub use pyderive_macros::PyDivMod;
 pub use pyderive_macros::PyFloorDiv;
 /// Derive macro generating an impl of [`__ifloordiv__`][py] method by [`DivAssign`][std::ops::DivAssign] trait.
 ///
+/// Notes, it calls [`DivAssign`][std::ops::DivAssign], not a floor division, unlike Python,
+/// e.g. `-7 // 2` is `-3` (truncated) for an `i64` field, implement the trait with flooring if needed.
+///
 /// # Expansion
 ///
 /// This implements:
@@ -1330,6 +1469,84 @@ pub use pyderive_macros::PyReflectedAdd;
 ///
 /// [py]: https://docs.python.org/3/reference/datamodel.html#object.__rand__
 pub use pyderive_macros::PyReflectedAnd;
+/// Derive macro generating an impl of reflected bitwise op methods/fns base on [std::ops] traits.
+///
+/// This derives;
+///
+/// | Python method                  | Required Trait              |
+/// |--------------------------------|-----------------------------|
+/// | [`__rand__()`][__rand__]       | `BitAnd<&Class> for &Class` |
+/// | [`__ror__()`][__ror__]         | `BitOr<&Class> for &Class`  |
+/// | [`__rxor__()`][__rxor__]       | `BitXor<&Class> for &Class` |
+/// | [`__rlshift__()`][__rlshift__] | `Shl<&Class> for &Class`    |
+/// | [`__rrshift__()`][__rrshift__] | `Shr<&Class> for &Class`    |
+///
+/// The methods take the left operand as `&Self`,
+/// thus an operator dispatches to them only if both operands are instances of the class,
+/// e.g. `PyClass(5) - PyClass(3)` calls `__rsub__()` of `PyClass(3)`
+/// (when the class has no `__sub__()`),
+/// and `2 - PyClass(3)` raises `TypeError`.
+/// Use the individual derive macros, e.g. [`PyReflectedAnd`], with `#[pyderive(rhs(..))]`
+/// for the other types of the left operand, see the [module documentation](self).
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Shl;
+///
+/// use pyo3::{prelude::*, py_run};
+///
+/// use pyderive::PyNew;
+/// use pyderive::ops::PyReflectedBitwise;
+/// # use std::ops::{BitAnd, BitOr, BitXor, Shr};
+///
+/// #[derive(PyNew, PyReflectedBitwise)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     field: i64
+/// }
+///
+/// impl Shl for &PyClass {
+///     type Output = PyClass;
+///
+///     fn shl(self, rhs: Self) -> Self::Output {
+///         PyClass { field: self.field << rhs.field }
+///     }
+/// }
+/// # impl BitAnd for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn bitand(self, rhs: Self) -> Self::Output { PyClass { field: self.field & rhs.field } }
+/// # }
+/// # impl BitOr for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn bitor(self, rhs: Self) -> Self::Output { PyClass { field: self.field | rhs.field } }
+/// # }
+/// # impl BitXor for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn bitxor(self, rhs: Self) -> Self::Output { PyClass { field: self.field ^ rhs.field } }
+/// # }
+/// # impl Shr for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn shr(self, rhs: Self) -> Self::Output { PyClass { field: self.field >> rhs.field } }
+/// # }
+///
+/// let test = "
+/// actual = PyClass(1) << PyClass(3)
+/// assert actual.field == 8
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [__rand__]: https://docs.python.org/3/reference/datamodel.html#object.__rand__
+/// [__ror__]: https://docs.python.org/3/reference/datamodel.html#object.__ror__
+/// [__rxor__]: https://docs.python.org/3/reference/datamodel.html#object.__rxor__
+/// [__rlshift__]: https://docs.python.org/3/reference/datamodel.html#object.__rlshift__
+/// [__rrshift__]: https://docs.python.org/3/reference/datamodel.html#object.__rrshift__
+pub use pyderive_macros::PyReflectedBitwise;
 /// Derive macro generating an impl of [`__rdivmod__`][py] method by [`Div`][std::ops::Div] and [`Rem`][std::ops::Rem] traits.
 ///
 /// # Expansion
@@ -1409,6 +1626,9 @@ pub use pyderive_macros::PyReflectedAnd;
 pub use pyderive_macros::PyReflectedDivMod;
 /// Derive macro generating an impl of [`__rfloordiv__`][py] method by [`Div`][std::ops::Div] trait.
 ///
+/// Notes, it calls [`Div`][std::ops::Div], not a floor division, unlike Python,
+/// e.g. `-7 // 2` is `-3` (truncated) for an `i64` field, implement the trait with flooring if needed.
+///
 /// # Expansion
 ///
 /// This implements:
@@ -1712,6 +1932,91 @@ pub use pyderive_macros::PyReflectedMod;
 ///
 /// [py]: https://docs.python.org/3/reference/datamodel.html#object.__rmul__
 pub use pyderive_macros::PyReflectedMul;
+/// Derive macro generating an impl of reflected numeric op methods/fns base on [std::ops] traits.
+///
+/// This derives;
+///
+/// | Python method                      | Required Trait                            |
+/// |------------------------------------|-------------------------------------------|
+/// | [`__radd__()`][__radd__]           | `Add<&Class> for &Class`                  |
+/// | [`__rsub__()`][__rsub__]           | `Sub<&Class> for &Class`                  |
+/// | [`__rmul__()`][__rmul__]           | `Mul<&Class> for &Class`                  |
+/// | [`__rtruediv__()`][__rtruediv__]   | `Div<&Class> for &Class`                  |
+/// | [`__rfloordiv__()`][__rfloordiv__] | `Div<&Class> for &Class`                  |
+/// | [`__rmod__()`][__rmod__]           | `Rem<&Class> for &Class`                  |
+/// | [`__rdivmod__()`][__rdivmod__]     | Same as `__rtruediv__()` and `__rmod__()` |
+///
+/// Notes, `__rfloordiv__()` calls [`Div`][std::ops::Div] as `__rtruediv__()` does, not a floor division, unlike Python,
+/// e.g. `7.0 // 2.0` is `3.5` for a `f64` field and `-7 // 2` is `-3` (truncated) for an `i64` field.
+///
+/// The methods take the left operand as `&Self`,
+/// thus an operator dispatches to them only if both operands are instances of the class,
+/// e.g. `PyClass(5) - PyClass(3)` calls `__rsub__()` of `PyClass(3)`
+/// (when the class has no `__sub__()`),
+/// and `2 - PyClass(3)` raises `TypeError`.
+/// Use the individual derive macros, e.g. [`PyReflectedAdd`], with `#[pyderive(rhs(..))]`
+/// for the other types of the left operand, see the [module documentation](self).
+///
+/// # Example
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use pyo3::{prelude::*, py_run};
+///
+/// use pyderive::PyNew;
+/// use pyderive::ops::PyReflectedNumeric;
+/// # use std::ops::{Add, Div, Mul, Rem};
+///
+/// #[derive(PyNew, PyReflectedNumeric)]
+/// #[pyclass(get_all)]
+/// struct PyClass {
+///     field: i64
+/// }
+///
+/// impl Sub for &PyClass {
+///     type Output = PyClass;
+///
+///     fn sub(self, rhs: Self) -> Self::Output {
+///         PyClass { field: self.field - rhs.field }
+///     }
+/// }
+/// # impl Add for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn add(self, rhs: Self) -> Self::Output { PyClass { field: self.field + rhs.field } }
+/// # }
+/// # impl Mul for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn mul(self, rhs: Self) -> Self::Output { PyClass { field: self.field * rhs.field } }
+/// # }
+/// # impl Div for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn div(self, rhs: Self) -> Self::Output { PyClass { field: self.field / rhs.field } }
+/// # }
+/// # impl Rem for &PyClass {
+/// #     type Output = PyClass;
+/// #     fn rem(self, rhs: Self) -> Self::Output { PyClass { field: self.field % rhs.field } }
+/// # }
+///
+/// let test = "
+/// actual = PyClass(5) - PyClass(3)
+/// assert actual.field == 2
+/// ";
+///
+/// Python::attach(|py| {
+///     let PyClass = py.get_type::<PyClass>();
+///     py_run!(py, PyClass, test)
+/// });
+/// ```
+///
+/// [__radd__]: https://docs.python.org/3/reference/datamodel.html#object.__radd__
+/// [__rsub__]: https://docs.python.org/3/reference/datamodel.html#object.__rsub__
+/// [__rmul__]: https://docs.python.org/3/reference/datamodel.html#object.__rmul__
+/// [__rtruediv__]: https://docs.python.org/3/reference/datamodel.html#object.__rtruediv__
+/// [__rfloordiv__]: https://docs.python.org/3/reference/datamodel.html#object.__rfloordiv__
+/// [__rmod__]: https://docs.python.org/3/reference/datamodel.html#object.__rmod__
+/// [__rdivmod__]: https://docs.python.org/3/reference/datamodel.html#object.__rdivmod__
+pub use pyderive_macros::PyReflectedNumeric;
 /// Derive macro generating an impl of [`__ror__`][py] method by [`BitOr`][std::ops::BitOr] trait.
 ///
 /// # Expansion
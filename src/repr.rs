@@ -0,0 +1,71 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use pyo3::IntoPyObjectExt;
+
+/// Helper trait rendering a field value by Python `repr()`, the default rendering of [`PyRepr`](crate::PyRepr) and [`PyStr`](crate::PyStr).
+///
+/// It converts the value into a Python object by [`IntoPyObject`]
+/// and calls Python `repr()` on it, that is,
+/// it renders nested pyclass, `bytes` etc. as same as the derived `__repr__()` does.
+/// It is implemented for all types `T` such that `&T` implements [`IntoPyObject`],
+/// include `Py<T>`.
+///
+/// Notes, it does not cover the special cases of the derive macros,
+/// e.g. path fields rendered as strings (`path_str`), `repr_float`, `SystemTime` as ISO 8601 strings,
+/// tuple fields rendered element by element and the field attributes such as `repr="debug"`,
+/// it renders them by Python `repr()` of the converted object as is.
+///
+/// This is useful to keep hand-written `#[pymethods]` consistent with the derive macros.
+///
+/// # Example
+///
+/// ```
+/// use pyo3::{prelude::*, py_run};
+/// use pyderive::*;
+///
+/// #[pyclass]
+/// struct PyClass {
+///     string: String,
+///     bytes: Vec<u8>,
+///     option: Option<i64>,
+/// }
+///
+/// #[pymethods]
+/// impl PyClass {
+///     fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+///         Ok(format!(
+///             "PyClass(string={}, option={})",
+///             self.string.field_repr(py)?,
+///             self.option.field_repr(py)?,
+///         ))
+///     }
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let a = Py::new(py, PyClass {
+///         string: "s".to_string(),
+///         bytes: b"b".to_vec(),
+///         option: None,
+///     })?;
+///
+///     py_run!(py, a, r#"assert repr(a) == "PyClass(string='s', option=None)""#);
+///
+///     let bytes = a.borrow(py).bytes.field_repr(py)?;
+///     assert_eq!(bytes.to_str()?, "b'b'");
+///
+///     Ok(())
+/// });
+/// ```
+pub trait FieldRepr {
+    /// Returns Python `repr()` of the value.
+    fn field_repr<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>>;
+}
+
+impl<T> FieldRepr for T
+where
+    for<'a, 'py> &'a T: IntoPyObject<'py>,
+{
+    fn field_repr<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyString>> {
+        self.into_bound_py_any(py)?.repr()
+    }
+}